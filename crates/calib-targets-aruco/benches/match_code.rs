@@ -0,0 +1,99 @@
+//! Criterion benches for [`Matcher::match_code`] against the full
+//! id × rotation scan it replaces, on `DICT_6X6_1000` with `max_hamming = 2`.
+//!
+//! The observations mimic one frame of a large board: 240 cells, mostly clean
+//! reads in all four rotations, a fifth with one or two flipped bits, and a
+//! tenth that are not markers at all. Codes are drawn from a seeded xorshift
+//! so there is no `rand` dependency and run-to-run numbers are comparable.
+//!
+//! Run with:
+//! ```text
+//! cargo bench -p calib-targets-aruco --bench match_code
+//! ```
+
+use calib_targets_aruco::{builtins, Matcher};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+const CELLS: usize = 240;
+const MAX_HAMMING: u8 = 2;
+
+/// Deterministic xorshift64 — used only to pick fixture codes.
+struct Xorshift(u64);
+
+impl Xorshift {
+    fn next(&mut self) -> u64 {
+        let mut x = self.0;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.0 = x;
+        x
+    }
+}
+
+fn fixture(matcher: &Matcher) -> Vec<u64> {
+    let bits = matcher.dictionary().bit_count() as u64;
+    let ids = matcher.dictionary().codes().len() as u64;
+    let mut rng = Xorshift(0x5eed_cafe);
+    (0..CELLS)
+        .map(|k| {
+            let r = rng.next();
+            if k % 10 == 9 {
+                return r & ((1 << bits) - 1);
+            }
+            let mut code = matcher
+                .rotated_code((r % ids) as u32, (k % 4) as u8)
+                .expect("id in range");
+            if k % 5 == 4 {
+                code ^= 1 << ((r >> 16) % bits);
+                code ^= 1 << ((r >> 32) % bits);
+            }
+            code
+        })
+        .collect()
+}
+
+/// The pre-bucketing matcher: every id and rotation, in order.
+fn full_scan(matcher: &Matcher, observed: u64) -> Option<(u32, u8, u8)> {
+    let mut best: Option<(u32, u8, u8)> = None;
+    for id in 0..matcher.dictionary().codes().len() as u32 {
+        for rot in 0..4 {
+            let code = matcher.rotated_code(id, rot).expect("id in range");
+            let h = (observed ^ code).count_ones() as u8;
+            if h <= matcher.max_hamming() && best.is_none_or(|(_, _, b)| h < b) {
+                if h == 0 {
+                    return Some((id, rot, h));
+                }
+                best = Some((id, rot, h));
+            }
+        }
+    }
+    best
+}
+
+fn bench_match_code(c: &mut Criterion) {
+    let dict = builtins::builtin_dictionary("DICT_6X6_1000").expect("builtin dict");
+    let matcher = Matcher::new(dict, MAX_HAMMING);
+    let observed = fixture(&matcher);
+    let mut group = c.benchmark_group("match_code_6x6_1000");
+
+    group.bench_function("bucketed", |b| {
+        b.iter(|| {
+            for &code in &observed {
+                black_box(matcher.match_code(black_box(code)));
+            }
+        })
+    });
+    group.bench_function("full_scan", |b| {
+        b.iter(|| {
+            for &code in &observed {
+                black_box(full_scan(&matcher, black_box(code)));
+            }
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_match_code);
+criterion_main!(benches);
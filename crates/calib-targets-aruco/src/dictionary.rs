@@ -4,6 +4,31 @@ use serde::{de::Error as DeError, Deserialize, Deserializer, Serialize, Serializ
 use std::error::Error;
 use std::fmt;
 
+/// Order in which a packed marker code enumerates the inner bit grid.
+///
+/// Bit `0` is always the top-left inner cell; the order decides whether the
+/// index advances along rows or columns first.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BitOrder {
+    /// `idx = y * N + x` (OpenCV / built-in dictionaries).
+    #[default]
+    RowMajor,
+    /// `idx = x * N + y`.
+    ColumnMajor,
+}
+
+impl BitOrder {
+    /// Bit index of inner cell `(x, y)` in an `n × n` marker.
+    #[inline]
+    pub fn bit_index(self, x: usize, y: usize, n: usize) -> usize {
+        match self {
+            BitOrder::RowMajor => y * n + x,
+            BitOrder::ColumnMajor => x * n + y,
+        }
+    }
+}
+
 /// A fixed ArUco/AprilTag-style dictionary.
 #[derive(Clone, Copy, Debug)]
 pub struct Dictionary {
@@ -15,8 +40,10 @@ pub struct Dictionary {
     max_correction_bits: u8,
     /// One `u64` per marker id, encoding the inner `marker_size × marker_size` bits.
     ///
-    /// Bits are stored in row-major order with **black = 1**.
+    /// Bits are stored in [`Dictionary::bit_order`] with **black = 1**.
     codes: &'static [u64],
+    /// Bit layout of `codes`.
+    bit_order: BitOrder,
 }
 
 impl Dictionary {
@@ -43,9 +70,19 @@ impl Dictionary {
             marker_size,
             max_correction_bits,
             codes,
+            bit_order: BitOrder::RowMajor,
         }
     }
 
+    /// Declare the bit layout of this dictionary's codes (default
+    /// [`BitOrder::RowMajor`]). Lets foreign dictionaries that enumerate bits
+    /// column-first be used without re-encoding their codes.
+    #[inline]
+    pub const fn with_bit_order(mut self, bit_order: BitOrder) -> Self {
+        self.bit_order = bit_order;
+        self
+    }
+
     fn validate(&self) -> Result<(), DictionaryError> {
         if self.name.is_empty() {
             return Err(DictionaryError::EmptyName);
@@ -104,6 +141,21 @@ impl Dictionary {
         self.codes
     }
 
+    /// Every marker `(id, code)` pair in ascending id order, with codes packed
+    /// as in [`Dictionary::codes`].
+    pub fn iter_codes(&self) -> impl Iterator<Item = (u32, u64)> {
+        self.codes
+            .iter()
+            .enumerate()
+            .map(|(id, &code)| (id as u32, code))
+    }
+
+    /// Bit layout of [`Dictionary::codes`].
+    #[inline]
+    pub fn bit_order(&self) -> BitOrder {
+        self.bit_order
+    }
+
     /// Total number of inner bits per marker.
     #[inline]
     pub fn bit_count(&self) -> usize {
@@ -177,3 +229,20 @@ impl<'de> Deserialize<'de> for Dictionary {
             .ok_or_else(|| D::Error::custom(format!("unknown dictionary {name}")))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::builtins::builtin_dictionary;
+
+    #[test]
+    fn iter_codes_yields_every_id_in_order() {
+        let dict = builtin_dictionary("DICT_4X4_50").expect("builtin dict");
+        let entries: Vec<(u32, u64)> = dict.iter_codes().collect();
+        assert_eq!(entries.len(), 50);
+        assert!(entries
+            .iter()
+            .enumerate()
+            .all(|(k, &(id, _))| id == k as u32));
+        assert_eq!(entries[17].1, dict.codes()[17]);
+    }
+}
@@ -37,11 +37,12 @@ mod matcher;
 mod scan;
 mod threshold;
 
-pub use dictionary::{Dictionary, DictionaryError};
-pub use matcher::{rotate_code_u64, Match, Matcher};
+pub use dictionary::{BitOrder, Dictionary, DictionaryError};
+pub use matcher::{identify_dictionary, rotate_code_u64, Match, Matcher};
 pub use scan::{
     decode_marker_in_cell, sample_cell, scan_decode_markers, scan_decode_markers_in_cells,
-    ArucoScanConfig, CellSamples, MarkerCell, MarkerDetection, ScanDecodeConfig,
+    scan_decode_markers_with_debug, ArucoScanConfig, BorderPolicy, CellSamples, MarkerCell,
+    MarkerDetection, ScanDebug, ScanDecodeConfig,
 };
 
 // Re-export the core image-view type this crate's scan API names, so depending
@@ -1,6 +1,7 @@
 //! Dictionary matching and rotation helpers.
 
-use crate::Dictionary;
+use crate::{BitOrder, Dictionary};
+use std::collections::HashMap;
 
 /// A dictionary match for an observed marker code.
 #[non_exhaustive]
@@ -27,13 +28,23 @@ impl Match {
 
 /// Matcher for a fixed dictionary.
 ///
-/// Implementation note: this uses a brute-force search over all ids and rotations.
-/// For typical dictionary sizes (<=1000) this is fast enough and keeps memory small.
+/// Implementation note: every rotated code is bucketed by popcount. A code
+/// `h` bits away from the observation differs from it in popcount by at most
+/// `h`, so the search visits buckets in order of popcount distance, returns
+/// on the first exact hit and stops once no farther bucket can hold a
+/// closer code. This skips most of a 1000-id dictionary per cell while
+/// returning exactly what a full scan would.
 #[derive(Clone, Debug)]
 pub struct Matcher {
     dict: Dictionary,
     max_hamming: u8,
     rotated: Vec<[u64; 4]>,
+    /// Every rotated code as `(code, id, rotation)`, grouped by popcount and
+    /// in `(id, rotation)` order within a group.
+    by_popcount: Vec<(u64, u32, u8)>,
+    /// `bucket_start[p]..bucket_start[p + 1]` spans popcount `p` in
+    /// `by_popcount`.
+    bucket_start: Vec<usize>,
 }
 
 impl Matcher {
@@ -47,20 +58,39 @@ impl Matcher {
             bits
         );
 
+        let n = dict.marker_size();
+        let order = dict.bit_order();
         let mut rotated = Vec::with_capacity(dict.codes().len());
         for &base in dict.codes() {
             rotated.push([
-                rotate_code_u64(base, dict.marker_size(), 0),
-                rotate_code_u64(base, dict.marker_size(), 1),
-                rotate_code_u64(base, dict.marker_size(), 2),
-                rotate_code_u64(base, dict.marker_size(), 3),
+                rotate_code_u64(base, n, 0, order),
+                rotate_code_u64(base, n, 1, order),
+                rotate_code_u64(base, n, 2, order),
+                rotate_code_u64(base, n, 3, order),
             ]);
         }
 
+        let mut by_popcount: Vec<(u64, u32, u8)> = rotated
+            .iter()
+            .enumerate()
+            .flat_map(|(id, rots)| (0..4u8).map(move |rot| (rots[rot as usize], id as u32, rot)))
+            .collect();
+        // Stable: `(id, rotation)` order survives within each bucket.
+        by_popcount.sort_by_key(|&(code, _, _)| code.count_ones());
+        let mut bucket_start = vec![0; 66];
+        for &(code, _, _) in &by_popcount {
+            bucket_start[code.count_ones() as usize + 1] += 1;
+        }
+        for p in 1..bucket_start.len() {
+            bucket_start[p] += bucket_start[p - 1];
+        }
+
         Self {
             dict,
             max_hamming,
             rotated,
+            by_popcount,
+            bucket_start,
         }
     }
 
@@ -76,30 +106,44 @@ impl Matcher {
         self.max_hamming
     }
 
+    /// Dictionary code of `id` rotated by `rotation` quarter turns, i.e. the
+    /// code a perfect observation of that [`Match`] reads. `None` for an id
+    /// outside the dictionary.
+    #[inline]
+    pub fn rotated_code(&self, id: u32, rotation: u8) -> Option<u64> {
+        self.rotated
+            .get(id as usize)
+            .map(|rots| rots[(rotation & 3) as usize])
+    }
+
     /// Find the best match within `max_hamming`.
+    ///
+    /// The lowest Hamming distance wins; ties go to the lower id, then the
+    /// lower rotation.
     pub fn match_code(&self, observed: u64) -> Option<Match> {
+        let p = observed.count_ones() as usize;
         let mut best: Option<Match> = None;
 
-        for (id, rots) in self.rotated.iter().enumerate() {
-            for (rot, &cand) in rots.iter().enumerate() {
-                let h = (observed ^ cand).count_ones() as u8;
-                if h > self.max_hamming {
-                    continue;
-                }
-                let m = Match {
-                    id: id as u32,
-                    rotation: rot as u8,
-                    hamming: h,
-                };
-                match best {
-                    None => best = Some(m),
-                    Some(prev) => {
-                        if m.hamming < prev.hamming {
-                            best = Some(m);
-                            if m.hamming == 0 {
-                                return best;
-                            }
+        for delta in 0..=self.max_hamming as usize {
+            // Codes in this bucket pair are at least `delta` bits away.
+            if best.is_some_and(|b| (b.hamming as usize) < delta) {
+                break;
+            }
+            let below = p.checked_sub(delta);
+            let above = (delta > 0).then_some(p + delta);
+            for q in [below, above].into_iter().flatten() {
+                for &(cand, id, rotation) in self.bucket(q) {
+                    let hamming = (observed ^ cand).count_ones() as u8;
+                    if hamming > self.max_hamming {
+                        continue;
+                    }
+                    let m = Match::new(id, rotation, hamming);
+                    if best.is_none_or(|b| (hamming, id, rotation) < (b.hamming, b.id, b.rotation))
+                    {
+                        if hamming == 0 {
+                            return Some(m);
                         }
+                        best = Some(m);
                     }
                 }
             }
@@ -107,10 +151,72 @@ impl Matcher {
 
         best
     }
+
+    /// Rotated codes with popcount `q`; empty past the widest code.
+    fn bucket(&self, q: usize) -> &[(u64, u32, u8)] {
+        match (self.bucket_start.get(q), self.bucket_start.get(q + 1)) {
+            (Some(&lo), Some(&hi)) => &self.by_popcount[lo..hi],
+            _ => &[],
+        }
+    }
+
+    /// Match a batch of observed codes; `out[k]` is `match_code(observed[k])`.
+    ///
+    /// Repeated codes are matched once and the result reused, which pays off
+    /// for offline pipelines that decode the same board across many frames.
+    pub fn match_codes(&self, observed: &[u64]) -> Vec<Option<Match>> {
+        let mut seen: HashMap<u64, Option<Match>> = HashMap::with_capacity(observed.len());
+        observed
+            .iter()
+            .map(|&code| *seen.entry(code).or_insert_with(|| self.match_code(code)))
+            .collect()
+    }
+}
+
+/// Pick the built-in dictionary that best explains a sample of observed codes.
+///
+/// Each name in `candidates` is resolved with
+/// [`builtin_dictionary`](crate::builtins::builtin_dictionary) (unknown names
+/// are skipped) and scored by how many of `codes` it matches within
+/// `max_hamming`, in any rotation; ties go to the lower summed Hamming
+/// distance, then to the earlier candidate. Returns `None` when no candidate
+/// matches any code.
+///
+/// Codes must be packed the way the scan decoder reports them
+/// ([`MarkerDetection::code`](crate::MarkerDetection::code)), so sample each
+/// candidate size separately when dictionaries differ in marker size.
+pub fn identify_dictionary(
+    codes: &[u64],
+    candidates: &[&str],
+    max_hamming: u8,
+) -> Option<&'static str> {
+    let mut best: Option<(usize, u32, &'static str)> = None;
+    for name in candidates {
+        let Some(dict) = crate::builtins::builtin_dictionary(name) else {
+            continue;
+        };
+        let matcher = Matcher::new(dict, max_hamming);
+        let (mut hits, mut hamming) = (0usize, 0u32);
+        for m in codes.iter().filter_map(|&code| matcher.match_code(code)) {
+            hits += 1;
+            hamming += m.hamming as u32;
+        }
+        if hits == 0 {
+            continue;
+        }
+        let better = best.is_none_or(|(best_hits, best_hamming, _)| {
+            hits > best_hits || (hits == best_hits && hamming < best_hamming)
+        });
+        if better {
+            best = Some((hits, hamming, dict.name()));
+        }
+    }
+    best.map(|(_, _, name)| name)
 }
 
-/// Rotate a code stored in row-major bits: `idx = y * N + x`.
-pub fn rotate_code_u64(code: u64, n: usize, rot: u8) -> u64 {
+/// Rotate an `n × n` code by `rot` quarter turns; `order` is the bit layout
+/// of both the input and the output code.
+pub fn rotate_code_u64(code: u64, n: usize, rot: u8, order: BitOrder) -> u64 {
     let rot = rot & 3;
     if rot == 0 {
         return code;
@@ -130,8 +236,8 @@ pub fn rotate_code_u64(code: u64, n: usize, rot: u8) -> u64 {
                 2 => (n - 1 - x, n - 1 - y),
                 _ => (n - 1 - y, x),
             };
-            let sidx = sy * n + sx;
-            let didx = y * n + x;
+            let sidx = order.bit_index(sx, sy, n);
+            let didx = order.bit_index(x, y, n);
             out |= get(code, sidx) << didx;
         }
     }
@@ -147,11 +253,13 @@ mod tests {
     fn rotate_four_times_is_identity() {
         let code = 0x0123_4567_89ab_cdef_u64;
         let n = 8;
-        let r = rotate_code_u64(code, n, 1);
-        let r = rotate_code_u64(r, n, 1);
-        let r = rotate_code_u64(r, n, 1);
-        let r = rotate_code_u64(r, n, 1);
-        assert_eq!(code, r);
+        for order in [BitOrder::RowMajor, BitOrder::ColumnMajor] {
+            let r = rotate_code_u64(code, n, 1, order);
+            let r = rotate_code_u64(r, n, 1, order);
+            let r = rotate_code_u64(r, n, 1, order);
+            let r = rotate_code_u64(r, n, 1, order);
+            assert_eq!(code, r);
+        }
     }
 
     #[test]
@@ -160,10 +268,90 @@ mod tests {
         let matcher = Matcher::new(dict, 0);
 
         let base = dict.codes()[0];
-        let observed = rotate_code_u64(base, dict.marker_size(), 1);
+        let observed = rotate_code_u64(base, dict.marker_size(), 1, BitOrder::RowMajor);
         let m = matcher.match_code(observed).expect("match");
         assert_eq!(m.id, 0);
         assert_eq!(m.rotation, 1);
         assert_eq!(m.hamming, 0);
     }
+
+    #[test]
+    fn bucketed_search_matches_a_full_scan() {
+        let dict = builtins::builtin_dictionary("DICT_6X6_1000").expect("builtin dict");
+        let matcher = Matcher::new(dict, 3);
+        let full_scan = |observed: u64| {
+            let mut best: Option<Match> = None;
+            for id in 0..dict.codes().len() as u32 {
+                for rot in 0..4 {
+                    let code = matcher.rotated_code(id, rot).expect("id in range");
+                    let h = (observed ^ code).count_ones() as u8;
+                    if h <= 3 && best.is_none_or(|b| h < b.hamming) {
+                        best = Some(Match::new(id, rot, h));
+                    }
+                }
+            }
+            best
+        };
+
+        // Dictionary codes with 0–4 bits flipped, plus arbitrary words.
+        let mut state = 0x9e37_79b9_7f4a_7c15_u64;
+        for k in 0..400_u64 {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            let id = (state % 1000) as u32;
+            let mut observed = matcher.rotated_code(id, (k % 4) as u8).expect("id");
+            for flip in 0..k % 5 {
+                observed ^= 1 << ((state >> (8 * flip)) % 36);
+            }
+            if k % 10 == 9 {
+                observed = state & ((1 << 36) - 1);
+            }
+            assert_eq!(
+                matcher.match_code(observed),
+                full_scan(observed),
+                "{observed:#x}"
+            );
+        }
+    }
+
+    #[test]
+    fn match_codes_decodes_a_rotated_batch() {
+        let dict = builtins::builtin_dictionary("DICT_4X4_50").expect("builtin dict");
+        let matcher = Matcher::new(dict, 0);
+        let n = dict.marker_size();
+
+        let ids = [3_u32, 17, 3, 42];
+        let mut observed: Vec<u64> = ids
+            .iter()
+            .enumerate()
+            .map(|(k, &id)| {
+                rotate_code_u64(dict.codes()[id as usize], n, k as u8, dict.bit_order())
+            })
+            .collect();
+        observed.push(0);
+
+        let matches = matcher.match_codes(&observed);
+        assert_eq!(matches.len(), observed.len());
+        for (k, &id) in ids.iter().enumerate() {
+            let m = matches[k].expect("match");
+            assert_eq!((m.id, m.rotation, m.hamming), (id, k as u8, 0));
+        }
+        assert_eq!(matches[4], matcher.match_code(0));
+    }
+
+    #[test]
+    fn identify_dictionary_prefers_the_source_dictionary() {
+        let dict = builtins::builtin_dictionary("DICT_4X4_50").expect("builtin dict");
+        let n = dict.marker_size();
+        let codes: Vec<u64> = dict.codes()[..12]
+            .iter()
+            .enumerate()
+            .map(|(i, &c)| rotate_code_u64(c, n, (i % 4) as u8, dict.bit_order()))
+            .collect();
+
+        let found = identify_dictionary(&codes, &["DICT_5X5_50", "DICT_4X4_50"], 0);
+        assert_eq!(found, Some("DICT_4X4_50"));
+        assert_eq!(identify_dictionary(&codes, &["NOT_A_DICT"], 0), None);
+    }
 }
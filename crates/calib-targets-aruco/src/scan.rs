@@ -1,16 +1,58 @@
 //! Marker decoding from rectified grids or per-cell image quads.
 
 use crate::threshold::{compute_threshold_candidates, otsu_threshold_from_samples};
-use crate::Matcher;
+use crate::{BitOrder, Matcher};
 use calib_targets_core::{
-    cell_rect_corners_at, homography_from_4pt, Coord, GrayImageView, Homography,
+    cell_rect_corners_at, homography_from_4pt, Coord, GrayImage, GrayImageView, Homography,
 };
 use nalgebra::Point2;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 #[cfg(feature = "tracing")]
 use tracing::instrument;
 
+/// How pixel reads outside the image are padded while sampling a cell.
+///
+/// Only the 3×3 neighbourhood of a sample can leave the image; a sample whose
+/// centre pixel lies outside is always rejected.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BorderPolicy {
+    /// Read `0` (black), and reject any sample whose 3×3 neighbourhood
+    /// crosses the image edge, as sampling did before this policy existed.
+    /// Cells touching the frame edge are mostly dropped; those that survive
+    /// are biased towards a black marker border.
+    Zero,
+    /// Repeat the nearest edge pixel.
+    #[default]
+    Clamp,
+    /// Mirror about the edge pixel (`-1 → 1`, `w → w - 2`).
+    Reflect,
+}
+
+impl BorderPolicy {
+    /// Map a possibly out-of-range coordinate into `0..len`, or `None` when
+    /// the read should return `0`.
+    #[inline]
+    fn resolve(self, v: i32, len: usize) -> Option<usize> {
+        let n = len as i32;
+        if (0..n).contains(&v) {
+            return Some(v as usize);
+        }
+        if n == 0 {
+            return None;
+        }
+        match self {
+            Self::Zero => None,
+            Self::Clamp => Some(v.clamp(0, n - 1) as usize),
+            Self::Reflect => {
+                let r = if v < 0 { -v } else { 2 * (n - 1) - v };
+                Some(r.clamp(0, n - 1) as usize)
+            }
+        }
+    }
+}
+
 /// Decoder configuration for scanning markers.
 #[non_exhaustive]
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -33,6 +75,51 @@ pub struct ScanDecodeConfig {
     /// one that yields a valid dictionary match. Improves recall on blurry or
     /// unevenly-lit images at a small compute cost.
     pub multi_threshold: bool,
+    /// Padding for pixel reads that fall outside the image.
+    pub border_policy: BorderPolicy,
+    /// If true, fit the sampling window per cell to the marker's dark outer
+    /// border instead of applying the fixed [`Self::inset_frac`]. Sides where
+    /// no light-to-dark edge is found keep the fixed inset. Applies to the
+    /// cell-quad decoders; the rectified-grid scan keeps the fixed inset.
+    pub auto_inset: bool,
+    /// Multipliers on `px_per_square` tried per cell by the rectified-grid
+    /// scan ([`scan_decode_markers`]); the best-scoring decode wins. Use e.g.
+    /// `[0.9, 1.0, 1.1]` when the square size is only approximately known.
+    /// Empty (the default) decodes at `px_per_square` only. The cell-quad
+    /// decoders are scale-free and ignore it.
+    pub scale_search: Vec<f32>,
+    /// Reject a cell when more than this fraction of its bit samples is fully
+    /// saturated (`255`), and leave saturated pixels out of its binarization
+    /// threshold. Guards overexposed captures where glare washes out black
+    /// bits, so the cell is dropped instead of mis-decoded. `None` (the
+    /// default) disables both. Applies to the decoders in this module; the
+    /// ChArUco board matcher samples cells itself and ignores it.
+    pub max_saturated_frac: Option<f32>,
+    /// Stop [`scan_decode_markers_in_cells`] once this many markers are
+    /// decoded (distinct ids when [`Self::dedup_by_id`] is set), visiting
+    /// cells nearest the grid centre first. For callers that only need
+    /// enough markers to align a large board. Every accepted decode counts,
+    /// with no extra confidence floor; tighten [`Self::min_border_score`]
+    /// and the matcher's `max_hamming` to count only confident ones. `None`
+    /// (the default) scans every cell in input order.
+    pub stop_after: Option<usize>,
+    /// Sub-samples averaged per bit before thresholding, spread over a
+    /// `k × k` grid inside the bit (`k` the smallest side with
+    /// `k² >= bit_samples`). `1` (the default) reads only the bit centre;
+    /// `4` suppresses speckle noise at a few extra reads per bit.
+    pub bit_samples: usize,
+    /// Fraction of each bit's side, centred on the bit, that the
+    /// [`Self::bit_samples`] sub-samples spread over. `1.0` (the default)
+    /// spans the whole bit; lower values keep the reads off anti-aliased or
+    /// blurred bit edges. Has no effect with a single sample per bit.
+    pub bit_core_frac: f32,
+    /// Reject a decode when more than this many data bits are ambiguous: the
+    /// four points of a small ring around the bit centre fall on both sides
+    /// of the cell's binarization threshold, i.e. the sample sits on a bit
+    /// boundary. Guards against slightly wrong cell homographies decoding to
+    /// a wrong id. `None` (the default) disables the check. Applies to the
+    /// cell-quad decoders; the rectified-grid scan ignores it.
+    pub max_ambiguous_bits: Option<u32>,
 }
 
 impl Default for ScanDecodeConfig {
@@ -44,6 +131,14 @@ impl Default for ScanDecodeConfig {
             min_border_score: 0.85,
             dedup_by_id: true,
             multi_threshold: true,
+            border_policy: BorderPolicy::Clamp,
+            auto_inset: false,
+            scale_search: Vec::new(),
+            max_saturated_frac: None,
+            stop_after: None,
+            bit_samples: 1,
+            bit_core_frac: 1.0,
+            max_ambiguous_bits: None,
         }
     }
 }
@@ -90,6 +185,62 @@ impl ScanDecodeConfig {
         self.multi_threshold = multi_threshold;
         self
     }
+
+    /// Override the out-of-image padding policy.
+    #[must_use]
+    pub fn with_border_policy(mut self, border_policy: BorderPolicy) -> Self {
+        self.border_policy = border_policy;
+        self
+    }
+
+    /// Set whether to fit the sampling window to each marker's border.
+    #[must_use]
+    pub fn with_auto_inset(mut self, auto_inset: bool) -> Self {
+        self.auto_inset = auto_inset;
+        self
+    }
+
+    /// Override the `px_per_square` multipliers tried by the rectified scan.
+    #[must_use]
+    pub fn with_scale_search(mut self, scale_search: Vec<f32>) -> Self {
+        self.scale_search = scale_search;
+        self
+    }
+
+    /// Override the saturated-sample cap (`None` disables it).
+    #[must_use]
+    pub fn with_max_saturated_frac(mut self, max_saturated_frac: Option<f32>) -> Self {
+        self.max_saturated_frac = max_saturated_frac;
+        self
+    }
+
+    /// Override the early-stop marker count (`None` scans every cell).
+    #[must_use]
+    pub fn with_stop_after(mut self, stop_after: Option<usize>) -> Self {
+        self.stop_after = stop_after;
+        self
+    }
+
+    /// Override the number of sub-samples averaged per bit.
+    #[must_use]
+    pub fn with_bit_samples(mut self, bit_samples: usize) -> Self {
+        self.bit_samples = bit_samples;
+        self
+    }
+
+    /// Override the centred fraction of each bit the sub-samples spread over.
+    #[must_use]
+    pub fn with_bit_core_frac(mut self, bit_core_frac: f32) -> Self {
+        self.bit_core_frac = bit_core_frac;
+        self
+    }
+
+    /// Set the cap on boundary-ambiguous data bits (`None` disables it).
+    #[must_use]
+    pub fn with_max_ambiguous_bits(mut self, max_ambiguous_bits: Option<u32>) -> Self {
+        self.max_ambiguous_bits = max_ambiguous_bits;
+        self
+    }
 }
 
 /// Optional overrides for marker scanning and matching.
@@ -121,6 +272,36 @@ pub struct ArucoScanConfig {
     /// binarization thresholds per cell.
     #[serde(default)]
     pub multi_threshold: Option<bool>,
+    /// Override `ScanDecodeConfig::border_policy` — out-of-image padding.
+    #[serde(default)]
+    pub border_policy: Option<BorderPolicy>,
+    /// Override `ScanDecodeConfig::auto_inset` — fit the sampling window to
+    /// each marker's border.
+    #[serde(default)]
+    pub auto_inset: Option<bool>,
+    /// Override `ScanDecodeConfig::scale_search` — `px_per_square`
+    /// multipliers tried by the rectified scan.
+    #[serde(default)]
+    pub scale_search: Option<Vec<f32>>,
+    /// Set `ScanDecodeConfig::max_saturated_frac` — reject cells with more
+    /// saturated bit samples than this fraction.
+    #[serde(default)]
+    pub max_saturated_frac: Option<f32>,
+    /// Set `ScanDecodeConfig::stop_after` — stop the cell scan once this
+    /// many markers are decoded.
+    #[serde(default)]
+    pub stop_after: Option<usize>,
+    /// Override `ScanDecodeConfig::bit_samples` — sub-samples averaged per bit.
+    #[serde(default)]
+    pub bit_samples: Option<usize>,
+    /// Override `ScanDecodeConfig::bit_core_frac` — centred fraction of each
+    /// bit the sub-samples spread over.
+    #[serde(default)]
+    pub bit_core_frac: Option<f32>,
+    /// Set `ScanDecodeConfig::max_ambiguous_bits` — reject decodes with more
+    /// boundary-ambiguous data bits than this.
+    #[serde(default)]
+    pub max_ambiguous_bits: Option<u32>,
 }
 
 impl ArucoScanConfig {
@@ -144,6 +325,30 @@ impl ArucoScanConfig {
         if let Some(multi_threshold) = self.multi_threshold {
             scan.multi_threshold = multi_threshold;
         }
+        if let Some(border_policy) = self.border_policy {
+            scan.border_policy = border_policy;
+        }
+        if let Some(auto_inset) = self.auto_inset {
+            scan.auto_inset = auto_inset;
+        }
+        if let Some(scale_search) = &self.scale_search {
+            scan.scale_search = scale_search.clone();
+        }
+        if let Some(max_saturated_frac) = self.max_saturated_frac {
+            scan.max_saturated_frac = Some(max_saturated_frac);
+        }
+        if let Some(stop_after) = self.stop_after {
+            scan.stop_after = Some(stop_after);
+        }
+        if let Some(bit_samples) = self.bit_samples {
+            scan.bit_samples = bit_samples;
+        }
+        if let Some(bit_core_frac) = self.bit_core_frac {
+            scan.bit_core_frac = bit_core_frac;
+        }
+        if let Some(max_ambiguous_bits) = self.max_ambiguous_bits {
+            scan.max_ambiguous_bits = Some(max_ambiguous_bits);
+        }
     }
 }
 
@@ -166,8 +371,14 @@ pub struct MarkerDetection {
     /// Fraction of border cells that read as black — the border-pattern
     /// confidence.
     pub border_score: f32,
-    /// Observed inner bits (row-major, black=1).
+    /// Observed inner bits (black=1), packed in the dictionary's [`crate::BitOrder`].
     pub code: u64,
+    /// Dictionary code of the matched id, rotated by [`Self::rotation`] into
+    /// the observed frame and packed like [`Self::code`], so
+    /// `code ^ matched_code` is the bit error pattern. `None` when the
+    /// producer did not record it.
+    #[serde(default)]
+    pub matched_code: Option<u64>,
     /// Whether the decoder inverted polarity to maximize `border_score`.
     pub inverted: bool,
     /// Corners of the square cell in rectified pixels.
@@ -180,6 +391,22 @@ pub struct MarkerDetection {
     pub corners_img: Option<[Point2<f32>; 4]>,
 }
 
+impl MarkerDetection {
+    /// Cell corners reordered so index 0 is the marker's own top-left, then
+    /// TR, BR, BL in the marker's frame.
+    ///
+    /// [`Self::corners_img`] / [`Self::corners_rect`] follow the scan order
+    /// of the cell, so after a [`Self::rotation`] of `r` quarter turns the
+    /// marker's top-left sits at scan corner `r` (the same corner
+    /// [`Self::gc`] points at). Uses `corners_img` when set, otherwise
+    /// `corners_rect`.
+    pub fn canonical_corners(&self) -> [Point2<f32>; 4] {
+        let raw = self.corners_img.unwrap_or(self.corners_rect);
+        let r = (self.rotation & 3) as usize;
+        std::array::from_fn(|k| raw[(k + r) % 4])
+    }
+}
+
 /// One square cell with its image-space corners.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct MarkerCell {
@@ -192,6 +419,9 @@ pub struct MarkerCell {
 /// Scan all square cells `(sx,sy)` in `0..cells_x × 0..cells_y`, read + decode markers.
 ///
 /// This expects a rectified image where one square ~= `px_per_square` pixels.
+/// With a non-empty [`ScanDecodeConfig::scale_search`], each cell is decoded
+/// at every listed multiple of `px_per_square` and the best-scoring decode is
+/// kept; its `corners_rect` use the winning scale.
 pub fn scan_decode_markers(
     rect: &GrayImageView<'_>,
     cells_x: usize,
@@ -202,17 +432,29 @@ pub fn scan_decode_markers(
 ) -> Vec<MarkerDetection> {
     let mut out = Vec::new();
     let bits = matcher.dictionary().marker_size();
+    let scales: &[f32] = if cfg.scale_search.is_empty() {
+        &[1.0]
+    } else {
+        &cfg.scale_search
+    };
 
     for sy in 0..(cells_y as i32) {
         for sx in 0..(cells_x as i32) {
-            let Some(obs) = decode_rectified_cell(rect, sx, sy, px_per_square, cfg, bits, matcher)
-            else {
-                continue;
-            };
             let gc = Coord::new(sx, sy);
-            if let Some(det) = build_detection(gc, px_per_square, obs, matcher) {
-                out.push(det);
+            let mut best: Option<MarkerDetection> = None;
+            for &m in scales {
+                let s = px_per_square * m;
+                let Some(obs) = decode_rectified_cell(rect, sx, sy, s, cfg, bits, matcher) else {
+                    continue;
+                };
+                let Some(det) = build_detection(gc, s, obs, matcher) else {
+                    continue;
+                };
+                if best.as_ref().is_none_or(|b| det.score > b.score) {
+                    best = Some(det);
+                }
             }
+            out.extend(best);
         }
     }
 
@@ -223,9 +465,69 @@ pub fn scan_decode_markers(
     }
 }
 
+/// Output of [`scan_decode_markers_with_debug`].
+#[derive(Clone, Debug)]
+pub struct ScanDebug {
+    /// Same detections as [`scan_decode_markers`] returns.
+    pub detections: Vec<MarkerDetection>,
+    /// Binarization of the rectified image, same size as the input: pixels
+    /// of each cell's sampling window are `0` below that cell's Otsu
+    /// threshold and `255` otherwise. Pixels outside every window, and
+    /// windows of cells that could not be sampled or were rejected as
+    /// saturated, are `127`.
+    pub binary: GrayImage,
+}
+
+/// [`scan_decode_markers`] plus the thresholded image it decoded from.
+///
+/// For telling lighting or threshold problems apart from geometry ones when
+/// markers fail to decode. The binary image shows each cell at the nominal
+/// `px_per_square` and its Otsu threshold; the multi-threshold retries and
+/// [`ScanDecodeConfig::scale_search`] are not reflected in it.
+pub fn scan_decode_markers_with_debug(
+    rect: &GrayImageView<'_>,
+    cells_x: usize,
+    cells_y: usize,
+    px_per_square: f32,
+    cfg: &ScanDecodeConfig,
+    matcher: &Matcher,
+) -> ScanDebug {
+    let detections = scan_decode_markers(rect, cells_x, cells_y, px_per_square, cfg, matcher);
+    let bits = matcher.dictionary().marker_size();
+    let mut binary = GrayImage {
+        width: rect.width,
+        height: rect.height,
+        data: vec![127; rect.width * rect.height],
+    };
+
+    for sy in 0..(cells_y as i32) {
+        for sx in 0..(cells_x as i32) {
+            let Some(cell) = sample_rectified_cell(rect, sx, sy, px_per_square, cfg, bits) else {
+                continue;
+            };
+            let Some(thr) =
+                binarization_threshold(&cell.as_decode_samples(bits, cfg.border_bits), cfg)
+            else {
+                continue;
+            };
+            let side = cell.side as usize;
+            for (i, &v) in cell.thr_samples.iter().enumerate() {
+                let x = cell.x0 as usize + i % side;
+                let y = cell.y0 as usize + i / side;
+                binary.data[y * rect.width + x] = if v < thr { 0 } else { 255 };
+            }
+        }
+    }
+
+    ScanDebug { detections, binary }
+}
+
 /// Decode markers from explicit per-cell image quads.
 ///
 /// This avoids warping the full image and can be parallelized by the caller.
+/// With [`ScanDecodeConfig::stop_after`] set, cells are visited nearest the
+/// centre of their grid coordinates first and the scan ends early once
+/// enough markers are decoded.
 #[cfg_attr(feature = "tracing", instrument(level = "info", skip(image, cells, cfg, matcher),
 fields(cells=cells.len(), w=image.width, h=image.height)))]
 pub fn scan_decode_markers_in_cells(
@@ -235,19 +537,72 @@ pub fn scan_decode_markers_in_cells(
     cfg: &ScanDecodeConfig,
     matcher: &Matcher,
 ) -> Vec<MarkerDetection> {
+    let scan = scan_decode_markers_in_cells_with_stats(image, cells, px_per_square, cfg, matcher);
+    log::debug!(
+        "cell scan visited {}/{} cells, decoded {} markers",
+        scan.cells_visited,
+        cells.len(),
+        scan.detections.len(),
+    );
+    scan.detections
+}
+
+/// Output of [`scan_decode_markers_in_cells_with_stats`].
+#[derive(Clone, Debug)]
+pub(crate) struct CellScanStats {
+    /// Same detections as [`scan_decode_markers_in_cells`] returns.
+    pub(crate) detections: Vec<MarkerDetection>,
+    /// Cells the scan reached and tried to decode. Below the number of input
+    /// cells only when [`ScanDecodeConfig::stop_after`] ended the scan early.
+    pub(crate) cells_visited: usize,
+}
+
+/// [`scan_decode_markers_in_cells`] plus how many cells it visited.
+pub(crate) fn scan_decode_markers_in_cells_with_stats(
+    image: &GrayImageView<'_>,
+    cells: &[MarkerCell],
+    px_per_square: f32,
+    cfg: &ScanDecodeConfig,
+    matcher: &Matcher,
+) -> CellScanStats {
     let mut out = Vec::new();
+    let mut cells_visited = 0;
     let Some(mut decoder) = CellDecoder::new(
         cfg,
         matcher.dictionary().marker_size(),
         px_per_square,
         matcher,
     ) else {
-        return out;
+        return CellScanStats {
+            detections: out,
+            cells_visited,
+        };
     };
 
     let cell_rect = cell_rect_corners_at(Coord::new(0, 0), px_per_square);
 
-    for cell in cells {
+    let mut order: Vec<&MarkerCell> = cells.iter().collect();
+    if cfg.stop_after.is_some() && !cells.is_empty() {
+        let n = cells.len() as f32;
+        let cu = cells.iter().map(|c| c.gc.u as f32).sum::<f32>() / n;
+        let cv = cells.iter().map(|c| c.gc.v as f32).sum::<f32>() / n;
+        let dist2 = |c: &MarkerCell| (c.gc.u as f32 - cu).powi(2) + (c.gc.v as f32 - cv).powi(2);
+        order.sort_by(|a, b| dist2(a).total_cmp(&dist2(b)));
+    }
+    let mut ids = HashSet::new();
+
+    for cell in order {
+        if let Some(limit) = cfg.stop_after {
+            let found = if cfg.dedup_by_id {
+                ids.len()
+            } else {
+                out.len()
+            };
+            if found >= limit {
+                break;
+            }
+        }
+        cells_visited += 1;
         let Some(h) = homography_from_4pt(&cell_rect, &cell.corners_img) else {
             continue;
         };
@@ -262,6 +617,7 @@ pub fn scan_decode_markers_in_cells(
         };
         if let Some(mut det) = build_detection(cell.gc, px_per_square, obs, matcher) {
             det.corners_img = Some(cell.corners_img);
+            ids.insert(det.id);
             out.push(det);
         } else {
             log::debug!(
@@ -274,10 +630,14 @@ pub fn scan_decode_markers_in_cells(
         }
     }
 
-    if cfg.dedup_by_id {
+    let detections = if cfg.dedup_by_id {
         dedup_by_id_keep_best(out)
     } else {
         out
+    };
+    CellScanStats {
+        detections,
+        cells_visited,
     }
 }
 
@@ -294,7 +654,8 @@ pub struct CellSamples {
     pub bits_per_side: usize,
     /// Border ring width, in cells (matches [`ScanDecodeConfig::border_bits`]).
     pub border_bits: usize,
-    /// Row-major mean-of-3x3 intensity per grid cell, `cells_per_side²` values.
+    /// Row-major mean-of-3x3 intensity per grid cell, `cells_per_side²` values,
+    /// averaged over [`ScanDecodeConfig::bit_samples`] reads per cell.
     pub mean_grid: Vec<u8>,
     /// Otsu threshold computed from dense interior samples; in `[0, 255]`.
     pub otsu_threshold: u8,
@@ -319,18 +680,23 @@ pub fn sample_cell(
     let grid = SampleGrid::new(cfg, bits, px_per_square)?;
     let cell_rect = cell_rect_corners_at(Coord::new(0, 0), px_per_square);
     let h = homography_from_4pt(&cell_rect, &cell.corners_img)?;
+    let grid = if cfg.auto_inset {
+        grid.fit_to_border(px_per_square, |p| sample_nearest(image, h.apply(p)))
+            .unwrap_or(grid)
+    } else {
+        grid
+    };
 
-    let mut mean_grid = Vec::with_capacity(grid.points.len());
-    for p in &grid.points {
-        let q = h.apply(*p);
-        let v = sample_mean_3x3(image, q.x, q.y)?;
-        mean_grid.push(v);
-    }
+    let mut mean_grid = Vec::with_capacity(grid.cells * grid.cells);
+    grid.read_bits(&mut mean_grid, |p| {
+        let q = h.apply(p);
+        sample_mean_3x3(image, q.x, q.y, cfg.border_policy)
+    })?;
 
     let mut thr_samples = Vec::with_capacity(grid.threshold_points.len());
     for p in &grid.threshold_points {
         let q = h.apply(*p);
-        if let Some(v) = sample_mean_3x3(image, q.x, q.y) {
+        if let Some(v) = sample_mean_3x3(image, q.x, q.y, cfg.border_policy) {
             thr_samples.push(v);
         }
     }
@@ -405,9 +771,32 @@ struct MarkerObservation {
 
 const MIN_SIDE_PX: f32 = 12.0;
 
+/// Probe positions across each side, as fractions of the cell side, used by
+/// the auto-inset border search.
+const AUTO_INSET_PROBES: [f32; 3] = [0.35, 0.5, 0.65];
+/// Minimum light/dark spread along a probe for its edge to count.
+const AUTO_INSET_MIN_CONTRAST: u8 = 16;
+/// Offset of the ambiguity-ring points from a bit centre, as a fraction of
+/// the bit side.
+const AMBIGUITY_RING_FRAC: f32 = 0.25;
+
+/// Sampling window inside a cell, in cell-local pixels.
+#[derive(Clone, Copy, Debug)]
+struct SampleRect {
+    x0: f32,
+    y0: f32,
+    x1: f32,
+    y1: f32,
+}
+
 struct SampleGrid {
     cells: usize,
-    points: Vec<Point2<f32>>, // row-major: cy * cells + cx
+    /// Side of the sub-sample grid read inside each bit.
+    sub: usize,
+    /// Centred fraction of the bit side the sub-samples spread over.
+    core: f32,
+    rect: SampleRect,
+    points: Vec<Point2<f32>>, // row-major: (cy * cells + cx) * sub² + sub-sample
     threshold_points: Vec<Point2<f32>>,
 }
 
@@ -437,27 +826,178 @@ impl SampleGrid {
             return None;
         }
 
-        let step = side / (cells as f32);
         let start = marker_offset + inset;
+        Some(Self::over(
+            cells,
+            bit_subsample_side(cfg.bit_samples),
+            cfg.bit_core_frac,
+            SampleRect {
+                x0: start,
+                y0: start,
+                x1: start + side,
+                y1: start + side,
+            },
+        ))
+    }
+
+    fn over(cells: usize, sub: usize, core: f32, rect: SampleRect) -> Self {
+        let step_x = (rect.x1 - rect.x0) / cells as f32;
+        let step_y = (rect.y1 - rect.y0) / cells as f32;
 
-        let mut points = Vec::with_capacity(cells * cells);
+        let mut points = Vec::with_capacity(cells * cells * sub * sub);
         for cy in 0..cells {
             for cx in 0..cells {
-                points.push(Point2::new(
-                    start + (cx as f32 + 0.5) * step,
-                    start + (cy as f32 + 0.5) * step,
-                ));
+                for (fx, fy) in bit_subsample_offsets(sub, core) {
+                    points.push(Point2::new(
+                        rect.x0 + (cx as f32 + fx) * step_x,
+                        rect.y0 + (cy as f32 + fy) * step_y,
+                    ));
+                }
             }
         }
 
-        let threshold_points = build_threshold_points(start, side, cells);
-
-        Some(Self {
+        Self {
             cells,
+            sub,
+            core,
+            rect,
             points,
-            threshold_points,
-        })
+            threshold_points: build_threshold_points(&rect, cells),
+        }
+    }
+
+    /// Append one value per grid cell to `out`: the mean of `read` over the
+    /// cell's sub-samples. `None` as soon as any read is `None`.
+    fn read_bits(&self, out: &mut Vec<u8>, read: impl Fn(Point2<f32>) -> Option<u8>) -> Option<()> {
+        let per_bit = self.sub * self.sub;
+        for bit_points in self.points.chunks(per_bit) {
+            let mut sum = 0u32;
+            for p in bit_points {
+                sum += read(*p)? as u32;
+            }
+            out.push((sum / per_bit as u32) as u8);
+        }
+        Some(())
+    }
+
+    /// Number of data bits (inside a `border`-wide ring) whose ambiguity
+    /// ring straddles `thr`: its four points, offset from the bit centre by
+    /// [`AMBIGUITY_RING_FRAC`] of a bit, do not all binarize alike. `None`
+    /// as soon as any read is `None`.
+    fn count_ambiguous_bits(
+        &self,
+        border: usize,
+        thr: u8,
+        read: impl Fn(Point2<f32>) -> Option<u8>,
+    ) -> Option<u32> {
+        let step_x = (self.rect.x1 - self.rect.x0) / self.cells as f32;
+        let step_y = (self.rect.y1 - self.rect.y0) / self.cells as f32;
+        let (dx, dy) = (AMBIGUITY_RING_FRAC * step_x, AMBIGUITY_RING_FRAC * step_y);
+        let mut ambiguous = 0;
+        for cy in border..self.cells.saturating_sub(border) {
+            for cx in border..self.cells.saturating_sub(border) {
+                let centre = Point2::new(
+                    self.rect.x0 + (cx as f32 + 0.5) * step_x,
+                    self.rect.y0 + (cy as f32 + 0.5) * step_y,
+                );
+                let mut dark = 0;
+                for (ox, oy) in [(-dx, 0.0), (dx, 0.0), (0.0, -dy), (0.0, dy)] {
+                    if read(Point2::new(centre.x + ox, centre.y + oy))? < thr {
+                        dark += 1;
+                    }
+                }
+                if dark != 0 && dark != 4 {
+                    ambiguous += 1;
+                }
+            }
+        }
+        Some(ambiguous)
+    }
+
+    /// Re-fit the window to the marker's dark outer border.
+    ///
+    /// `sample` reads the pixel at a cell-local point. Each side is probed
+    /// from the cell edge inwards for its first light-to-dark transition;
+    /// sides without one keep this grid's bound. Returns `None` when the
+    /// fitted window is too small to decode.
+    fn fit_to_border(
+        &self,
+        px_per_square: f32,
+        sample: impl Fn(Point2<f32>) -> Option<u8>,
+    ) -> Option<Self> {
+        let s = px_per_square;
+        let depth_at = |side: usize, t: f32, across: f32| match side {
+            0 => Point2::new(t, across),
+            1 => Point2::new(across, t),
+            2 => Point2::new(s - t, across),
+            _ => Point2::new(across, s - t),
+        };
+        let steps = (0.5 * s).floor() as usize;
+
+        let mut depth = [None; 4];
+        for (side, slot) in depth.iter_mut().enumerate() {
+            let mut found: Vec<f32> = AUTO_INSET_PROBES
+                .iter()
+                .filter_map(|&frac| {
+                    let profile = (0..steps)
+                        .map(|t| sample(depth_at(side, t as f32 + 0.5, frac * s)))
+                        .collect::<Option<Vec<u8>>>()?;
+                    first_dark_edge(&profile)
+                })
+                .collect();
+            if found.len() * 2 > AUTO_INSET_PROBES.len() {
+                found.sort_by(f32::total_cmp);
+                *slot = Some(found[found.len() / 2]);
+            }
+        }
+
+        let rect = SampleRect {
+            x0: depth[0].unwrap_or(self.rect.x0),
+            y0: depth[1].unwrap_or(self.rect.y0),
+            x1: depth[2].map_or(self.rect.x1, |d| s - d),
+            y1: depth[3].map_or(self.rect.y1, |d| s - d),
+        };
+        if rect.x1 - rect.x0 < MIN_SIDE_PX || rect.y1 - rect.y0 < MIN_SIDE_PX {
+            return None;
+        }
+        Some(Self::over(self.cells, self.sub, self.core, rect))
+    }
+}
+
+/// Side `k` of the `k × k` sub-sample grid holding at least `bit_samples`
+/// reads per bit (`1` for `0` or `1`).
+fn bit_subsample_side(bit_samples: usize) -> usize {
+    let mut k = 1;
+    while k * k < bit_samples {
+        k += 1;
+    }
+    k
+}
+
+/// Sub-sample positions inside one bit, as fractions of the bit side, spread
+/// over the centred `core` fraction of the bit. A side of `1` yields the bit
+/// centre alone.
+fn bit_subsample_offsets(sub: usize, core: f32) -> impl Iterator<Item = (f32, f32)> {
+    let core = core.clamp(0.0, 1.0);
+    let frac = move |i: usize| 0.5 + ((i as f32 + 0.5) / sub as f32 - 0.5) * core;
+    (0..sub).flat_map(move |j| (0..sub).map(move |i| (frac(i), frac(j))))
+}
+
+/// Depth along `profile` (one sample per pixel, sample `t` at `t + 0.5`) of
+/// the first light-to-dark crossing of the profile's mid level. Leading dark
+/// samples (e.g. a neighbouring square bleeding over the cell edge) are
+/// skipped.
+fn first_dark_edge(profile: &[u8]) -> Option<f32> {
+    let lo = *profile.iter().min()?;
+    let hi = *profile.iter().max()?;
+    if hi - lo < AUTO_INSET_MIN_CONTRAST {
+        return None;
     }
+    let thr = 0.5 * (lo as f32 + hi as f32);
+    profile.windows(2).enumerate().find_map(|(t, w)| {
+        let (a, b) = (w[0] as f32, w[1] as f32);
+        (a >= thr && b < thr).then(|| t as f32 + 0.5 + (a - thr) / (a - b))
+    })
 }
 
 struct CellDecoder<'a> {
@@ -465,6 +1005,7 @@ struct CellDecoder<'a> {
     matcher: &'a Matcher,
     bits: usize,
     border: usize,
+    px_per_square: f32,
     grid: SampleGrid,
     scratch_bits: Vec<u8>,
     scratch_thr: Vec<u8>,
@@ -478,13 +1019,14 @@ impl<'a> CellDecoder<'a> {
         matcher: &'a Matcher,
     ) -> Option<Self> {
         let grid = SampleGrid::new(cfg, bits, px_per_square)?;
-        let scratch_bits = Vec::with_capacity(grid.points.len());
+        let scratch_bits = Vec::with_capacity(grid.cells * grid.cells);
         let scratch_thr = Vec::with_capacity(grid.threshold_points.len());
         Some(Self {
             cfg,
             matcher,
             bits,
             border: cfg.border_bits,
+            px_per_square,
             grid,
             scratch_bits,
             scratch_thr,
@@ -496,17 +1038,24 @@ impl<'a> CellDecoder<'a> {
         img: &GrayImageView<'_>,
         h: &Homography,
     ) -> Option<MarkerObservation> {
+        let fitted = if self.cfg.auto_inset {
+            self.grid
+                .fit_to_border(self.px_per_square, |p| sample_nearest(img, h.apply(p)))
+        } else {
+            None
+        };
+        let sample_grid = fitted.as_ref().unwrap_or(&self.grid);
+
         self.scratch_bits.clear();
-        for p in &self.grid.points {
-            let q = h.apply(*p);
-            let v = sample_mean_3x3(img, q.x, q.y)?;
-            self.scratch_bits.push(v);
-        }
+        sample_grid.read_bits(&mut self.scratch_bits, |p| {
+            let q = h.apply(p);
+            sample_mean_3x3(img, q.x, q.y, self.cfg.border_policy)
+        })?;
 
         self.scratch_thr.clear();
-        for p in &self.grid.threshold_points {
+        for p in &sample_grid.threshold_points {
             let q = h.apply(*p);
-            if let Some(v) = sample_mean_3x3(img, q.x, q.y) {
+            if let Some(v) = sample_mean_3x3(img, q.x, q.y, self.cfg.border_policy) {
                 self.scratch_thr.push(v);
             }
         }
@@ -514,16 +1063,23 @@ impl<'a> CellDecoder<'a> {
         let grid = DecodeSamples {
             samples: &self.scratch_bits,
             thr_samples: &self.scratch_thr,
-            cells: self.grid.cells,
+            cells: sample_grid.cells,
             bits: self.bits,
             border: self.border,
         };
-        decode_samples(
-            &grid,
-            self.cfg.min_border_score,
-            self.matcher,
-            self.cfg.multi_threshold,
-        )
+        let obs = decode_samples(&grid, self.cfg, self.matcher)?;
+        if let Some(max_ambiguous) = self.cfg.max_ambiguous_bits {
+            let thr = binarization_threshold(&grid, self.cfg)?;
+            let ambiguous = sample_grid.count_ambiguous_bits(self.border, thr, |p| {
+                let q = h.apply(p);
+                sample_mean_3x3(img, q.x, q.y, self.cfg.border_policy)
+            })?;
+            if ambiguous > max_ambiguous {
+                log::debug!("cell rejected: {ambiguous} ambiguous bits exceed {max_ambiguous}");
+                return None;
+            }
+        }
+        Some(obs)
     }
 }
 
@@ -559,21 +1115,47 @@ fn build_detection(
         score,
         border_score: obs.border_score,
         code: obs.code,
+        matched_code: matcher.rotated_code(m.id, m.rotation),
         inverted: obs.inverted,
         corners_rect: corners,
         corners_img: None,
     })
 }
 
-fn decode_rectified_cell(
+/// Pixel samples of one rectified-grid cell, read by [`sample_rectified_cell`].
+struct RectifiedCell {
+    /// Top-left pixel of the sampling window.
+    x0: i32,
+    y0: i32,
+    /// Window side in pixels.
+    side: i32,
+    cells: usize,
+    /// Per-bit means, row-major `cells²`.
+    samples: Vec<u8>,
+    /// Every pixel of the window, row-major `side²`.
+    thr_samples: Vec<u8>,
+}
+
+impl RectifiedCell {
+    fn as_decode_samples(&self, bits: usize, border: usize) -> DecodeSamples<'_> {
+        DecodeSamples {
+            samples: &self.samples,
+            thr_samples: &self.thr_samples,
+            cells: self.cells,
+            bits,
+            border,
+        }
+    }
+}
+
+fn sample_rectified_cell(
     rect: &GrayImageView<'_>,
     sx: i32,
     sy: i32,
     px_per_square: f32,
     cfg: &ScanDecodeConfig,
     bits: usize,
-    matcher: &Matcher,
-) -> Option<MarkerObservation> {
+) -> Option<RectifiedCell> {
     let border = cfg.border_bits;
     let cells = bits + 2 * border;
     if bits * bits > 64 || cells == 0 {
@@ -604,43 +1186,68 @@ fn decode_rectified_cell(
     let mut thr_samples = Vec::with_capacity((side * side) as usize);
     for yy in 0..side {
         for xx in 0..side {
-            thr_samples.push(get_gray(rect, x0 + xx, y0 + yy));
+            thr_samples.push(get_gray(rect, x0 + xx, y0 + yy, cfg.border_policy));
         }
     }
 
     let step = side as f32 / (cells as f32);
+    let sub = bit_subsample_side(cfg.bit_samples);
+    let per_bit = (sub * sub) as u32;
     let mut samples = Vec::with_capacity(cells * cells);
     for cy in 0..cells {
         for cx in 0..cells {
-            let rx = x0 as f32 + (cx as f32 + 0.5) * step;
-            let ry = y0 as f32 + (cy as f32 + 0.5) * step;
-            let v = sample_mean_3x3_clamped(rect, rx, ry);
-            samples.push(v);
+            let sum: u32 = bit_subsample_offsets(sub, cfg.bit_core_frac)
+                .map(|(fx, fy)| {
+                    let rx = x0 as f32 + (cx as f32 + fx) * step;
+                    let ry = y0 as f32 + (cy as f32 + fy) * step;
+                    sample_mean_3x3_padded(rect, rx, ry, cfg.border_policy) as u32
+                })
+                .sum();
+            samples.push((sum / per_bit) as u8);
         }
     }
 
-    let grid = DecodeSamples {
-        samples: &samples,
-        thr_samples: &thr_samples,
+    Some(RectifiedCell {
+        x0,
+        y0,
+        side,
         cells,
-        bits,
-        border,
-    };
-    decode_samples(&grid, cfg.min_border_score, matcher, cfg.multi_threshold)
+        samples,
+        thr_samples,
+    })
 }
 
-/// Binarize `samples` at `thr` for one polarity and return border_score + code.
+fn decode_rectified_cell(
+    rect: &GrayImageView<'_>,
+    sx: i32,
+    sy: i32,
+    px_per_square: f32,
+    cfg: &ScanDecodeConfig,
+    bits: usize,
+    matcher: &Matcher,
+) -> Option<MarkerObservation> {
+    let cell = sample_rectified_cell(rect, sx, sy, px_per_square, cfg, bits)?;
+    decode_samples(&cell.as_decode_samples(bits, cfg.border_bits), cfg, matcher)
+}
+
+/// Binarize `grid.samples` at `thr` for one polarity and return
+/// border_score + code, packing the inner bits in `order`.
 ///
 /// Returns `None` if `border_score < min_border_score`.
 fn binarize_and_score(
-    samples: &[u8],
-    cells: usize,
-    bits: usize,
-    border: usize,
+    grid: &DecodeSamples<'_>,
+    order: BitOrder,
     thr: u8,
     inverted: bool,
     min_border_score: f32,
 ) -> Option<MarkerObservation> {
+    let DecodeSamples {
+        samples,
+        cells,
+        bits,
+        border,
+        ..
+    } = *grid;
     let use_border = border > 0;
     let mut border_ok = 0u32;
     let mut border_total = 0u32;
@@ -664,7 +1271,7 @@ fn binarize_and_score(
                 let bx = cx - border;
                 let by = cy - border;
                 let bit = if is_black { 1u64 } else { 0u64 };
-                code |= bit << (by * bits + bx); // row-major
+                code |= bit << order.bit_index(bx, by, bits);
             }
         }
     }
@@ -700,36 +1307,26 @@ struct DecodeSamples<'a> {
 
 fn decode_samples(
     grid: &DecodeSamples<'_>,
-    min_border_score: f32,
+    cfg: &ScanDecodeConfig,
     matcher: &Matcher,
-    multi_threshold: bool,
 ) -> Option<MarkerObservation> {
     if grid.samples.len() != grid.cells * grid.cells {
         return None;
     }
+    let min_border_score = cfg.min_border_score;
+    let otsu = binarization_threshold(grid, cfg)?;
+    // Pack observed bits in the dictionary's own layout so they compare
+    // directly against its codes.
+    let order = matcher.dictionary().bit_order();
 
-    let thr_src = if grid.thr_samples.is_empty() {
-        grid.samples
-    } else {
-        grid.thr_samples
-    };
-    let otsu = otsu_threshold_from_samples(thr_src);
-
-    if multi_threshold {
+    if cfg.multi_threshold {
         let candidates = compute_threshold_candidates(otsu, grid.samples, grid.cells, grid.border);
         let mut best_matched: Option<MarkerObservation> = None;
 
         'outer: for &thr in &candidates {
             for inverted in [false, true] {
-                let Some(obs) = binarize_and_score(
-                    grid.samples,
-                    grid.cells,
-                    grid.bits,
-                    grid.border,
-                    thr,
-                    inverted,
-                    min_border_score,
-                ) else {
+                let Some(obs) = binarize_and_score(grid, order, thr, inverted, min_border_score)
+                else {
                     continue;
                 };
                 if let Some(m) = matcher.match_code(obs.code) {
@@ -757,15 +1354,7 @@ fn decode_samples(
     // Single-threshold fallback: Otsu only, keep best by border_score.
     let mut best: Option<MarkerObservation> = None;
     for inverted in [false, true] {
-        let Some(obs) = binarize_and_score(
-            grid.samples,
-            grid.cells,
-            grid.bits,
-            grid.border,
-            otsu,
-            inverted,
-            min_border_score,
-        ) else {
+        let Some(obs) = binarize_and_score(grid, order, otsu, inverted, min_border_score) else {
             continue;
         };
         if best
@@ -779,6 +1368,37 @@ fn decode_samples(
     best
 }
 
+/// Otsu threshold `decode_samples` binarizes `grid` at: computed from
+/// `grid.thr_samples` (or `grid.samples` when empty), minus saturated pixels
+/// under [`ScanDecodeConfig::max_saturated_frac`]. `None` when that cap
+/// rejects the cell.
+fn binarization_threshold(grid: &DecodeSamples<'_>, cfg: &ScanDecodeConfig) -> Option<u8> {
+    let thr_src = if grid.thr_samples.is_empty() {
+        grid.samples
+    } else {
+        grid.thr_samples
+    };
+    let unsaturated: Vec<u8>;
+    let thr_src = match cfg.max_saturated_frac {
+        Some(max_frac) => {
+            let saturated = grid.samples.iter().filter(|&&v| v == u8::MAX).count();
+            let frac = saturated as f32 / grid.samples.len().max(1) as f32;
+            if frac > max_frac {
+                log::debug!("cell rejected: saturated fraction {frac:.3} exceeds {max_frac:.3}");
+                return None;
+            }
+            unsaturated = thr_src.iter().copied().filter(|&v| v < u8::MAX).collect();
+            if unsaturated.is_empty() {
+                thr_src
+            } else {
+                &unsaturated
+            }
+        }
+        None => thr_src,
+    };
+    Some(otsu_threshold_from_samples(thr_src))
+}
+
 fn dedup_by_id_keep_best(mut dets: Vec<MarkerDetection>) -> Vec<MarkerDetection> {
     dets.sort_by(|a, b| {
         b.score
@@ -797,65 +1417,87 @@ fn dedup_by_id_keep_best(mut dets: Vec<MarkerDetection>) -> Vec<MarkerDetection>
     out
 }
 
-fn build_threshold_points(start: f32, side: f32, cells: usize) -> Vec<Point2<f32>> {
+fn build_threshold_points(rect: &SampleRect, cells: usize) -> Vec<Point2<f32>> {
     const THRESH_SUBDIV: usize = 3;
     let grid = (cells * THRESH_SUBDIV).max(cells);
-    let step = side / grid as f32;
+    let step_x = (rect.x1 - rect.x0) / grid as f32;
+    let step_y = (rect.y1 - rect.y0) / grid as f32;
     let mut points = Vec::with_capacity(grid * grid);
     for ty in 0..grid {
         for tx in 0..grid {
             points.push(Point2::new(
-                start + (tx as f32 + 0.5) * step,
-                start + (ty as f32 + 0.5) * step,
+                rect.x0 + (tx as f32 + 0.5) * step_x,
+                rect.y0 + (ty as f32 + 0.5) * step_y,
             ));
         }
     }
     points
 }
 
-fn sample_mean_3x3(img: &GrayImageView<'_>, x: f32, y: f32) -> Option<u8> {
+/// 3×3 mean around `(x, y)`; `None` when the centre pixel is outside the
+/// image, or for [`BorderPolicy::Zero`] when any neighbour is. Otherwise
+/// neighbours past the edge are padded per `policy`.
+fn sample_mean_3x3(img: &GrayImageView<'_>, x: f32, y: f32, policy: BorderPolicy) -> Option<u8> {
     let ix = x.floor() as i32;
     let iy = y.floor() as i32;
-    if ix - 1 < 0 || iy - 1 < 0 || ix + 1 >= img.width as i32 || iy + 1 >= img.height as i32 {
+    let margin = i32::from(policy == BorderPolicy::Zero);
+    if ix < margin
+        || iy < margin
+        || ix + margin >= img.width as i32
+        || iy + margin >= img.height as i32
+    {
         return None;
     }
-
-    let mut sum = 0u32;
-    for dy in -1..=1 {
-        for dx in -1..=1 {
-            sum += get_gray(img, ix + dx, iy + dy) as u32;
-        }
-    }
-    Some((sum / 9) as u8)
+    Some(sample_mean_3x3_padded(img, x, y, policy))
 }
 
-fn sample_mean_3x3_clamped(img: &GrayImageView<'_>, x: f32, y: f32) -> u8 {
+fn sample_mean_3x3_padded(img: &GrayImageView<'_>, x: f32, y: f32, policy: BorderPolicy) -> u8 {
     let ix = x.floor() as i32;
     let iy = y.floor() as i32;
     let mut sum = 0u32;
     for dy in -1..=1 {
         for dx in -1..=1 {
-            sum += get_gray(img, ix + dx, iy + dy) as u32;
+            sum += get_gray(img, ix + dx, iy + dy, policy) as u32;
         }
     }
     (sum / 9) as u8
 }
 
+/// Single pixel under `p`; `None` when `p` is outside the image.
+fn sample_nearest(img: &GrayImageView<'_>, p: Point2<f32>) -> Option<u8> {
+    let ix = p.x.floor() as i32;
+    let iy = p.y.floor() as i32;
+    if ix < 0 || iy < 0 || ix >= img.width as i32 || iy >= img.height as i32 {
+        return None;
+    }
+    Some(img.data[iy as usize * img.width + ix as usize])
+}
+
 #[inline]
-fn get_gray(img: &GrayImageView<'_>, x: i32, y: i32) -> u8 {
-    if x < 0 || y < 0 || x >= img.width as i32 || y >= img.height as i32 {
-        return 0;
+fn get_gray(img: &GrayImageView<'_>, x: i32, y: i32, policy: BorderPolicy) -> u8 {
+    match (policy.resolve(x, img.width), policy.resolve(y, img.height)) {
+        (Some(x), Some(y)) => img.data[y * img.width + x],
+        _ => 0,
     }
-    img.data[y as usize * img.width + x as usize]
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::builtins;
+    use crate::{builtins, Dictionary};
     use calib_targets_core::GrayImage;
 
     fn build_marker_image(code: u64, bits: usize, border: usize, cell_px: usize) -> GrayImage {
+        build_marker_image_ordered(code, bits, border, cell_px, BitOrder::RowMajor)
+    }
+
+    fn build_marker_image_ordered(
+        code: u64,
+        bits: usize,
+        border: usize,
+        cell_px: usize,
+        order: BitOrder,
+    ) -> GrayImage {
         let cells = bits + 2 * border;
         let side = cells * cell_px;
         let mut data = vec![255u8; side * side];
@@ -868,7 +1510,7 @@ mod tests {
                 } else {
                     let bx = cx - border;
                     let by = cy - border;
-                    let idx = by * bits + bx;
+                    let idx = order.bit_index(bx, by, bits);
                     ((code >> idx) & 1) == 1
                 };
 
@@ -890,6 +1532,32 @@ mod tests {
         }
     }
 
+    /// A cell whose quad is the whole `side × side` image.
+    fn full_image_cell(side: f32) -> MarkerCell {
+        MarkerCell {
+            gc: Coord::new(0, 0),
+            corners_img: [
+                Point2::new(0.0, 0.0),
+                Point2::new(side, 0.0),
+                Point2::new(side, side),
+                Point2::new(0.0, side),
+            ],
+        }
+    }
+
+    /// Ids read from a single-marker image by both decode paths: the
+    /// rectified one-cell scan and the quad path on [`full_image_cell`].
+    fn decode_ids_both_ways(
+        view: &GrayImageView<'_>,
+        cfg: &ScanDecodeConfig,
+        matcher: &Matcher,
+    ) -> (Vec<u32>, Option<u32>) {
+        let s = view.width as f32;
+        let rectified = scan_decode_markers(view, 1, 1, s, cfg, matcher);
+        let quad = decode_marker_in_cell(view, &full_image_cell(s), s, cfg, matcher);
+        (rectified.iter().map(|d| d.id).collect(), quad.map(|d| d.id))
+    }
+
     #[test]
     fn decode_marker_from_cell_quad() {
         let dict = builtins::builtin_dictionary("DICT_4X4_50").expect("builtin dict");
@@ -902,6 +1570,14 @@ mod tests {
             min_border_score: 0.9,
             dedup_by_id: false,
             multi_threshold: true,
+            border_policy: BorderPolicy::Clamp,
+            auto_inset: false,
+            scale_search: Vec::new(),
+            max_saturated_frac: None,
+            stop_after: None,
+            bit_samples: 1,
+            bit_core_frac: 1.0,
+            max_ambiguous_bits: None,
         };
 
         let code = dict.codes()[0];
@@ -914,15 +1590,7 @@ mod tests {
         };
 
         let s = img.width as f32;
-        let cell = MarkerCell {
-            gc: Coord::new(0, 0),
-            corners_img: [
-                Point2::new(0.0, 0.0),
-                Point2::new(s, 0.0),
-                Point2::new(s, s),
-                Point2::new(0.0, s),
-            ],
-        };
+        let cell = full_image_cell(s);
 
         let det = decode_marker_in_cell(&view, &cell, s, &cfg, &matcher).expect("decode marker");
         assert_eq!(det.id, 0);
@@ -941,6 +1609,14 @@ mod tests {
             min_border_score: 0.9,
             dedup_by_id: false,
             multi_threshold: true,
+            border_policy: BorderPolicy::Clamp,
+            auto_inset: false,
+            scale_search: Vec::new(),
+            max_saturated_frac: None,
+            stop_after: None,
+            bit_samples: 1,
+            bit_core_frac: 1.0,
+            max_ambiguous_bits: None,
         };
 
         let code = dict.codes()[0];
@@ -957,4 +1633,547 @@ mod tests {
         assert_eq!(dets[0].id, 0);
         assert_eq!(dets[0].hamming, 0);
     }
+
+    /// Re-pack a row-major code column-first, as a foreign dictionary would.
+    fn transpose_code(code: u64, n: usize) -> u64 {
+        let mut out = 0u64;
+        for y in 0..n {
+            for x in 0..n {
+                let bit = (code >> BitOrder::RowMajor.bit_index(x, y, n)) & 1;
+                out |= bit << BitOrder::ColumnMajor.bit_index(x, y, n);
+            }
+        }
+        out
+    }
+
+    #[test]
+    fn decode_column_major_dictionary() {
+        let builtin = builtins::builtin_dictionary("DICT_4X4_50").expect("builtin dict");
+        let n = builtin.marker_size();
+        let codes: Vec<u64> = builtin.codes()[..8]
+            .iter()
+            .map(|&c| transpose_code(c, n))
+            .collect();
+        let codes: &'static [u64] = Box::leak(codes.into_boxed_slice());
+        let dict = Dictionary::from_static_codes("COLUMN_MAJOR_4X4", n, 1, codes)
+            .expect("valid dictionary")
+            .with_bit_order(BitOrder::ColumnMajor);
+        let matcher = Matcher::new(dict, 0);
+
+        let cfg = ScanDecodeConfig {
+            border_bits: 1,
+            inset_frac: 0.0,
+            marker_size_rel: 1.0,
+            min_border_score: 0.9,
+            dedup_by_id: false,
+            multi_threshold: true,
+            border_policy: BorderPolicy::Clamp,
+            auto_inset: false,
+            scale_search: Vec::new(),
+            max_saturated_frac: None,
+            stop_after: None,
+            bit_samples: 1,
+            bit_core_frac: 1.0,
+            max_ambiguous_bits: None,
+        };
+
+        let id = 3;
+        // The stored code must actually differ from its row-major reading,
+        // otherwise the test would pass without honouring the order.
+        assert_ne!(codes[id], builtin.codes()[id]);
+        let img =
+            build_marker_image_ordered(codes[id], n, cfg.border_bits, 10, BitOrder::ColumnMajor);
+        let view = GrayImageView {
+            width: img.width,
+            height: img.height,
+            data: &img.data,
+        };
+
+        let s = img.width as f32;
+        let dets = scan_decode_markers(&view, 1, 1, s, &cfg, &matcher);
+        assert_eq!(dets.len(), 1);
+        assert_eq!(dets[0].id, id as u32);
+        assert_eq!(dets[0].rotation, 0);
+        assert_eq!(dets[0].hamming, 0);
+        assert_eq!(dets[0].code, codes[id]);
+    }
+
+    /// A white-bordered cell flush against the top-left image corner: the
+    /// `Zero` policy rejects its edge samples as sampling always did, clamp
+    /// and reflect padding read the real (light) pixels.
+    #[test]
+    fn edge_cell_is_rejected_by_zero_and_read_by_padding_policies() {
+        const SIDE: usize = 60;
+        const STEP: f32 = 10.0;
+        // Light ring, mid-grey checkerboard interior (low contrast, as in a
+        // dim frame corner).
+        let mut data = vec![200u8; SIDE * SIDE];
+        for by in 0..4 {
+            for bx in 0..4 {
+                if (bx + by) % 2 != 0 {
+                    continue;
+                }
+                let x0 = (bx + 1) * 10 - 4;
+                let y0 = (by + 1) * 10 - 4;
+                for y in y0..y0 + 10 {
+                    for x in x0..x0 + 10 {
+                        data[y * SIDE + x] = 150;
+                    }
+                }
+            }
+        }
+        let view = GrayImageView {
+            width: SIDE,
+            height: SIDE,
+            data: &data,
+        };
+        // Sample centres of the first row / column land on pixel 0.
+        let lo = 0.5 - 0.5 * STEP;
+        let hi = lo + 6.0 * STEP;
+        let cell = MarkerCell {
+            gc: Coord::new(0, 0),
+            corners_img: [
+                Point2::new(lo, lo),
+                Point2::new(hi, lo),
+                Point2::new(hi, hi),
+                Point2::new(lo, hi),
+            ],
+        };
+        let cfg = ScanDecodeConfig::default()
+            .with_inset_frac(0.0)
+            .with_marker_size_rel(1.0);
+
+        let sample = |policy| {
+            let cfg = cfg.clone().with_border_policy(policy);
+            sample_cell(&view, &cell, 6.0 * STEP, &cfg, 4)
+        };
+        assert!(sample(BorderPolicy::Zero).is_none());
+        for policy in [BorderPolicy::Clamp, BorderPolicy::Reflect] {
+            let cell = sample(policy).expect("cell sampled");
+            assert_eq!(cell.border_black_fraction, 0.0, "{policy:?}");
+        }
+    }
+
+    #[test]
+    fn canonical_corners_follow_marker_rotation() {
+        let dict = builtins::builtin_dictionary("DICT_4X4_50").expect("builtin dict");
+        let matcher = Matcher::new(dict, 0);
+        let cfg = ScanDecodeConfig::default()
+            .with_inset_frac(0.0)
+            .with_marker_size_rel(1.0);
+
+        let n = dict.marker_size();
+        let code = crate::rotate_code_u64(dict.codes()[5], n, 1, dict.bit_order());
+        let img = build_marker_image(code, n, cfg.border_bits, 10);
+        let view = GrayImageView {
+            width: img.width,
+            height: img.height,
+            data: &img.data,
+        };
+        let s = img.width as f32;
+        let cell = full_image_cell(s);
+
+        let det = decode_marker_in_cell(&view, &cell, s, &cfg, &matcher).expect("decode marker");
+        assert_eq!((det.id, det.rotation), (5, 1));
+        let canonical = det.canonical_corners();
+        // The marker's top-left is the cell's scan-order top-right.
+        assert_eq!(canonical[0], cell.corners_img[1]);
+        assert_ne!(canonical[0], cell.corners_img[0]);
+        assert_eq!(canonical[3], cell.corners_img[0]);
+        assert_eq!(det.gc, Coord::new(1, 0));
+    }
+
+    #[test]
+    fn auto_inset_handles_asymmetric_print_margin() {
+        let dict = builtins::builtin_dictionary("DICT_4X4_50").expect("builtin dict");
+        let matcher = Matcher::new(dict, 0);
+        let id = 7;
+        let marker = build_marker_image(dict.codes()[id], dict.marker_size(), 1, 10);
+
+        // 60 px marker in an 80 px cell, shifted 6 px left of centre: the
+        // margins are 4 px left, 16 px right and 10 px top and bottom.
+        let side = 80;
+        let (left, top) = (4, 10);
+        let mut data = vec![255u8; side * side];
+        for y in 0..marker.height {
+            let row = &marker.data[y * marker.width..(y + 1) * marker.width];
+            let dst = (y + top) * side + left;
+            data[dst..dst + marker.width].copy_from_slice(row);
+        }
+        let view = GrayImageView {
+            width: side,
+            height: side,
+            data: &data,
+        };
+        let s = side as f32;
+        let cell = full_image_cell(s);
+
+        // The fixed inset assumes a centred 60 px marker.
+        let fixed = ScanDecodeConfig::default()
+            .with_inset_frac(0.125)
+            .with_marker_size_rel(1.0);
+        let misread = decode_marker_in_cell(&view, &cell, s, &fixed, &matcher);
+        assert_ne!(misread.map(|d| d.id), Some(id as u32));
+
+        let auto = fixed.with_auto_inset(true);
+        let det = decode_marker_in_cell(&view, &cell, s, &auto, &matcher).expect("decode marker");
+        assert_eq!((det.id, det.rotation), (id as u32, 0));
+        assert!(det.border_score > 0.99, "{}", det.border_score);
+    }
+
+    #[test]
+    fn scale_search_recovers_misestimated_square_size() {
+        let dict = builtins::builtin_dictionary("DICT_4X4_50").expect("builtin dict");
+        let matcher = Matcher::new(dict, 0);
+        let cfg = ScanDecodeConfig::default()
+            .with_inset_frac(0.0)
+            .with_dedup_by_id(false);
+
+        // Three markers side by side in 60 px squares.
+        let tiles: Vec<GrayImage> = (0..3)
+            .map(|id| build_marker_image(dict.codes()[id], dict.marker_size(), 1, 10))
+            .collect();
+        let side = tiles[0].width;
+        let width = 3 * side;
+        let mut data = vec![255u8; width * side];
+        for (k, tile) in tiles.iter().enumerate() {
+            for y in 0..side {
+                let row = &tile.data[y * side..(y + 1) * side];
+                data[y * width + k * side..y * width + (k + 1) * side].copy_from_slice(row);
+            }
+        }
+        let view = GrayImageView {
+            width,
+            height: side,
+            data: &data,
+        };
+
+        // The caller's square-size estimate is ~9% too small.
+        let s = side as f32 / 1.1;
+        let plain = scan_decode_markers(&view, 3, 1, s, &cfg, &matcher);
+        assert!(plain.len() < 3, "decoded {} without search", plain.len());
+
+        let searched = cfg.with_scale_search(vec![0.9, 1.0, 1.1]);
+        let mut ids: Vec<u32> = scan_decode_markers(&view, 3, 1, s, &searched, &matcher)
+            .iter()
+            .map(|d| d.id)
+            .collect();
+        ids.sort_unstable();
+        assert_eq!(ids, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn saturated_cell_is_rejected_under_cap() {
+        let dict = builtins::builtin_dictionary("DICT_4X4_50").expect("builtin dict");
+        let matcher = Matcher::new(dict, 0);
+        let mut img = build_marker_image(dict.codes()[5], dict.marker_size(), 1, 10);
+        // Realistic exposure: whites below the clip level.
+        for v in &mut img.data {
+            *v = if *v == 0 { 30 } else { 220 };
+        }
+        let side = img.width;
+        let decode = |data: &[u8], max_saturated_frac| {
+            let view = GrayImageView {
+                width: side,
+                height: side,
+                data,
+            };
+            let cfg = ScanDecodeConfig::default()
+                .with_inset_frac(0.0)
+                .with_max_saturated_frac(max_saturated_frac);
+            scan_decode_markers(&view, 1, 1, side as f32, &cfg, &matcher)
+        };
+
+        let clean = decode(&img.data, Some(0.25));
+        assert_eq!(clean.iter().map(|d| d.id).collect::<Vec<_>>(), vec![5]);
+
+        // Glare clips the left half of the marker, black bits included.
+        let mut glare = img.data.clone();
+        for y in 0..side {
+            for x in 0..side / 2 {
+                glare[y * side + x] = 255;
+            }
+        }
+        let unguarded = decode(&glare, None);
+        assert!(unguarded.iter().all(|d| d.id != 5));
+        assert!(decode(&glare, Some(0.25)).is_empty());
+    }
+
+    #[test]
+    fn bit_samples_average_out_speckle_at_bit_centres() {
+        let dict = builtins::builtin_dictionary("DICT_4X4_50").expect("builtin dict");
+        let matcher = Matcher::new(dict, 0);
+        let bits = dict.marker_size();
+        let cell_px = 12;
+        let mut img = build_marker_image(dict.codes()[5], bits, 1, cell_px);
+        // Invert a 4×4 speck on every inner bit centre, exactly where a
+        // single-sample read lands.
+        let side = img.width;
+        for by in 1..=bits {
+            for bx in 1..=bits {
+                for y in by * cell_px + 4..by * cell_px + 8 {
+                    for x in bx * cell_px + 4..bx * cell_px + 8 {
+                        img.data[y * side + x] = 255 - img.data[y * side + x];
+                    }
+                }
+            }
+        }
+        let view = GrayImageView {
+            width: side,
+            height: side,
+            data: &img.data,
+        };
+        let ids = |bit_samples| {
+            let cfg = ScanDecodeConfig::default()
+                .with_inset_frac(0.0)
+                .with_bit_samples(bit_samples);
+            decode_ids_both_ways(&view, &cfg, &matcher)
+        };
+
+        let (rectified, quad) = ids(1);
+        assert!(rectified.iter().all(|&id| id != 5));
+        assert_ne!(quad, Some(5));
+
+        assert_eq!(ids(4), (vec![5], Some(5)));
+    }
+
+    #[test]
+    fn bit_core_frac_keeps_samples_off_anti_aliased_edges() {
+        let dict = builtins::builtin_dictionary("DICT_4X4_50").expect("builtin dict");
+        let matcher = Matcher::new(dict, 0);
+        let img = build_marker_image(dict.codes()[5], dict.marker_size(), 1, 5);
+        // A 7×7 box blur smears every bit edge over more than a bit width at
+        // 5 px per bit, as heavy anti-aliasing does.
+        let side = img.width;
+        let r = 3;
+        let mut data = img.data.clone();
+        for y in 0..side {
+            for x in 0..side {
+                let (mut sum, mut n) = (0u32, 0u32);
+                for yy in y.saturating_sub(r)..(y + r + 1).min(side) {
+                    for xx in x.saturating_sub(r)..(x + r + 1).min(side) {
+                        sum += img.data[yy * side + xx] as u32;
+                        n += 1;
+                    }
+                }
+                data[y * side + x] = (sum / n) as u8;
+            }
+        }
+        let view = GrayImageView {
+            width: side,
+            height: side,
+            data: &data,
+        };
+        let ids = |bit_core_frac| {
+            let cfg = ScanDecodeConfig::default()
+                .with_inset_frac(0.0)
+                .with_bit_samples(9)
+                .with_bit_core_frac(bit_core_frac);
+            decode_ids_both_ways(&view, &cfg, &matcher)
+        };
+
+        let (rectified, quad) = ids(1.0);
+        assert!(rectified.iter().all(|&id| id != 5));
+        assert_ne!(quad, Some(5));
+
+        assert_eq!(ids(0.4), (vec![5], Some(5)));
+    }
+
+    #[test]
+    fn ambiguous_bits_reject_a_misaligned_cell() {
+        let dict = builtins::builtin_dictionary("DICT_4X4_50").expect("builtin dict");
+        // Two-bit error correction is what turns a shifted read into a
+        // confident wrong id.
+        let matcher = Matcher::new(dict, 2);
+        let cell_px = 12;
+        let marker = build_marker_image(dict.codes()[5], dict.marker_size(), 1, cell_px);
+        let pad = 2 * cell_px;
+        let side = marker.width + 2 * pad;
+        let mut data = vec![255u8; side * side];
+        for y in 0..marker.height {
+            let row = &marker.data[y * marker.width..(y + 1) * marker.width];
+            data[(y + pad) * side + pad..][..marker.width].copy_from_slice(row);
+        }
+        let view = GrayImageView {
+            width: side,
+            height: side,
+            data: &data,
+        };
+        let s = marker.width as f32;
+        let cell_at = |dx: f32, dy: f32| {
+            let (x0, y0) = (pad as f32 + dx, pad as f32 + dy);
+            MarkerCell {
+                gc: Coord::new(0, 0),
+                corners_img: [
+                    Point2::new(x0, y0),
+                    Point2::new(x0 + s, y0),
+                    Point2::new(x0 + s, y0 + s),
+                    Point2::new(x0, y0 + s),
+                ],
+            }
+        };
+        let cfg = ScanDecodeConfig::default().with_inset_frac(0.0);
+        let guarded = cfg.clone().with_max_ambiguous_bits(Some(2));
+        let id = |cell: &MarkerCell, cfg: &ScanDecodeConfig| {
+            decode_marker_in_cell(&view, cell, s, cfg, &matcher).map(|d| d.id)
+        };
+
+        let aligned = cell_at(0.0, 0.0);
+        assert_eq!(id(&aligned, &cfg), Some(5));
+        assert_eq!(id(&aligned, &guarded), Some(5));
+
+        // One bit off in x and half a bit off in y: every sample row sits on
+        // a bit boundary.
+        let shifted = cell_at(cell_px as f32, 0.5 * cell_px as f32);
+        let unguarded = id(&shifted, &cfg);
+        assert!(unguarded.is_some_and(|id| id != 5), "{unguarded:?}");
+        assert_eq!(id(&shifted, &guarded), None);
+    }
+
+    #[test]
+    fn debug_binary_image_matches_cell_threshold() {
+        let dict = builtins::builtin_dictionary("DICT_4X4_50").expect("builtin dict");
+        let matcher = Matcher::new(dict, 0);
+        let mut img = build_marker_image(dict.codes()[7], dict.marker_size(), 1, 10);
+        // Uneven lighting, so the threshold is not simply mid-range.
+        let side = img.width;
+        for (i, v) in img.data.iter_mut().enumerate() {
+            let shade = (i % side * 40 / side) as u8;
+            *v = if *v == 0 { 20 + shade } else { 180 + shade };
+        }
+        let view = GrayImageView {
+            width: side,
+            height: side,
+            data: &img.data,
+        };
+        let cfg = ScanDecodeConfig::default().with_inset_frac(0.0);
+
+        let debug = scan_decode_markers_with_debug(&view, 1, 1, side as f32, &cfg, &matcher);
+        let plain = scan_decode_markers(&view, 1, 1, side as f32, &cfg, &matcher);
+        assert_eq!(
+            debug.detections.iter().map(|d| d.id).collect::<Vec<_>>(),
+            plain.iter().map(|d| d.id).collect::<Vec<_>>()
+        );
+        assert_eq!(plain[0].id, 7);
+
+        // With no inset the sampling window is the whole image.
+        let thr = otsu_threshold_from_samples(&img.data);
+        assert_eq!((debug.binary.width, debug.binary.height), (side, side));
+        for (&v, &b) in img.data.iter().zip(&debug.binary.data) {
+            assert_eq!(b, if v < thr { 0 } else { 255 });
+        }
+    }
+
+    #[test]
+    fn clean_marker_matched_code_equals_observed_code() {
+        let dict = builtins::builtin_dictionary("DICT_4X4_50").expect("builtin dict");
+        let matcher = Matcher::new(dict, 1);
+        let n = dict.marker_size();
+        let code = crate::rotate_code_u64(dict.codes()[9], n, 3, dict.bit_order());
+        let img = build_marker_image(code, n, 1, 10);
+        let cfg = ScanDecodeConfig::default().with_inset_frac(0.0);
+        let detections = scan_decode_markers(&img.view(), 1, 1, img.width as f32, &cfg, &matcher);
+
+        assert_eq!(detections.len(), 1);
+        let d = &detections[0];
+        assert_eq!((d.id, d.rotation, d.hamming), (9, 3, 0));
+        assert_eq!(d.matched_code, Some(d.code));
+        assert_ne!(d.code, dict.codes()[9]);
+    }
+
+    #[test]
+    fn marker_detection_round_trips_through_json() {
+        let dict = builtins::builtin_dictionary("DICT_4X4_50").expect("builtin dict");
+        let matcher = Matcher::new(dict, 0);
+        let img = build_marker_image(dict.codes()[7], dict.marker_size(), 1, 10);
+        let side = img.width;
+        let cfg = ScanDecodeConfig::default().with_inset_frac(0.0);
+        let mut detections = scan_decode_markers(&img.view(), 1, 1, side as f32, &cfg, &matcher);
+        assert_eq!(detections.len(), 1);
+        let mut with_img = detections[0].clone();
+        with_img.corners_img = Some([
+            Point2::new(1.5, 2.0),
+            Point2::new(61.0, 2.5),
+            Point2::new(60.5, 62.0),
+            Point2::new(1.0, 61.5),
+        ]);
+        detections.push(with_img);
+
+        for det in &detections {
+            let json = serde_json::to_string(det).expect("serialize");
+            let value: serde_json::Value = serde_json::from_str(&json).expect("parse");
+            // Points are plain `[x, y]` arrays.
+            assert_eq!(value["corners_rect"][2].as_array().map(Vec::len), Some(2));
+
+            let back: MarkerDetection = serde_json::from_str(&json).expect("deserialize");
+            assert_eq!(
+                (back.id, back.gc, back.rotation),
+                (det.id, det.gc, det.rotation)
+            );
+            assert_eq!(
+                (back.code, back.hamming, back.inverted),
+                (det.code, det.hamming, det.inverted)
+            );
+            assert_eq!(back.matched_code, det.matched_code);
+            assert_eq!(
+                (back.score, back.border_score),
+                (det.score, det.border_score)
+            );
+            assert_eq!(back.corners_rect, det.corners_rect);
+            assert_eq!(back.corners_img, det.corners_img);
+        }
+    }
+
+    #[test]
+    fn stop_after_ends_cell_scan_early_from_the_centre() {
+        let dict = builtins::builtin_dictionary("DICT_4X4_50").expect("builtin dict");
+        let matcher = Matcher::new(dict, 0);
+
+        // 3 × 3 markers, id `3 * v + u` at cell `(u, v)`.
+        let tiles: Vec<GrayImage> = (0..9)
+            .map(|id| build_marker_image(dict.codes()[id], dict.marker_size(), 1, 10))
+            .collect();
+        let side = tiles[0].width;
+        let width = 3 * side;
+        let mut data = vec![255u8; width * width];
+        for (k, tile) in tiles.iter().enumerate() {
+            let (u, v) = (k % 3, k / 3);
+            for y in 0..side {
+                let row = &tile.data[y * side..(y + 1) * side];
+                let start = (v * side + y) * width + u * side;
+                data[start..start + side].copy_from_slice(row);
+            }
+        }
+        let view = GrayImageView {
+            width,
+            height: width,
+            data: &data,
+        };
+        let s = side as f32;
+        let cells: Vec<MarkerCell> = (0..9)
+            .map(|k| {
+                let (u, v) = ((k % 3) as f32, (k / 3) as f32);
+                MarkerCell {
+                    gc: Coord::new(k % 3, k / 3),
+                    corners_img: [
+                        Point2::new(u * s, v * s),
+                        Point2::new((u + 1.0) * s, v * s),
+                        Point2::new((u + 1.0) * s, (v + 1.0) * s),
+                        Point2::new(u * s, (v + 1.0) * s),
+                    ],
+                }
+            })
+            .collect();
+
+        let cfg = ScanDecodeConfig::default().with_inset_frac(0.0);
+        let full = scan_decode_markers_in_cells_with_stats(&view, &cells, s, &cfg, &matcher);
+        assert_eq!((full.detections.len(), full.cells_visited), (9, 9));
+
+        // Every cell decodes, so the scan stops after exactly four visits.
+        let early = cfg.with_stop_after(Some(4));
+        let partial = scan_decode_markers_in_cells_with_stats(&view, &cells, s, &early, &matcher);
+        assert_eq!((partial.detections.len(), partial.cells_visited), (4, 4));
+        // The centre cell is visited first.
+        assert!(partial.detections.iter().any(|d| d.id == 4));
+    }
 }
@@ -160,6 +160,10 @@ fn knob_catalogue() -> Vec<KnobSpec> {
             k("min_peak_weight_fraction", ScalarRel, None),
             "/advanced/min_peak_weight_fraction",
         ),
+        with_pointer(
+            k("smooth_sigma_bins", ScalarRel, None),
+            "/advanced/smooth_sigma_bins",
+        ),
         with_pointer(
             k("attach_search_rel", ScalarRel, None),
             "/advanced/attach_search_rel",
@@ -181,6 +185,10 @@ fn knob_catalogue() -> Vec<KnobSpec> {
             k("line_min_members", ScalarRel, None),
             "/advanced/line_min_members",
         ),
+        with_pointer(
+            k("min_grid_lines", ScalarRel, None),
+            "/advanced/min_grid_lines",
+        ),
         with_pointer(
             k("geometry_check_line_tol_rel", ScalarRel, None),
             "/advanced/geometry_check_line_tol_rel",
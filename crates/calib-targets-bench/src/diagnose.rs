@@ -129,7 +129,7 @@ pub fn diagnose_topological(
         .detect_all(corners);
     let labelled_corner_set: HashSet<usize> = detections
         .iter()
-        .flat_map(|d| d.corners.iter().map(|c| c.input_index))
+        .flat_map(|d| d.corners.iter().filter_map(|c| c.input_index))
         .collect();
 
     let components = detections
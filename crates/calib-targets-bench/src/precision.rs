@@ -14,6 +14,8 @@
 
 use std::collections::HashMap;
 
+use calib_targets_core::PointIndex;
+use nalgebra::Point2;
 use serde::{Deserialize, Serialize};
 
 use crate::baseline::BaselineCorner;
@@ -44,8 +46,8 @@ pub struct StructuralPrecision {
 ///
 /// Thresholds match the source helper: overlong = edge `> 1.6×` the global
 /// median cardinal-edge length; collapsed = a distinct `(i, j)` pair `< 0.2×`
-/// that median apart. The collapsed-pair scan is `O(n²)` in the corner count;
-/// acceptable because the bench is a measurement tool, not a hot path.
+/// that median apart. The collapsed-pair scan queries a
+/// [`PointIndex`] radius around each corner rather than testing every pair.
 pub fn structural_precision(corners: &[BaselineCorner]) -> StructuralPrecision {
     // (i, j) → pixel position. Mirrors the source helper: a duplicate label
     // (same (i, j)) collapses here, which the chessboard grid contract forbids
@@ -69,16 +71,22 @@ pub fn structural_precision(corners: &[BaselineCorner]) -> StructuralPrecision {
     let median = lens[lens.len() / 2];
     let overlong_edges = lens.iter().filter(|&&l| l > 1.6 * median).count();
 
-    let pts: Vec<(f32, f32)> = by_grid.values().copied().collect();
-    let eps2 = (0.2 * median).powi(2);
-    let mut collapsed_pairs = 0usize;
-    for (a, &(ax, ay)) in pts.iter().enumerate() {
-        for &(bx, by) in &pts[a + 1..] {
-            if (ax - bx).powi(2) + (ay - by).powi(2) < eps2 {
-                collapsed_pairs += 1;
-            }
-        }
-    }
+    let pts: Vec<Point2<f32>> = by_grid.values().map(|&(x, y)| Point2::new(x, y)).collect();
+    let eps = 0.2 * median;
+    let index = PointIndex::new(&pts);
+    // Count each unordered pair once (b > a); the radius query is inclusive,
+    // the threshold strict.
+    let collapsed_pairs: usize = pts
+        .iter()
+        .enumerate()
+        .map(|(a, &p)| {
+            index
+                .within_radius(p, eps)
+                .iter()
+                .filter(|hit| hit.index > a && hit.distance < eps)
+                .count()
+        })
+        .sum();
 
     StructuralPrecision {
         overlong_edges,
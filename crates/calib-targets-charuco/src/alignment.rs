@@ -17,6 +17,9 @@ pub(crate) struct CharucoAlignment {
     /// Indices of the marker detections that agreed with the chosen
     /// alignment (the inlier set).
     pub marker_inliers: Vec<usize>,
+    /// Mean distance, in cells, between each marker's predicted cell and the
+    /// cell that best decodes it. `~0` for a sound alignment.
+    pub residual_cells: f32,
 }
 
 impl CharucoAlignment {
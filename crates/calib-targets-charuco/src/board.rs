@@ -1,9 +1,11 @@
 //! Board specification and layout helpers for ChArUco.
 
+use crate::CharucoDetectionResult;
 use calib_targets_aruco::Dictionary;
 use calib_targets_core::Coord;
-use nalgebra::Point2;
+use nalgebra::{Point2, Point3};
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
 
 /// Marker placement scheme for the board.
 #[non_exhaustive]
@@ -18,6 +20,64 @@ pub enum MarkerLayout {
     OpenCvCharuco,
 }
 
+/// Marker placement strategy: which board square each marker id occupies.
+///
+/// [`CharucoBoard::new`] resolves the built-in [`MarkerLayout`] schemes to a
+/// placement; research boards with arbitrary marker positions implement this
+/// trait and build the board with [`CharucoBoard::with_placement`]. The board
+/// matcher and corner mapping only consult the resulting board lookups, so a
+/// custom placement flows through decoding and alignment unchanged.
+pub trait MarkerPlacement {
+    /// Board square `(i, j)` holding marker `id`, or `None` if `id` is not
+    /// placed on the board.
+    fn marker_cell(&self, id: u32) -> Option<Coord>;
+
+    /// Number of markers on the board. Marker ids are `0..count()`.
+    fn count(&self) -> usize;
+}
+
+/// [`MarkerLayout::OpenCvCharuco`] as a [`MarkerPlacement`] for a board of
+/// `rows × cols` squares.
+///
+/// Markers sit on the white squares (`(i + j)` odd, the top-left square being
+/// black), numbered in row-major order.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OpenCvCharucoPlacement {
+    rows: u32,
+    cols: u32,
+}
+
+impl OpenCvCharucoPlacement {
+    /// Placement for a board with `rows × cols` squares.
+    pub fn new(rows: u32, cols: u32) -> Self {
+        Self { rows, cols }
+    }
+}
+
+impl MarkerPlacement for OpenCvCharucoPlacement {
+    fn marker_cell(&self, id: u32) -> Option<Coord> {
+        if self.cols == 0 || id as usize >= self.count() {
+            return None;
+        }
+        // Every pair of rows holds exactly `cols` markers: `cols / 2` on the
+        // even row (odd `i`), `(cols + 1) / 2` on the odd row (even `i`).
+        let pair = id / self.cols;
+        let rem = id % self.cols;
+        let on_even_row = self.cols / 2;
+        let (i, j) = if rem < on_even_row {
+            (2 * rem + 1, 2 * pair)
+        } else {
+            (2 * (rem - on_even_row), 2 * pair + 1)
+        };
+        Some(Coord::new(i as i32, j as i32))
+    }
+
+    fn count(&self) -> usize {
+        let (rows, cols) = (self.rows as usize, self.cols as usize);
+        (rows / 2) * cols + (rows % 2) * (cols / 2)
+    }
+}
+
 /// Static ChArUco board specification.
 ///
 /// `rows`/`cols` are **square counts** (not inner corner counts).
@@ -68,6 +128,36 @@ impl CharucoBoardSpec {
     }
 }
 
+/// Predefined ChArUco boards for common OpenCV setups, named
+/// `B{rows}x{cols}Dict{bits}x{bits}_{size}` (rows first, like
+/// [`CharucoBoardSpec::new`]; OpenCV's `CharucoBoard((squaresX, squaresY))`
+/// lists columns first).
+///
+/// Each uses the OpenCV ChArUco layout, markers at `0.75` of the square side
+/// (the printable generator's default), and `cell_size = 1.0`, so target
+/// positions come out in square units. Scale
+/// [`CharucoBoardSpec::cell_size`] on [`StandardBoard::spec`] for metric
+/// positions.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StandardBoard {
+    /// 5 rows × 7 columns of squares, `DICT_4X4_50`.
+    B5x7Dict4x4_50,
+    /// 5 rows × 7 columns of squares, `DICT_6X6_250`.
+    B5x7Dict6x6_250,
+}
+
+impl StandardBoard {
+    /// Board specification for this standard board.
+    pub fn spec(self) -> CharucoBoardSpec {
+        let dictionary = match self {
+            StandardBoard::B5x7Dict4x4_50 => calib_targets_aruco::builtins::DICT_4X4_50,
+            StandardBoard::B5x7Dict6x6_250 => calib_targets_aruco::builtins::DICT_6X6_250,
+        };
+        CharucoBoardSpec::new(5, 7, 1.0, 0.75, dictionary)
+    }
+}
+
 /// Board specification validation errors.
 #[non_exhaustive]
 #[derive(thiserror::Error, Debug)]
@@ -84,6 +174,21 @@ pub enum CharucoBoardError {
     /// The supplied dictionary contains no marker codes.
     #[error("dictionary has no codes")]
     EmptyDictionary,
+    /// A [`MarkerPlacement`] left marker `id` unplaced, placed it outside the
+    /// board, or placed it on a square already holding another marker.
+    #[error("marker placement is invalid for marker id {id}")]
+    InvalidMarkerPlacement {
+        /// The first offending marker id.
+        id: u32,
+    },
+    /// An id map passed to [`CharucoBoard::with_id_map`] has the wrong length,
+    /// repeats a dictionary id, or names an id outside the dictionary.
+    #[error("id map is invalid at marker slot {slot}")]
+    InvalidIdMap {
+        /// The first offending marker slot (or the map length on a length
+        /// mismatch).
+        slot: usize,
+    },
     /// The dictionary has fewer codes than the board has marker squares.
     #[error("board needs {needed} markers, dictionary has {available}")]
     NotEnoughDictionaryCodes {
@@ -95,19 +200,61 @@ pub enum CharucoBoardError {
 }
 
 /// Precomputed board mapping helpers.
+///
+/// Marker ids are dictionary ids. By default marker slot `k` of the placement
+/// uses dictionary id `k`; [`CharucoBoard::with_id_map`] substitutes an
+/// arbitrary subset of the dictionary.
 #[derive(Clone, Debug)]
 pub struct CharucoBoard {
     spec: CharucoBoardSpec,
+    /// Board square of each marker slot, in placement order.
     marker_positions: Vec<Coord>,
+    /// Slot → dictionary id; `None` ⇒ identity.
+    id_map: Option<Vec<u32>>,
     /// Reverse lookup: board square `(i, j)` → marker id. Keyed as
     /// `j * cols + i` so `None` ⇒ no marker at that square (a black square
     /// in the OpenCV layout).
     id_at_square: Vec<Option<u32>>,
+    /// Reverse lookup: marker id → board square, indexed by id. `None` ⇒ no
+    /// marker uses that id.
+    square_of_id: Vec<Option<Coord>>,
 }
 
 impl CharucoBoard {
-    /// Validate and create a board from a spec.
+    /// Validate and create a board from a spec, placing markers according to
+    /// [`CharucoBoardSpec::marker_layout`].
     pub fn new(spec: CharucoBoardSpec) -> Result<Self, CharucoBoardError> {
+        match spec.marker_layout {
+            MarkerLayout::OpenCvCharuco => {
+                Self::with_placement(spec, &OpenCvCharucoPlacement::new(spec.rows, spec.cols))
+            }
+        }
+    }
+
+    /// Build one of the [`StandardBoard`]s. Standard specs are valid by
+    /// construction, so this cannot fail.
+    pub fn standard(board: StandardBoard) -> Self {
+        Self::new(board.spec()).expect("standard board specs are valid")
+    }
+
+    /// The 5 × 7 square (rows × columns), `DICT_4X4_50` board
+    /// ([`StandardBoard::B5x7Dict4x4_50`]).
+    pub fn opencv_5x7_dict4x4() -> Self {
+        Self::standard(StandardBoard::B5x7Dict4x4_50)
+    }
+
+    /// Validate and create a board whose markers are placed by a custom
+    /// [`MarkerPlacement`]; [`CharucoBoardSpec::marker_layout`] is ignored.
+    ///
+    /// Every id in `0..placement.count()` must map to a distinct square
+    /// inside the board, otherwise
+    /// [`CharucoBoardError::InvalidMarkerPlacement`] is returned. Hand the
+    /// board to [`CharucoDetector::with_board`](crate::CharucoDetector::with_board)
+    /// to detect it.
+    pub fn with_placement(
+        spec: CharucoBoardSpec,
+        placement: &dyn MarkerPlacement,
+    ) -> Result<Self, CharucoBoardError> {
         if spec.rows < 2 || spec.cols < 2 {
             return Err(CharucoBoardError::InvalidSize);
         }
@@ -124,36 +271,81 @@ impl CharucoBoard {
             return Err(CharucoBoardError::EmptyDictionary);
         }
 
-        let marker_positions = match spec.marker_layout {
-            MarkerLayout::OpenCvCharuco => open_cv_charuco_marker_positions(spec.rows, spec.cols),
-        };
-
-        let needed = marker_positions.len();
+        let needed = placement.count();
         let available = spec.dictionary.codes().len();
         if available < needed {
             return Err(CharucoBoardError::NotEnoughDictionaryCodes { needed, available });
         }
 
+        let mut marker_positions = Vec::with_capacity(needed);
         let mut id_at_square = vec![None; (spec.rows as usize) * (spec.cols as usize)];
-        for (id, bc) in marker_positions.iter().enumerate() {
-            if bc.u < 0 || bc.v < 0 {
-                continue;
+        for id in 0..needed as u32 {
+            let invalid = CharucoBoardError::InvalidMarkerPlacement { id };
+            let bc = placement.marker_cell(id).ok_or(invalid)?;
+            if bc.u < 0 || bc.v < 0 || bc.u >= spec.cols as i32 || bc.v >= spec.rows as i32 {
+                return Err(CharucoBoardError::InvalidMarkerPlacement { id });
             }
-            let i = bc.u as usize;
-            let j = bc.v as usize;
-            if i >= spec.cols as usize || j >= spec.rows as usize {
-                continue;
+            let slot = &mut id_at_square[bc.v as usize * spec.cols as usize + bc.u as usize];
+            if slot.is_some() {
+                return Err(CharucoBoardError::InvalidMarkerPlacement { id });
             }
-            id_at_square[j * spec.cols as usize + i] = Some(id as u32);
+            *slot = Some(id);
+            marker_positions.push(bc);
         }
 
+        let square_of_id = marker_positions.iter().copied().map(Some).collect();
         Ok(Self {
             spec,
             marker_positions,
+            id_map: None,
             id_at_square,
+            square_of_id,
         })
     }
 
+    /// Draw the board's markers from a subset of the dictionary: marker slot
+    /// `k` (the `k`-th placed marker, id `k` without a map) uses dictionary id
+    /// `id_map[k]`.
+    ///
+    /// Supports boards printed with a non-contiguous id range. Every board
+    /// lookup ([`Self::marker_position`], [`Self::marker_id_at`],
+    /// [`Self::iter_marker_positions`]) and therefore decoding and alignment
+    /// speak dictionary ids afterwards. `id_map` must have one entry per
+    /// marker, with distinct ids inside the dictionary, otherwise
+    /// [`CharucoBoardError::InvalidIdMap`] is returned.
+    pub fn with_id_map(mut self, id_map: Vec<u32>) -> Result<Self, CharucoBoardError> {
+        if id_map.len() != self.marker_positions.len() {
+            return Err(CharucoBoardError::InvalidIdMap {
+                slot: id_map.len().min(self.marker_positions.len()),
+            });
+        }
+        let available = self.spec.dictionary.codes().len();
+        let mut seen = vec![false; available];
+        for (slot, &id) in id_map.iter().enumerate() {
+            let Some(used) = seen.get_mut(id as usize) else {
+                return Err(CharucoBoardError::InvalidIdMap { slot });
+            };
+            if std::mem::replace(used, true) {
+                return Err(CharucoBoardError::InvalidIdMap { slot });
+            }
+        }
+        let cols = self.spec.cols as usize;
+        let max_id = id_map.iter().copied().max().unwrap_or(0) as usize;
+        self.square_of_id = vec![None; max_id + 1];
+        for (bc, &id) in self.marker_positions.iter().zip(&id_map) {
+            self.id_at_square[bc.v as usize * cols + bc.u as usize] = Some(id);
+            self.square_of_id[id as usize] = Some(*bc);
+        }
+        self.id_map = Some(id_map);
+        Ok(self)
+    }
+
+    /// Slot → dictionary id map set by [`Self::with_id_map`], if any.
+    #[inline]
+    pub fn id_map(&self) -> Option<&[u32]> {
+        self.id_map.as_deref()
+    }
+
     /// Return the underlying board specification.
     #[inline]
     pub fn spec(&self) -> CharucoBoardSpec {
@@ -175,7 +367,7 @@ impl CharucoBoard {
     /// Mapping from marker id -> board cell (square) coordinates.
     #[inline]
     pub fn marker_position(&self, id: u32) -> Option<Coord> {
-        self.marker_positions.get(id as usize).cloned()
+        self.square_of_id.get(id as usize).copied().flatten()
     }
 
     /// Square-cell coordinates `(sx, sy)` for the given marker id.
@@ -209,6 +401,39 @@ impl CharucoBoard {
         self.marker_positions.len()
     }
 
+    /// Suggested [`min_marker_inliers`](crate::CharucoParams::min_marker_inliers)
+    /// for views that show roughly `visible_fraction` of this board.
+    ///
+    /// Returns half the markers expected in view (rounded down, at least 1),
+    /// leaving slack for markers lost to blur, glare or the image border.
+    /// `visible_fraction` is clamped to `[0, 1]`; a non-finite value counts
+    /// as `0`. The board-level matcher is its own alignment gate, which is
+    /// why [`CharucoParams::for_board`](crate::CharucoParams::for_board) keeps
+    /// the floor at `1`; raise it to this value when partial views of a large
+    /// board must carry more marker evidence.
+    pub fn suggest_min_inliers(&self, visible_fraction: f32) -> usize {
+        let frac = if visible_fraction.is_finite() {
+            visible_fraction.clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        let expected = self.marker_count() as f32 * frac;
+        ((expected * 0.5).floor() as usize).max(1)
+    }
+
+    /// ChArUco corner ids of this board that `result` did not detect, in
+    /// ascending order.
+    ///
+    /// For capture QC: an empty list means a complete view, and the ids map
+    /// back to board positions through [`Self::charuco_object_xy`] to show
+    /// which region to re-image. `result` must come from a detector built for
+    /// this board.
+    pub fn missing_corner_ids(&self, result: &CharucoDetectionResult) -> Vec<u32> {
+        let total = self.expected_inner_rows() * self.expected_inner_cols();
+        let found: HashSet<u32> = result.corners.iter().map(|c| c.id).collect();
+        (0..total).filter(|id| !found.contains(id)).collect()
+    }
+
     /// Reverse lookup: marker id at a given board square coordinate, if any.
     ///
     /// Returns `None` for out-of-range `bc` and for squares where the board
@@ -228,10 +453,10 @@ impl CharucoBoard {
 
     /// Iterate all marker `(id, position)` pairs for this board.
     pub fn iter_marker_positions(&self) -> impl Iterator<Item = (u32, Coord)> + '_ {
-        self.marker_positions
-            .iter()
-            .enumerate()
-            .map(|(id, bc)| (id as u32, *bc))
+        self.marker_positions.iter().enumerate().map(|(slot, bc)| {
+            let id = self.id_map.as_ref().map_or(slot as u32, |map| map[slot]);
+            (id, *bc)
+        })
     }
 
     /// Convert a board **corner coordinate** `(i, j)` into a ChArUco corner id.
@@ -268,6 +493,26 @@ impl CharucoBoard {
             j * self.spec.cell_size,
         ))
     }
+
+    /// Physical corners of a marker's printed square on the board plane
+    /// (`z = 0`), in TL, TR, BR, BL order.
+    ///
+    /// The marker is centred in its square with side
+    /// `marker_size_rel * cell_size`; coordinates share the frame of
+    /// [`Self::charuco_object_xy`]. Returns `None` for an unknown id.
+    pub fn marker_corners_board(&self, id: u32) -> Option<[Point3<f32>; 4]> {
+        let bc = self.marker_position(id)?;
+        let s = self.spec.cell_size;
+        let side = self.spec.marker_size_rel * s;
+        let x0 = bc.u as f32 * s + 0.5 * (s - side);
+        let y0 = bc.v as f32 * s + 0.5 * (s - side);
+        Some([
+            Point3::new(x0, y0, 0.0),
+            Point3::new(x0 + side, y0, 0.0),
+            Point3::new(x0 + side, y0 + side, 0.0),
+            Point3::new(x0, y0 + side, 0.0),
+        ])
+    }
 }
 
 /// True if `(ix, iy)` is an internal intersection for a board with `squares_x` × `squares_y`.
@@ -321,19 +566,6 @@ fn marker_surrounding_charuco_corners_for_cell(
     Some([tl, tr, br, bl])
 }
 
-fn open_cv_charuco_marker_positions(rows: u32, cols: u32) -> Vec<Coord> {
-    let mut out = Vec::new();
-    for j in 0..(rows as i32) {
-        for i in 0..(cols as i32) {
-            // OpenCV: top-left square is black => white squares have (i+j) odd.
-            if ((i + j) & 1) == 1 {
-                out.push(Coord::new(i, j));
-            }
-        }
-    }
-    out
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -389,6 +621,174 @@ mod tests {
             .is_none());
     }
 
+    #[test]
+    fn missing_corner_ids_lists_undetected_corners() {
+        use crate::CharucoCorner;
+        use calib_targets_core::GridAlignment;
+
+        // 6x5 squares: 5x4 = 20 inner corners. Detect all but ids 3, 7, 19.
+        let board = build_board();
+        let corners = (0..20u32)
+            .filter(|id| ![3, 7, 19].contains(id))
+            .map(|id| {
+                let p = board.charuco_object_xy(id).expect("corner id");
+                let g = Coord::new((id % 5) as i32 + 1, (id / 5) as i32 + 1);
+                CharucoCorner::new(p, g, id, p, 1.0)
+            })
+            .collect();
+        let result = CharucoDetectionResult::new(corners, Vec::new(), GridAlignment::IDENTITY);
+        assert_eq!(board.missing_corner_ids(&result), vec![3, 7, 19]);
+
+        let empty = CharucoDetectionResult::new(Vec::new(), Vec::new(), GridAlignment::IDENTITY);
+        assert_eq!(
+            board.missing_corner_ids(&empty),
+            (0..20).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn standard_boards_build() {
+        for standard in [
+            StandardBoard::B5x7Dict4x4_50,
+            StandardBoard::B5x7Dict6x6_250,
+        ] {
+            let board = CharucoBoard::standard(standard);
+            assert_eq!(board.spec().rows, 5);
+            assert_eq!(board.spec().cols, 7);
+            assert_eq!(board.marker_count(), 17);
+        }
+        let board = CharucoBoard::opencv_5x7_dict4x4();
+        assert_eq!(board.spec().dictionary.name(), "DICT_4X4_50");
+    }
+
+    #[test]
+    fn open_cv_placement_matches_white_squares_row_major() {
+        for (rows, cols) in [(5, 6), (5, 7), (6, 5), (2, 2), (22, 22)] {
+            let placement = OpenCvCharucoPlacement::new(rows, cols);
+            let expected: Vec<Coord> = (0..rows as i32)
+                .flat_map(|j| (0..cols as i32).map(move |i| Coord::new(i, j)))
+                .filter(|c| (c.u + c.v) % 2 == 1)
+                .collect();
+            assert_eq!(placement.count(), expected.len());
+            for (id, bc) in expected.iter().enumerate() {
+                assert_eq!(placement.marker_cell(id as u32), Some(*bc));
+            }
+            assert_eq!(placement.marker_cell(expected.len() as u32), None);
+        }
+    }
+
+    struct Cells(Vec<Coord>);
+
+    impl MarkerPlacement for Cells {
+        fn marker_cell(&self, id: u32) -> Option<Coord> {
+            self.0.get(id as usize).copied()
+        }
+
+        fn count(&self) -> usize {
+            self.0.len()
+        }
+    }
+
+    #[test]
+    fn custom_placement_builds_lookups() {
+        let spec = build_board().spec();
+        let board = CharucoBoard::with_placement(
+            spec,
+            &Cells(vec![Coord::new(0, 0), Coord::new(2, 2), Coord::new(5, 4)]),
+        )
+        .expect("board");
+        assert_eq!(board.marker_count(), 3);
+        assert_eq!(board.marker_position(1), Some(Coord::new(2, 2)));
+        assert_eq!(board.marker_id_at(Coord::new(5, 4)), Some(2));
+        assert_eq!(board.marker_id_at(Coord::new(1, 0)), None);
+    }
+
+    #[test]
+    fn custom_placement_rejects_duplicate_and_out_of_range_cells() {
+        let spec = build_board().spec();
+        let dup = Cells(vec![Coord::new(1, 1), Coord::new(1, 1)]);
+        assert!(matches!(
+            CharucoBoard::with_placement(spec, &dup),
+            Err(CharucoBoardError::InvalidMarkerPlacement { id: 1 })
+        ));
+        let outside = Cells(vec![Coord::new(6, 0)]);
+        assert!(matches!(
+            CharucoBoard::with_placement(spec, &outside),
+            Err(CharucoBoardError::InvalidMarkerPlacement { id: 0 })
+        ));
+    }
+
+    #[test]
+    fn id_map_translates_lookups_and_rejects_bad_maps() {
+        let board = build_board();
+        let odd: Vec<u32> = (0..board.marker_count() as u32)
+            .map(|k| 2 * k + 1)
+            .collect();
+        let mapped = board.clone().with_id_map(odd.clone()).expect("odd ids fit");
+        assert_eq!(mapped.id_map(), Some(odd.as_slice()));
+        for (slot, (id, bc)) in mapped.iter_marker_positions().enumerate() {
+            assert_eq!(id, odd[slot]);
+            assert_eq!(
+                mapped.marker_position(id),
+                board.marker_position(slot as u32)
+            );
+            assert_eq!(mapped.marker_id_at(bc), Some(id));
+        }
+        assert_eq!(mapped.marker_position(0), None);
+        assert_eq!(mapped.marker_position(u32::MAX), None);
+        assert_eq!(board.marker_position(board.marker_count() as u32), None);
+
+        assert!(matches!(
+            board.clone().with_id_map(vec![1, 3]),
+            Err(CharucoBoardError::InvalidIdMap { slot: 2 })
+        ));
+        let mut dup = odd.clone();
+        dup[4] = dup[0];
+        assert!(matches!(
+            board.clone().with_id_map(dup),
+            Err(CharucoBoardError::InvalidIdMap { slot: 4 })
+        ));
+        let mut outside = odd;
+        outside[0] = 50;
+        assert!(matches!(
+            board.with_id_map(outside),
+            Err(CharucoBoardError::InvalidIdMap { slot: 0 })
+        ));
+    }
+
+    #[test]
+    fn marker_corners_board_span_marker_side() {
+        let dict = builtins::builtin_dictionary("DICT_4X4_50").expect("dict");
+        let board =
+            CharucoBoard::new(CharucoBoardSpec::new(5, 6, 20.0, 0.75, dict)).expect("board");
+        let side = 0.75 * 20.0;
+        for (id, bc) in board.iter_marker_positions() {
+            let [tl, tr, br, bl] = board.marker_corners_board(id).expect("known id");
+            assert!(((tr.x - tl.x) - side).abs() < 1e-5);
+            assert!(((bl.y - tl.y) - side).abs() < 1e-5);
+            assert_eq!((br.x, br.y), (tr.x, bl.y));
+            assert!([tl, tr, br, bl].iter().all(|p| p.z == 0.0));
+            // Centred in its square.
+            let cx = 0.5 * (tl.x + br.x);
+            let cy = 0.5 * (tl.y + br.y);
+            assert!((cx - (bc.u as f32 + 0.5) * 20.0).abs() < 1e-4);
+            assert!((cy - (bc.v as f32 + 0.5) * 20.0).abs() < 1e-4);
+        }
+        assert!(board
+            .marker_corners_board(board.marker_count() as u32)
+            .is_none());
+    }
+
+    #[test]
+    fn suggest_min_inliers_scales_with_board_size() {
+        let small = build_board();
+        let large = build_board_1000();
+        assert!(large.suggest_min_inliers(0.5) > small.suggest_min_inliers(0.5));
+        assert_eq!(large.suggest_min_inliers(1.0), large.marker_count() / 2);
+        assert_eq!(small.suggest_min_inliers(0.0), 1);
+        assert_eq!(small.suggest_min_inliers(f32::NAN), 1);
+    }
+
     #[test]
     fn marker_22() {
         let board = build_board_1000();
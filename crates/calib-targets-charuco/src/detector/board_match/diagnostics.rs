@@ -13,7 +13,7 @@ use super::score_matrix::ScoreMatrix;
 use super::{match_board_core, BoardMatchConfig, MatchSink};
 use crate::alignment::CharucoAlignment;
 use crate::board::CharucoBoard;
-use calib_targets_aruco::{rotate_code_u64, CellSamples, MarkerCell, MarkerDetection};
+use calib_targets_aruco::{rotate_code_u64, BitOrder, CellSamples, MarkerCell, MarkerDetection};
 use calib_targets_core::{log_sigmoid, Coord, GrayImageView, GridAlignment};
 use serde::Serialize;
 #[cfg(feature = "tracing")]
@@ -280,8 +280,11 @@ fn fill_expected_from_board(
         cell.expected_id = Some(id);
         cell.expected_score = matrix.score(ci, id, rot);
         if cell.sampled && !cell.interior_means.is_empty() {
+            // `interior_means` is row-major; compare against the expected
+            // code in the same layout.
             let base = dict.codes()[id as usize];
-            let code = rotate_code_u64(base, bits, rot);
+            let code = rotate_code_u64(base, bits, rot, dict.bit_order());
+            let code = repack_row_major(code, bits, dict.bit_order());
             cell.expected_bit_ll = per_bit_log_likelihood(cell, code, bits);
         }
     }
@@ -305,6 +308,20 @@ fn per_bit_log_likelihood(cell: &CellDiag, expected_code: u64, bits: usize) -> V
     out
 }
 
+fn repack_row_major(code: u64, bits: usize, order: BitOrder) -> u64 {
+    if order == BitOrder::RowMajor {
+        return code;
+    }
+    let mut out = 0u64;
+    for y in 0..bits {
+        for x in 0..bits {
+            let bit = (code >> order.bit_index(x, y, bits)) & 1;
+            out |= bit << BitOrder::RowMajor.bit_index(x, y, bits);
+        }
+    }
+    out
+}
+
 fn extract_interior_means(s: Option<&CellSamples>, bits: usize) -> Vec<u8> {
     let Some(s) = s else {
         return Vec::new();
@@ -2,10 +2,11 @@
 //!
 //! Given the winning [`GridAlignment`], walks the candidate cells, looks up the
 //! marker each maps to on the board, and emits a [`MarkerDetection`] with
-//! rectified + image-space geometry and a calibrated confidence score.
+//! rectified + image-space geometry and a calibrated confidence score. Markers
+//! scoring below the configured confidence floor are skipped.
 
 use super::hypothesis::rotation_index_for;
-use super::score_matrix::ScoreMatrix;
+use super::score_matrix::{marker_confidence, ScoreMatrix};
 use crate::board::CharucoBoard;
 use calib_targets_aruco::{rotate_code_u64, CellSamples, MarkerCell, MarkerDetection};
 use calib_targets_core::{cell_rect_corners_at, Coord, GridAlignment};
@@ -20,6 +21,7 @@ pub(super) fn emit_markers(
     matrix: &ScoreMatrix,
     alignment: &GridAlignment,
     px_per_square: f32,
+    min_score: f32,
 ) -> Vec<MarkerDetection> {
     let mut out = Vec::new();
     for (ci, cell) in cells.iter().enumerate() {
@@ -35,10 +37,14 @@ pub(super) fn emit_markers(
         let Some(samp) = samples[ci].as_ref() else {
             continue;
         };
+        let score = marker_confidence(s, samples_bit_count(samp));
+        if score < min_score {
+            continue;
+        }
         let dict = board.spec().dictionary;
         let bits = dict.marker_size();
         let base = dict.codes()[expected_id as usize];
-        let observed_code = rotate_code_u64(base, bits, rot);
+        let observed_code = rotate_code_u64(base, bits, rot, dict.bit_order());
         let gc = rotate_gc_top_left(cell.gc, rot);
 
         // Rectified-pixel cell corners: a `px_per_square × px_per_square`
@@ -53,9 +59,10 @@ pub(super) fn emit_markers(
             gc,
             rotation: rot,
             hamming: 0,
-            score: sigmoid01(s / samples_bit_count(samp) as f32),
+            score,
             border_score: samp.border_black_fraction,
             code: observed_code,
+            matched_code: Some(observed_code),
             inverted: false,
             corners_rect,
             corners_img: Some(cell.corners_img),
@@ -69,10 +76,6 @@ fn samples_bit_count(s: &CellSamples) -> usize {
     (s.bits_per_side * s.bits_per_side).max(1)
 }
 
-fn sigmoid01(x: f32) -> f32 {
-    1.0 / (1.0 + (-x).exp())
-}
-
 fn rotate_gc_top_left(gc0: Coord, rot: u8) -> Coord {
     match rot & 3 {
         0 => gc0,
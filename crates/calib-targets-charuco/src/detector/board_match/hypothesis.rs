@@ -177,6 +177,51 @@ fn score_hypothesis(
     (total, contributing)
 }
 
+/// Mean grid distance, in cells, between where the chosen alignment places
+/// each marker and the cell that scores best for that marker.
+///
+/// For every cell the alignment maps onto a marker square, the marker's id is
+/// scored (at the alignment's rotation) against *all* candidate cells; the
+/// residual is how far the best-scoring cell lies from the predicted one. A
+/// correct alignment puts every marker on its own best cell (`0.0`); an
+/// alignment off by one square scores `~1.0`. `score` is the score-matrix
+/// lookup `(cell, id, rotation) -> score`; `0.0` when no cell maps to a
+/// scored marker.
+pub(super) fn alignment_residual_cells(
+    board: &CharucoBoard,
+    cells: &[MarkerCell],
+    alignment: &GridAlignment,
+    score: impl Fn(usize, u32, u8) -> f32,
+) -> f32 {
+    let rot = rotation_index_for(&alignment.transform);
+    let mut sum = 0.0f32;
+    let mut count = 0usize;
+    for (ci, cell) in cells.iter().enumerate() {
+        let Some(id) = board.marker_id_at(alignment.map(cell.gc.u, cell.gc.v)) else {
+            continue;
+        };
+        if !score(ci, id, rot).is_finite() {
+            continue;
+        }
+        let best = (0..cells.len())
+            .map(|cj| (cj, score(cj, id, rot)))
+            .filter(|(_, s)| s.is_finite())
+            .max_by(|a, b| a.1.total_cmp(&b.1).then(b.0.cmp(&a.0)));
+        let Some((cj, _)) = best else {
+            continue;
+        };
+        let du = (cells[cj].gc.u - cell.gc.u) as f32;
+        let dv = (cells[cj].gc.v - cell.gc.v) as f32;
+        sum += du.hypot(dv);
+        count += 1;
+    }
+    if count == 0 {
+        0.0
+    } else {
+        sum / count as f32
+    }
+}
+
 /// Index in [`GRID_TRANSFORMS_D4`] of the given transform (its 90°-rotation
 /// step), or `0` if not found. Shared by marker emission and diagnostics to
 /// recover the rotation from a chosen alignment.
@@ -200,6 +245,51 @@ mod tests {
         assert_eq!(win, (0, 2, 0, 2));
     }
 
+    /// Every square of a 5×5 OpenCV board as a candidate cell, scored so a
+    /// marker matches only the cell that truly holds it.
+    #[test]
+    fn residual_is_zero_for_true_alignment_and_grows_when_shifted() {
+        use crate::board::CharucoBoardSpec;
+        use calib_targets_aruco::builtins;
+        use nalgebra::Point2;
+
+        let board = CharucoBoard::new(CharucoBoardSpec::new(
+            5,
+            5,
+            1.0,
+            0.75,
+            builtins::DICT_4X4_50,
+        ))
+        .expect("board");
+        let cells: Vec<MarkerCell> = (0..5)
+            .flat_map(|v| (0..5).map(move |u| Coord::new(u, v)))
+            .map(|gc| MarkerCell {
+                gc,
+                corners_img: [Point2::origin(); 4],
+            })
+            .collect();
+        let score = |ci: usize, id: u32, _rot: u8| {
+            if board.marker_id_at(cells[ci].gc) == Some(id) {
+                0.0
+            } else {
+                -10.0
+            }
+        };
+
+        let truth = GridAlignment {
+            transform: GridTransform::IDENTITY,
+            translation: [0, 0],
+        };
+        assert_eq!(alignment_residual_cells(&board, &cells, &truth, score), 0.0);
+
+        let shifted = GridAlignment {
+            transform: GridTransform::IDENTITY,
+            translation: [1, 0],
+        };
+        let residual = alignment_residual_cells(&board, &cells, &shifted, score);
+        assert!((residual - 1.0).abs() < 1e-6, "residual {residual}");
+    }
+
     #[test]
     fn translation_window_rejects_oversize() {
         let mapped = [Coord::new(0, 0), Coord::new(10, 10)];
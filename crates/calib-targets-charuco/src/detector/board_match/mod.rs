@@ -44,7 +44,11 @@ use calib_targets_core::{GrayImageView, GridAlignment};
 use tracing::instrument;
 
 use emit::emit_markers;
-use hypothesis::{enumerate_hypotheses, hypothesis_to_alignment, margin_from_scores};
+use hypothesis::{
+    alignment_residual_cells, enumerate_hypotheses, hypothesis_to_alignment, margin_from_scores,
+};
+#[cfg(test)]
+pub(crate) use score_matrix::marker_confidence;
 use score_matrix::{build_score_matrix, ScoreMatrix};
 
 // `DiagHypothesis` is always compiled (the production matcher selects on it).
@@ -68,6 +72,7 @@ pub(crate) struct BoardMatchConfig {
     pub per_bit_floor: f32,
     pub alignment_min_margin: f32,
     pub cell_weight_border_threshold: f32,
+    pub min_marker_score: f32,
 }
 
 impl Default for BoardMatchConfig {
@@ -78,6 +83,7 @@ impl Default for BoardMatchConfig {
             per_bit_floor: -6.0,
             alignment_min_margin: 0.05,
             cell_weight_border_threshold: 0.5,
+            min_marker_score: 0.0,
         }
     }
 }
@@ -228,6 +234,7 @@ fn match_board_core<S: MatchSink>(
         &matrix,
         &chosen_align,
         cfg.px_per_square,
+        cfg.min_marker_score,
     );
     if markers.is_empty() {
         sink.reject_no_markers();
@@ -235,10 +242,14 @@ fn match_board_core<S: MatchSink>(
     }
 
     let n_markers = markers.len();
+    let residual_cells = alignment_residual_cells(board, cells, &chosen_align, |ci, id, rot| {
+        matrix.score(ci, id, rot)
+    });
     log::debug!(
-        "board-level matcher: {} markers emitted, alignment margin = {:.3}",
+        "board-level matcher: {} markers emitted, alignment margin = {:.3}, residual = {:.2} cells",
         n_markers,
         margin,
+        residual_cells,
     );
 
     Some((
@@ -246,6 +257,7 @@ fn match_board_core<S: MatchSink>(
         CharucoAlignment {
             alignment: chosen_align,
             marker_inliers: (0..n_markers).collect(),
+            residual_cells,
         },
     ))
 }
@@ -270,3 +282,95 @@ fn sample_cells(
         .map(|c| sample_cell(image, c, px_per_square, scan_cfg, bits))
         .collect()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::board::{CharucoBoardSpec, MarkerLayout};
+    use calib_targets_aruco::builtins;
+    use nalgebra::Point2;
+
+    /// Interior bit means of `code` at `dark` / `light`, inside a black
+    /// one-bit border, thresholded at their midpoint.
+    fn samples_for(code: u64, dark: u8, light: u8, board: &CharucoBoard) -> CellSamples {
+        let dict = board.spec().dictionary;
+        let bits = dict.marker_size();
+        let side = bits + 2;
+        let mut mean_grid = vec![dark; side * side];
+        for by in 0..bits {
+            for bx in 0..bits {
+                let k = dict.bit_order().bit_index(bx, by, bits);
+                if (code >> k) & 1 == 0 {
+                    mean_grid[(by + 1) * side + bx + 1] = light;
+                }
+            }
+        }
+        CellSamples {
+            cells_per_side: side,
+            bits_per_side: bits,
+            border_bits: 1,
+            mean_grid,
+            otsu_threshold: ((dark as u16 + light as u16) / 2) as u8,
+            border_black_fraction: 1.0,
+        }
+    }
+
+    #[test]
+    fn min_marker_score_keeps_a_weak_decode_out_of_the_alignment() {
+        let spec = CharucoBoardSpec::new(5, 7, 20.0, 0.75, builtins::DICT_4X4_50)
+            .with_marker_layout(MarkerLayout::OpenCvCharuco);
+        let board = CharucoBoard::new(spec).expect("board");
+        let positions: Vec<(u32, calib_targets_core::Coord)> =
+            board.iter_marker_positions().take(4).collect();
+        let cells: Vec<MarkerCell> = positions
+            .iter()
+            .map(|&(_, gc)| MarkerCell {
+                gc,
+                corners_img: [Point2::origin(); 4],
+            })
+            .collect();
+        // Three crisp reads plus one barely above the noise: the weak cell
+        // still reads its own id best, just with little margin per bit.
+        let codes = spec.dictionary.codes();
+        let samples: Vec<Option<CellSamples>> = positions
+            .iter()
+            .enumerate()
+            .map(|(i, &(id, _))| {
+                let (dark, light) = if i == 3 { (122, 128) } else { (20, 230) };
+                Some(samples_for(codes[id as usize], dark, light, &board))
+            })
+            .collect();
+
+        let emitted_ids = |min_marker_score: f32| {
+            let cfg = BoardMatchConfig {
+                min_marker_score,
+                ..BoardMatchConfig::default()
+            };
+            let matrix = build_score_matrix(&board, &samples, &cfg).expect("matrix");
+            let (best, _, _) = enumerate_hypotheses(&board, &cells, &matrix).expect("hypothesis");
+            let alignment = hypothesis_to_alignment(&best);
+            let markers = emit_markers(
+                &board,
+                &cells,
+                &samples,
+                &matrix,
+                &alignment,
+                cfg.px_per_square,
+                cfg.min_marker_score,
+            );
+            (
+                matrix.weights[3],
+                markers.iter().map(|m| m.id).collect::<Vec<_>>(),
+            )
+        };
+
+        let all: Vec<u32> = positions.iter().map(|&(id, _)| id).collect();
+        let (weight, ids) = emitted_ids(0.0);
+        assert!(weight > 0.0);
+        assert_eq!(ids, all);
+
+        let (weight, ids) = emitted_ids(0.9);
+        assert_eq!(weight, 0.0);
+        assert_eq!(ids, all[..3]);
+    }
+}
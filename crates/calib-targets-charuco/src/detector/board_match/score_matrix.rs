@@ -69,6 +69,7 @@ pub(super) fn build_score_matrix(
 ) -> Option<ScoreMatrix> {
     let dict = board.spec().dictionary;
     let bits = dict.marker_size();
+    let order = dict.bit_order();
     let num_markers = board.marker_count();
     if num_markers == 0 {
         return None;
@@ -82,9 +83,9 @@ pub(super) fn build_score_matrix(
         let base = dict.codes()[id as usize];
         rotated_codes.push([
             base,
-            rotate_code_u64(base, bits, 1),
-            rotate_code_u64(base, bits, 2),
-            rotate_code_u64(base, bits, 3),
+            rotate_code_u64(base, bits, 1, order),
+            rotate_code_u64(base, bits, 2, order),
+            rotate_code_u64(base, bits, 3, order),
         ]);
     }
     // Flat dictionary-id → slot table (ids index into `dict.codes()`, so its
@@ -126,7 +127,7 @@ pub(super) fn build_score_matrix(
                 let cy = border + by;
                 let mean = s.mean_grid[cy * cells_per_side + cx] as f32;
                 let signed = slope_over_255 * (thresh - mean);
-                let k = by * bits + bx;
+                let k = order.bit_index(bx, by, bits);
                 ll_set[k] = log_sigmoid(signed).max(cfg.per_bit_floor);
                 ll_clear[k] = log_sigmoid(-signed).max(cfg.per_bit_floor);
             }
@@ -146,6 +147,16 @@ pub(super) fn build_score_matrix(
                 scores[ci * num_markers * 4 + slot * 4 + rot as usize] = total;
             }
         }
+
+        // A cell that reads no board marker confidently stays out of the
+        // hypothesis scoring altogether.
+        if cfg.min_marker_score > 0.0 {
+            let row = &scores[ci * num_markers * 4..(ci + 1) * num_markers * 4];
+            let best = row.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+            if marker_confidence(best, n_interior) < cfg.min_marker_score {
+                weights[ci] = 0.0;
+            }
+        }
     }
 
     Some(ScoreMatrix {
@@ -158,6 +169,13 @@ pub(super) fn build_score_matrix(
     })
 }
 
+/// Decode confidence of a cell-vs-marker `score` over `n_bits` bits: twice
+/// the logistic of the mean per-bit log-likelihood, in `(0, 1]` with `1` for
+/// a perfect read. This is the emitted `MarkerDetection::score`.
+pub(crate) fn marker_confidence(score: f32, n_bits: usize) -> f32 {
+    2.0 / (1.0 + (-(score / n_bits as f32)).exp())
+}
+
 fn cell_weight(s: &CellSamples, cfg: &BoardMatchConfig) -> f32 {
     if cfg.cell_weight_border_threshold <= 0.0 {
         return 1.0;
@@ -1,14 +1,32 @@
+use super::CharucoDetectError;
 use crate::alignment::CharucoAlignment;
 use crate::board::CharucoBoard;
+use calib_targets_aruco::MarkerDetection;
 use calib_targets_core::{Coord, LabeledCorner, TargetDetection, TargetKind};
 use std::collections::HashMap;
 
+/// Label the chessboard corners with ChArUco ids under `alignment`.
+///
+/// Each corner's score is its chessboard score weighted by marker support:
+/// `score * (1 + s) / 2`, where `s` is the best `score` among the inlier
+/// markers (`alignment.marker_inliers` into `markers`) in the four squares
+/// around the corner, clamped to `[0, 1]`, or `0` without one. A cleanly
+/// decoded marker scores `1`, so a corner next to one keeps its chessboard
+/// score; an isolated one is halved.
+///
+/// When two corners land on the same ChArUco corner the higher-scoring one is
+/// kept, or, with `reject_duplicates`, the mapping fails with
+/// [`CharucoDetectError::DuplicateLabel`] for the smallest such id.
 pub(crate) fn map_charuco_corners(
     board: &CharucoBoard,
     chessboard: &TargetDetection,
     alignment: &CharucoAlignment,
-) -> TargetDetection {
+    markers: &[MarkerDetection],
+    reject_duplicates: bool,
+) -> Result<TargetDetection, CharucoDetectError> {
+    let support = marker_support(board, alignment, markers);
     let mut by_grid: HashMap<Coord, LabeledCorner> = HashMap::new();
+    let mut duplicate_id: Option<u32> = None;
 
     for corner in &chessboard.corners {
         let Some(grid) = corner.grid else {
@@ -33,18 +51,54 @@ pub(crate) fn map_charuco_corners(
         match by_grid.get(&grid) {
             None => {
                 by_grid.insert(grid, candidate);
+                continue;
             }
             Some(prev) if candidate.score > prev.score => {
                 by_grid.insert(grid, candidate);
             }
             _ => {}
         }
+        duplicate_id = Some(duplicate_id.map_or(id, |d| d.min(id)));
+    }
+    if let Some(id) = duplicate_id.filter(|_| reject_duplicates) {
+        return Err(CharucoDetectError::DuplicateLabel { id });
     }
 
     let mut corners: Vec<LabeledCorner> = by_grid.into_values().collect();
+    for c in &mut corners {
+        let best = c.grid.and_then(|g| support.get(&g)).copied().unwrap_or(0.0);
+        let s = best.clamp(0.0, 1.0);
+        c.score *= 0.5 * (1.0 + s);
+    }
     corners.sort_by_key(|c| c.id.unwrap_or(u32::MAX));
 
-    TargetDetection::new(TargetKind::Charuco, corners)
+    Ok(TargetDetection::new(TargetKind::Charuco, corners))
+}
+
+/// Best inlier-marker score touching each board corner. A marker in square
+/// `(sx, sy)` touches corners `(sx, sy)` through `(sx + 1, sy + 1)`.
+fn marker_support(
+    board: &CharucoBoard,
+    alignment: &CharucoAlignment,
+    markers: &[MarkerDetection],
+) -> HashMap<Coord, f32> {
+    let mut support: HashMap<Coord, f32> = HashMap::new();
+    for m in alignment
+        .marker_inliers
+        .iter()
+        .filter_map(|&k| markers.get(k))
+    {
+        let Some(cell) = board.marker_position(m.id) else {
+            continue;
+        };
+        for (du, dv) in [(0, 0), (1, 0), (0, 1), (1, 1)] {
+            let best = support
+                .entry(Coord::new(cell.u + du, cell.v + dv))
+                .or_insert(0.0);
+            *best = best.max(m.score);
+        }
+    }
+    support
 }
 
 fn grid_from_charuco_id(board: &CharucoBoard, id: u32) -> Option<Coord> {
@@ -64,6 +118,8 @@ mod tests {
     use super::*;
     use crate::alignment::CharucoAlignment;
     use crate::board::{CharucoBoard, CharucoBoardSpec, MarkerLayout};
+    use crate::detector::board_match::marker_confidence;
+    use crate::detector::test_support;
     use calib_targets_aruco::builtins;
     use calib_targets_core::GridAlignment;
     use nalgebra::Point2;
@@ -111,12 +167,32 @@ mod tests {
         assert_eq!(grid_from_charuco_id(&board, 3), Some(Coord::new(1, 2)));
     }
 
+    /// Inlier marker `id` decoded in its board square with `score`.
+    fn decoded_marker(board: &CharucoBoard, id: u32, score: f32) -> MarkerDetection {
+        let gc = board.marker_position(id).expect("marker on board");
+        MarkerDetection {
+            score,
+            ..test_support::marker(id, gc)
+        }
+    }
+
+    /// Score the board matcher gives a 16-bit marker read with no bit error
+    /// or doubt.
+    fn clean_decode_score() -> f32 {
+        marker_confidence(0.0, 16)
+    }
+
     #[test]
-    fn map_charuco_corners_keeps_best_confidence() {
+    fn duplicate_labels_keep_best_confidence_unless_strict() {
         let board = build_board();
+        // Marker 0 sits in a square touching inner corner (1, 1).
+        let markers = [decoded_marker(&board, 0, clean_decode_score())];
+        let cell = markers[0].gc;
+        assert!((0..=1).contains(&(1 - cell.u)) && (0..=1).contains(&(1 - cell.v)));
         let alignment = CharucoAlignment {
             alignment: GridAlignment::IDENTITY,
-            marker_inliers: Vec::new(),
+            marker_inliers: vec![0],
+            residual_cells: 0.0,
         };
         let chessboard = TargetDetection::new(
             TargetKind::Chessboard,
@@ -126,7 +202,15 @@ mod tests {
             ],
         );
 
-        let detection = map_charuco_corners(&board, &chessboard, &alignment);
+        let err = map_charuco_corners(&board, &chessboard, &alignment, &markers, true)
+            .expect_err("strict mapping");
+        assert!(
+            matches!(err, CharucoDetectError::DuplicateLabel { id: 0 }),
+            "{err:?}"
+        );
+
+        let detection = map_charuco_corners(&board, &chessboard, &alignment, &markers, false)
+            .expect("lenient mapping");
         assert_eq!(detection.corners.len(), 1);
         let corner = &detection.corners[0];
         assert_eq!(corner.position, Point2::new(2.0, 2.0));
@@ -135,4 +219,47 @@ mod tests {
         assert_eq!(corner.grid, Some(Coord::new(1, 1)));
         assert_eq!(corner.target_position, board.charuco_object_xy(0));
     }
+
+    #[test]
+    fn corners_next_to_a_decoded_marker_gain_confidence() {
+        let board = build_board();
+        let marker = decoded_marker(&board, 0, clean_decode_score());
+        let cell = marker.gc;
+        let alignment = CharucoAlignment {
+            alignment: GridAlignment::IDENTITY,
+            marker_inliers: vec![0],
+            residual_cells: 0.0,
+        };
+        // One inner corner of the marker's square and one inner corner two
+        // squares away from it, with equal chessboard scores.
+        let near = [(0, 0), (1, 0), (0, 1), (1, 1)]
+            .map(|(du, dv)| Coord::new(cell.u + du, cell.v + dv))
+            .into_iter()
+            .find(|g| (1..=3).contains(&g.u) && (1..=3).contains(&g.v))
+            .expect("marker square has an inner corner");
+        let far = Coord::new(4 - near.u, 4 - near.v);
+        assert!((far.u - near.u).abs() > 1 || (far.v - near.v).abs() > 1);
+        let chessboard = TargetDetection::new(
+            TargetKind::Chessboard,
+            vec![
+                LabeledCorner::new(Point2::new(1.0, 1.0), 0.8).with_grid(near),
+                LabeledCorner::new(Point2::new(3.0, 3.0), 0.8).with_grid(far),
+            ],
+        );
+
+        let detection = map_charuco_corners(&board, &chessboard, &alignment, &[marker], false)
+            .expect("lenient mapping");
+        let score_at = |g: Coord| {
+            detection
+                .corners
+                .iter()
+                .find(|c| c.grid == Some(g))
+                .expect("corner mapped")
+                .score
+        };
+        // A clean decode leaves the chessboard score intact; no support
+        // halves it.
+        assert!((score_at(near) - 0.8).abs() < 1e-5);
+        assert!((score_at(far) - 0.4).abs() < 1e-6);
+    }
 }
@@ -353,6 +353,7 @@ mod tests {
             score: 1.0,
             border_score: 1.0,
             code: 0,
+            matched_code: None,
             inverted: false,
             corners_rect: [Point2::new(0.0, 0.0); 4],
             corners_img: None,
@@ -375,6 +376,7 @@ mod tests {
             score: 1.0,
             border_score: 1.0,
             code: 0,
+            matched_code: None,
             inverted: false,
             corners_rect: [Point2::new(0.0, 0.0); 4],
             corners_img: None,
@@ -396,6 +398,7 @@ mod tests {
             score: 1.0,
             border_score: 1.0,
             code: 0,
+            matched_code: None,
             inverted: false,
             corners_rect: [Point2::new(0.0, 0.0); 4],
             corners_img: None,
@@ -417,6 +420,7 @@ mod tests {
             score: 1.0,
             border_score: 1.0,
             code: 0,
+            matched_code: None,
             inverted: false,
             corners_rect: [Point2::new(0.0, 0.0); 4],
             corners_img: None,
@@ -14,6 +14,15 @@ pub enum CharucoDetectError {
         /// Number of markers that agreed with the best alignment found.
         inliers: usize,
     },
+    /// Two image corners were labelled as the same ChArUco corner — within
+    /// one grid component or across merged components — and
+    /// [`CharucoParams::reject_on_duplicate_labels`](crate::CharucoParams::reject_on_duplicate_labels)
+    /// is set.
+    #[error("ChArUco corner id {id} claimed by more than one image corner")]
+    DuplicateLabel {
+        /// The ChArUco corner id claimed more than once (the smallest such id).
+        id: u32,
+    },
     /// The chessboard parameters supplied via
     /// [`CharucoParams::chessboard`](crate::CharucoParams::chessboard) were
     /// rejected by the chessboard detector's own configuration validator
@@ -7,24 +7,44 @@
 //! missing any of their four corners are skipped, so every emitted
 //! [`MarkerCell`] has full geometry.
 
+use std::collections::HashMap;
+
 use calib_targets_aruco::MarkerCell;
 use calib_targets_core::{
     complete_cell_corners, corner_map_bounds, Coord, CornerMap, LabeledCorner,
 };
+use log::debug;
 
 #[cfg(feature = "tracing")]
 use tracing::instrument;
 
 /// Build a grid -> image map from inlier chessboard corners.
+///
+/// When several corners carry the same grid label, the highest-scoring one is
+/// kept (ties go to the earlier inlier), independent of inlier order; each
+/// collision is logged at debug level.
 #[cfg_attr(feature = "tracing", instrument(level = "info", skip(corners, inliers), fields(corners=inliers.len())))]
 pub(crate) fn build_corner_map(corners: &[LabeledCorner], inliers: &[usize]) -> CornerMap {
     let mut map = CornerMap::new();
+    let mut scores: HashMap<Coord, f32> = HashMap::with_capacity(inliers.len());
     for &idx in inliers {
-        if let Some(c) = corners.get(idx) {
-            if let Some(g) = c.grid {
-                map.insert(g, c.position);
+        let Some(c) = corners.get(idx) else {
+            continue;
+        };
+        let Some(g) = c.grid else {
+            continue;
+        };
+        if let Some(&kept) = scores.get(&g) {
+            debug!(
+                "duplicate grid label ({}, {}): scores {kept:.3} vs {:.3}",
+                g.u, g.v, c.score
+            );
+            if c.score <= kept {
+                continue;
             }
         }
+        scores.insert(g, c.score);
+        map.insert(g, c.position);
     }
     map
 }
@@ -78,6 +98,22 @@ mod tests {
         assert_eq!(map.get(&Coord::new(1, 0)), Some(&Point2::new(5.0, 6.0)));
     }
 
+    #[test]
+    fn build_corner_map_keeps_stronger_duplicate_label() {
+        let g = Coord::new(2, 3);
+        let corners = vec![
+            LabeledCorner::new(Point2::new(1.0, 1.0), 0.4).with_grid(g),
+            LabeledCorner::new(Point2::new(2.0, 2.0), 0.9).with_grid(g),
+            LabeledCorner::new(Point2::new(3.0, 3.0), 0.6).with_grid(g),
+        ];
+
+        for inliers in [[0, 1, 2], [2, 1, 0], [1, 0, 2]] {
+            let map = build_corner_map(&corners, &inliers);
+            assert_eq!(map.len(), 1);
+            assert_eq!(map.get(&g), Some(&Point2::new(2.0, 2.0)), "{inliers:?}");
+        }
+    }
+
     #[test]
     fn build_marker_cells_skips_incomplete_cells() {
         let mut map = CornerMap::new();
@@ -14,7 +14,7 @@
 //! deterministic regardless of component or hash ordering.
 
 use super::pipeline::RawMarkerCounts;
-use super::{CharucoCorner, CharucoDetectionResult};
+use super::{CharucoCorner, CharucoDetectError, CharucoDetectionResult};
 use calib_targets_aruco::MarkerDetection;
 use log::debug;
 use std::collections::HashMap;
@@ -29,13 +29,18 @@ use std::collections::HashMap;
 /// Each component's [`RawMarkerCounts`] is threaded in alongside its
 /// result; the returned counts are the sum over the winning group, so the
 /// caller can record them on [`super::CharucoDetectDiagnostics`].
+///
+/// With `reject_duplicates`, a corner id claimed by more than one component
+/// of the winning group fails the merge with
+/// [`CharucoDetectError::DuplicateLabel`] instead.
 pub(crate) fn merge_charuco_results(
     results: Vec<(CharucoDetectionResult, RawMarkerCounts)>,
-) -> (CharucoDetectionResult, RawMarkerCounts) {
+    reject_duplicates: bool,
+) -> Result<(CharucoDetectionResult, RawMarkerCounts), CharucoDetectError> {
     debug_assert!(!results.is_empty());
     if results.len() == 1 {
         // INVARIANT: results is non-empty (guaranteed by debug_assert! above), so next() is Some.
-        return results.into_iter().next().unwrap();
+        return Ok(results.into_iter().next().unwrap());
     }
 
     // Group by D4 transform.
@@ -71,19 +76,26 @@ pub(crate) fn merge_charuco_results(
 
     // Merge corners by charuco ID, keep highest score.
     let mut corners_by_id: HashMap<u32, CharucoCorner> = HashMap::new();
+    let mut duplicate_id: Option<u32> = None;
     for r in &best_group {
         for c in &r.0.corners {
             match corners_by_id.get(&c.id) {
                 None => {
                     corners_by_id.insert(c.id, c.clone());
+                    continue;
                 }
                 Some(prev) if c.score > prev.score => {
                     corners_by_id.insert(c.id, c.clone());
                 }
                 _ => {}
             }
+            duplicate_id = Some(duplicate_id.map_or(c.id, |id| id.min(c.id)));
         }
     }
+    if let Some(id) = duplicate_id.filter(|_| reject_duplicates) {
+        debug!("merge rejected: corner id {id} claimed by more than one component");
+        return Err(CharucoDetectError::DuplicateLabel { id });
+    }
 
     // Merge markers by marker ID, keep highest score.
     let mut markers_by_id: HashMap<u32, MarkerDetection> = HashMap::new();
@@ -105,7 +117,7 @@ pub(crate) fn merge_charuco_results(
     // the alignment translation (smaller wins) so the pick is independent of the
     // group's `HashMap`-derived ordering.
     // INVARIANT: best_group is non-empty — it was selected from a non-empty groups map above.
-    let best_alignment = best_group
+    let best = &best_group
         .iter()
         .max_by(|a, b| {
             a.0.markers
@@ -114,8 +126,9 @@ pub(crate) fn merge_charuco_results(
                 .then_with(|| b.0.alignment.translation.cmp(&a.0.alignment.translation))
         })
         .unwrap()
-        .0
-        .alignment;
+        .0;
+    let best_alignment = best.alignment;
+    let best_residual = best.alignment_residual_cells;
 
     let mut corners: Vec<CharucoCorner> = corners_by_id.into_values().collect();
     corners.sort_by_key(|c| c.id);
@@ -137,15 +150,17 @@ pub(crate) fn merge_charuco_results(
         markers.len()
     );
 
-    (
-        CharucoDetectionResult::new(corners, markers, best_alignment),
+    Ok((
+        CharucoDetectionResult::new(corners, markers, best_alignment)
+            .with_alignment_residual_cells(best_residual),
         raw_counts,
-    )
+    ))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::detector::test_support;
     use calib_targets_core::{Coord, GridAlignment, GridTransform};
     use nalgebra::Point2;
 
@@ -161,16 +176,8 @@ mod tests {
 
     fn marker(id: u32, score: f32) -> MarkerDetection {
         MarkerDetection {
-            id,
-            gc: Coord::new(id as i32, 0),
-            rotation: 0,
-            hamming: 0,
             score,
-            border_score: 1.0,
-            code: 0,
-            inverted: false,
-            corners_rect: [Point2::new(0.0, 0.0); 4],
-            corners_img: None,
+            ..test_support::marker(id, Coord::new(id as i32, 0))
         }
     }
 
@@ -199,7 +206,7 @@ mod tests {
     fn merge_non_overlapping() {
         let r1 = result(vec![corner(0, 1.0, 1.0, 0.9)], vec![marker(10, 0.8)]);
         let r2 = result(vec![corner(5, 5.0, 5.0, 0.7)], vec![marker(20, 0.6)]);
-        let (merged, raw) = merge_charuco_results(vec![r1, r2]);
+        let (merged, raw) = merge_charuco_results(vec![r1, r2], false).expect("lenient merge");
         assert_eq!(merged.corners.len(), 2);
         assert_eq!(merged.markers.len(), 2);
         assert_eq!(raw.raw_marker_count, 2);
@@ -215,7 +222,7 @@ mod tests {
             vec![corner(0, 1.1, 1.1, 0.3), corner(2, 3.0, 3.0, 0.7)],
             vec![marker(10, 0.9), marker(20, 0.6)],
         );
-        let (merged, _raw) = merge_charuco_results(vec![r1, r2]);
+        let (merged, _raw) = merge_charuco_results(vec![r1, r2], false).expect("lenient merge");
         assert_eq!(merged.corners.len(), 3);
         // Corner 0 should have the higher score (0.9 from r1)
         let c0 = merged.corners.iter().find(|c| c.id == 0).unwrap();
@@ -225,10 +232,32 @@ mod tests {
         assert_eq!(m10.score, 0.9);
     }
 
+    #[test]
+    fn strict_merge_rejects_duplicate_labels() {
+        let components = || {
+            vec![
+                result(vec![corner(0, 1.0, 1.0, 0.9)], vec![marker(10, 0.8)]),
+                result(
+                    vec![corner(0, 1.1, 1.1, 0.3), corner(2, 3.0, 3.0, 0.7)],
+                    vec![marker(20, 0.6)],
+                ),
+            ]
+        };
+        let err = merge_charuco_results(components(), true).expect_err("strict merge");
+        assert!(
+            matches!(err, CharucoDetectError::DuplicateLabel { id: 0 }),
+            "{err:?}"
+        );
+
+        let (merged, _raw) = merge_charuco_results(components(), false).expect("lenient merge");
+        let c0 = merged.corners.iter().find(|c| c.id == 0).unwrap();
+        assert_eq!(c0.score, 0.9);
+    }
+
     #[test]
     fn single_result_passthrough() {
         let r = result(vec![corner(0, 1.0, 1.0, 0.9)], vec![marker(10, 0.8)]);
-        let (merged, raw) = merge_charuco_results(vec![r]);
+        let (merged, raw) = merge_charuco_results(vec![r], true).expect("single result");
         assert_eq!(merged.corners.len(), 1);
         assert_eq!(merged.markers.len(), 1);
         assert_eq!(raw.raw_marker_count, 1);
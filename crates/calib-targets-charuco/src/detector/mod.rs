@@ -13,6 +13,8 @@ mod merge;
 mod params;
 mod pipeline;
 mod result;
+#[cfg(test)]
+mod test_support;
 
 // Diagnostics types reach the public surface only behind the `diagnostics`
 // feature (default off), consistent with `calib-targets-chessboard`. The
@@ -31,4 +33,4 @@ pub use params::{CharucoAdvancedTuning, CharucoParams};
 pub use pipeline::CharucoDetector;
 #[cfg(feature = "diagnostics")]
 pub use pipeline::{CharucoDetectDiagnostics, ComponentDiagnostics, ComponentOutcome};
-pub use result::{CharucoCorner, CharucoDetectionResult};
+pub use result::{CharucoCorner, CharucoDetectionResult, RectifiedQualityMap};
@@ -1,6 +1,7 @@
 use crate::board::CharucoBoardSpec;
 use calib_targets_aruco::ScanDecodeConfig;
 use calib_targets_chessboard::{AdvancedTuning, DetectorParams};
+use calib_targets_core::BoardConvention;
 use chess_corners::low_level::{ChessParams as ChessCornerParams, RefinerKind};
 use chess_corners::SaddlePointConfig;
 use serde::{Deserialize, Serialize};
@@ -41,6 +42,44 @@ pub struct CharucoParams {
     /// to confirm alignment for a small grid fragment.
     #[serde(default = "default_min_secondary_marker_inliers")]
     pub min_secondary_marker_inliers: usize,
+    /// Minimal number of distinct marker ids among the inliers, in every
+    /// component.
+    ///
+    /// Inliers repeating one id (a decode error) are not independent
+    /// constraints on the alignment; this floor rejects alignments that
+    /// rest on such repeats. Default `0` (no floor beyond the inlier counts).
+    #[serde(default)]
+    pub min_distinct_marker_ids: usize,
+    /// Minimal decode confidence, on the scale of
+    /// [`MarkerDetection::score`](calib_targets_aruco::MarkerDetection::score),
+    /// for a marker cell to take part in the board alignment.
+    ///
+    /// A cell whose best reading of any board marker scores below this is
+    /// left out of the hypothesis scoring, and markers emitted below it are
+    /// dropped, so a lucky low-confidence decode cannot become an inlier.
+    /// The matcher's scores lie in `(0, 1]`, `1` for a perfect read. Default
+    /// `0.0` (every cell takes part).
+    #[serde(default)]
+    pub min_marker_score: f32,
+    /// Fail the whole detection with
+    /// [`CharucoDetectError::DuplicateLabel`](crate::CharucoDetectError::DuplicateLabel)
+    /// when two image corners are labelled as the same ChArUco corner, within
+    /// one grid component or across merged components.
+    ///
+    /// Without it the higher-scoring claimant is kept. A duplicate means one
+    /// corner is labelled wrongly, so strict calibration pipelines may prefer
+    /// to drop the frame. Default `false`.
+    #[serde(default)]
+    pub reject_on_duplicate_labels: bool,
+    /// Origin convention for the output grid labels.
+    ///
+    /// Every corner's [`grid`](crate::CharucoCorner::grid) and the result's
+    /// alignment are relabelled into this convention; pass the same value
+    /// to the printable renderer's point list so the labels agree. Corner
+    /// ids and `target_position`s keep the printed board's frame. Default:
+    /// the canonical top-left origin.
+    #[serde(default)]
+    pub convention: BoardConvention,
     /// Relative threshold for local grid smoothness pre-filter.
     ///
     /// Each grid corner's position is predicted from its immediate neighbors
@@ -284,6 +323,10 @@ impl CharucoParams {
             // (1 primary / 1 secondary).
             min_marker_inliers: 1,
             min_secondary_marker_inliers: 1,
+            min_distinct_marker_ids: 0,
+            min_marker_score: 0.0,
+            reject_on_duplicate_labels: false,
+            convention: BoardConvention::CANONICAL,
             grid_smoothness_threshold_rel: 0.05,
             corner_validation_threshold_rel: 0.08,
             corner_redetect_params: default_redetect_params(),
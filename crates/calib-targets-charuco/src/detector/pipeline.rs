@@ -29,6 +29,16 @@ fn chessboard_detection_to_target(chess: &ChessboardDetection) -> TargetDetectio
     TargetDetection::new(TargetKind::Chessboard, corners)
 }
 
+/// Number of distinct marker ids among the inlier markers `inliers`
+/// (indices into `markers`).
+fn distinct_inlier_ids(markers: &[MarkerDetection], inliers: &[usize]) -> usize {
+    let ids: std::collections::HashSet<u32> = inliers
+        .iter()
+        .filter_map(|&k| markers.get(k).map(|m| m.id))
+        .collect();
+    ids.len()
+}
+
 /// Rich per-frame diagnostics captured by [`CharucoDetector::detect_with_diagnostics`].
 ///
 /// One entry per chessboard connected component the detector tried to
@@ -225,6 +235,20 @@ impl CharucoDetector {
         Ok(Self { board, params })
     }
 
+    /// Create a detector for a prebuilt board, e.g. one with a custom
+    /// [`MarkerPlacement`](crate::MarkerPlacement) from
+    /// [`CharucoBoard::with_placement`].
+    ///
+    /// `board` takes the place of `params.board`, which is overwritten with
+    /// `board.spec()` so the two cannot disagree.
+    pub fn with_board(mut params: CharucoParams, board: CharucoBoard) -> Self {
+        params.board = board.spec();
+        if !params.scan.marker_size_rel.is_finite() || params.scan.marker_size_rel <= 0.0 {
+            params.scan.marker_size_rel = params.board.marker_size_rel;
+        }
+        Self { board, params }
+    }
+
     /// Board definition used by the detector.
     #[inline]
     pub fn board(&self) -> &CharucoBoard {
@@ -362,6 +386,9 @@ impl CharucoDetector {
                     );
                     results.push((result, raw_counts));
                 }
+                // A duplicated label fails the whole frame under
+                // `reject_on_duplicate_labels`, not just its component.
+                Err(e @ CharucoDetectError::DuplicateLabel { .. }) => return Err(e),
                 Err(e) => {
                     debug!("component {i} failed: {e}");
                 }
@@ -378,10 +405,10 @@ impl CharucoDetector {
         let (merged, raw_counts) = if results.len() == 1 {
             results.into_iter().next().unwrap()
         } else {
-            merge_charuco_results(results)
+            merge_charuco_results(results, self.params.reject_on_duplicate_labels)?
         };
         sink.record_frame_counts(raw_counts);
-        Ok(merged)
+        Ok(merged.in_convention(self.params.convention, &self.board))
     }
 
     /// Run the full charuco pipeline on a single chessboard component.
@@ -436,6 +463,7 @@ impl CharucoDetector {
             per_bit_floor: self.params.advanced.per_bit_floor,
             alignment_min_margin: self.params.advanced.alignment_min_margin,
             cell_weight_border_threshold: self.params.advanced.cell_weight_border_threshold,
+            min_marker_score: self.params.min_marker_score,
         };
         let matched = sink.run_match(image, &cells, &self.board, &scan_cfg, &board_cfg);
 
@@ -487,7 +515,33 @@ impl CharucoDetector {
             return Err(err);
         }
 
-        let detection = map_charuco_corners(&self.board, &chessboard, &alignment);
+        let distinct_ids = distinct_inlier_ids(&markers, &alignment.marker_inliers);
+        if distinct_ids < self.params.min_distinct_marker_ids {
+            warn!(
+                "marker-to-board alignment rejected: {} distinct inlier ids < required {}",
+                distinct_ids, self.params.min_distinct_marker_ids
+            );
+            let err = CharucoDetectError::AlignmentFailed {
+                inliers: alignment.marker_inliers.len(),
+            };
+            sink.component_failed(ctx, &err);
+            return Err(err);
+        }
+
+        let detection = match map_charuco_corners(
+            &self.board,
+            &chessboard,
+            &alignment,
+            &markers,
+            self.params.reject_on_duplicate_labels,
+        ) {
+            Ok(detection) => detection,
+            Err(err) => {
+                warn!("corner mapping rejected: {err}");
+                sink.component_failed(ctx, &err);
+                return Err(err);
+            }
+        };
         debug!(
             "mapped {} ChArUco corners before validation",
             detection.corners.len()
@@ -513,7 +567,8 @@ impl CharucoDetector {
         sink.component_ok(ctx, markers.len(), detection.corners.len(), raw_counts);
 
         Ok((
-            CharucoDetectionResult::from_target_detection(detection, markers, alignment.alignment),
+            CharucoDetectionResult::from_target_detection(detection, markers, alignment.alignment)
+                .with_alignment_residual_cells(alignment.residual_cells),
             raw_counts,
         ))
     }
@@ -591,7 +646,9 @@ impl PipelineSink for DiagCollector {
 mod tests {
     use super::*;
     use crate::board::{CharucoBoardSpec, MarkerLayout};
+    use crate::detector::test_support;
     use calib_targets_aruco::builtins;
+    use calib_targets_core::Coord;
 
     fn test_board() -> CharucoBoardSpec {
         CharucoBoardSpec::new(5, 7, 20.0, 0.75, builtins::DICT_4X4_50)
@@ -613,6 +670,71 @@ mod tests {
         }
     }
 
+    /// Board-matcher stand-in that hands the pipeline a fixed match, so a
+    /// component can be driven past marker decoding with chosen inliers.
+    struct ScriptedMatch(Vec<MarkerDetection>);
+
+    impl PipelineSink for ScriptedMatch {
+        fn run_match(
+            &mut self,
+            _image: &GrayImageView<'_>,
+            _cells: &[calib_targets_aruco::MarkerCell],
+            _board: &CharucoBoard,
+            _scan_cfg: &calib_targets_aruco::ScanDecodeConfig,
+            _cfg: &BoardMatchConfig,
+        ) -> Option<(Vec<MarkerDetection>, CharucoAlignment)> {
+            let alignment = CharucoAlignment {
+                alignment: calib_targets_core::GridAlignment::IDENTITY,
+                marker_inliers: (0..self.0.len()).collect(),
+                residual_cells: 0.0,
+            };
+            Some((self.0.clone(), alignment))
+        }
+    }
+
+    /// Three inliers that all decode one id fail alignment under
+    /// `min_distinct_marker_ids = 3`; three distinct ids pass it.
+    #[test]
+    fn distinct_ids_gate_counts_repeated_ids_once() {
+        let mut params = CharucoParams::for_board(&test_board());
+        params.min_distinct_marker_ids = 3;
+        let detector = CharucoDetector::new(params).expect("detector");
+
+        let mut corners = calib_targets_chessboard::test_util::grid_corners(6, 4, 40.0, 0.0, 100.0);
+        for c in &mut corners {
+            c.position += nalgebra::Vector2::new(60.0, 60.0);
+        }
+        let chessboard = ChessDetector::new(detector.params.chessboard.clone())
+            .expect("chessboard detector")
+            .detect(&corners)
+            .expect("chessboard");
+        let buf = vec![128u8; 400 * 300];
+        let image = GrayImageView {
+            width: 400,
+            height: 300,
+            data: &buf,
+        };
+        let run = |ids: [u32; 3]| {
+            let markers = ids
+                .into_iter()
+                .zip([0, 2, 4])
+                .map(|(id, u)| test_support::marker(id, Coord::new(u, 1)))
+                .collect();
+            detector.detect_component(&image, &chessboard, 3, 0, &mut ScriptedMatch(markers))
+        };
+
+        let repeated = run([4, 4, 4]);
+        assert!(
+            matches!(
+                repeated,
+                Err(CharucoDetectError::AlignmentFailed { inliers: 3 })
+            ),
+            "{repeated:?}"
+        );
+        let distinct = run([4, 5, 6]);
+        assert!(distinct.is_ok(), "{distinct:?}");
+    }
+
     /// The default detector config reaches the chessboard stage and reports
     /// `ChessboardNotDetected` on an empty image (no algorithm guard rejects
     /// it up front).
@@ -1,7 +1,12 @@
+use crate::board::CharucoBoard;
 use calib_targets_aruco::MarkerDetection;
-use calib_targets_core::{Coord, GridAlignment, LabeledCorner, TargetDetection, TargetKind};
+use calib_targets_core::{
+    estimate_homography_rect_to_img, BoardConvention, Coord, DetectionQuality, DetectionSummary,
+    GridAlignment, Homography, LabeledCorner, Summarize, TargetDetection, TargetKind,
+};
 use nalgebra::Point2;
 use serde::Serialize;
+use std::collections::HashMap;
 
 /// A labelled ChArUco inner corner.
 #[non_exhaustive]
@@ -9,13 +14,18 @@ use serde::Serialize;
 pub struct CharucoCorner {
     /// Sub-pixel image position.
     pub position: Point2<f32>,
-    /// ChArUco board corner coordinate.
+    /// ChArUco board corner coordinate, in the detector's
+    /// [`CharucoParams::convention`](crate::CharucoParams::convention).
     pub grid: Coord,
     /// ChArUco logical corner ID.
     pub id: u32,
     /// Physical board-space position in millimetres.
     pub target_position: Point2<f32>,
-    /// Detector-specific corner score; higher is better.
+    /// Corner confidence; higher is better. The chessboard corner score
+    /// weighted by `(1 + s) / 2`, `s` the best score of an inlier marker in
+    /// the four squares around the corner (`0` without one). A cleanly
+    /// decoded marker scores `1`, so its neighbours keep the chessboard
+    /// score.
     pub score: f32,
 }
 
@@ -56,6 +66,34 @@ impl CharucoCorner {
     }
 }
 
+/// Per-cell decode quality over the rectified grid, for heatmap rendering.
+///
+/// Produced by [`CharucoDetectionResult::rectified_quality_map`]. Cells are in
+/// the rectified grid frame (the frame of each marker's cell *before* the
+/// board alignment) and cover the bounding box of the decoded markers.
+#[non_exhaustive]
+#[derive(Clone, Debug, Serialize)]
+pub struct RectifiedQualityMap {
+    /// Rectified cell coordinate of `scores[0]` (top-left of the map).
+    pub origin: Coord,
+    /// Map width in cells.
+    pub cols: usize,
+    /// Map height in cells.
+    pub rows: usize,
+    /// Row-major per-cell scores, `rows * cols` values: the marker's
+    /// `border_score` for decoded cells, `0.0` otherwise.
+    pub scores: Vec<f32>,
+}
+
+impl RectifiedQualityMap {
+    /// Score of the rectified cell `cell`, or `None` outside the map.
+    pub fn score_at(&self, cell: Coord) -> Option<f32> {
+        let x = usize::try_from(cell.u - self.origin.u).ok()?;
+        let y = usize::try_from(cell.v - self.origin.v).ok()?;
+        (x < self.cols && y < self.rows).then(|| self.scores[y * self.cols + x])
+    }
+}
+
 /// Output of a ChArUco detection run.
 ///
 /// `#[non_exhaustive]`: construct with [`CharucoDetectionResult::new`].
@@ -69,6 +107,11 @@ pub struct CharucoDetectionResult {
     pub markers: Vec<MarkerDetection>,
     /// Alignment from the rectified grid coordinate system into board coordinates.
     pub alignment: GridAlignment,
+    /// Mean distance, in cells, between where [`Self::alignment`] places each
+    /// marker and the cell that decodes it best. `~0` for a sound alignment; a
+    /// value approaching `1` flags a borderline, possibly off-by-one
+    /// alignment.
+    pub alignment_residual_cells: f32,
 }
 
 impl CharucoDetectionResult {
@@ -82,9 +125,17 @@ impl CharucoDetectionResult {
             corners,
             markers,
             alignment,
+            alignment_residual_cells: 0.0,
         }
     }
 
+    /// Set [`Self::alignment_residual_cells`].
+    #[must_use]
+    pub fn with_alignment_residual_cells(mut self, residual: f32) -> Self {
+        self.alignment_residual_cells = residual;
+        self
+    }
+
     pub(crate) fn from_target_detection(
         detection: TargetDetection,
         markers: Vec<MarkerDetection>,
@@ -101,11 +152,489 @@ impl CharucoDetectionResult {
         Self::new(corners, markers, alignment)
     }
 
+    /// Relabel the canonical-frame corners and alignment into `convention`
+    /// over `board`'s square-corner lattice.
+    pub(crate) fn in_convention(
+        mut self,
+        convention: BoardConvention,
+        board: &CharucoBoard,
+    ) -> Self {
+        if convention == BoardConvention::CANONICAL {
+            return self;
+        }
+        let spec = board.spec();
+        let relabel = convention.alignment(spec.cols + 1, spec.rows + 1);
+        for corner in &mut self.corners {
+            corner.grid = relabel.map(corner.grid.u, corner.grid.v);
+        }
+        self.alignment = self.alignment.then(&relabel);
+        self
+    }
+
+    /// Per-cell decode quality of [`Self::markers`] as a dense grid over the
+    /// rectified board; see [`RectifiedQualityMap`]. Empty (`0 × 0`) when no
+    /// marker was decoded.
+    pub fn rectified_quality_map(&self) -> RectifiedQualityMap {
+        // `MarkerDetection::gc` is the rotated top-left corner; undo the
+        // rotation offset to recover the sampled cell.
+        let cell_of = |m: &MarkerDetection| {
+            let (du, dv) = match m.rotation {
+                1 => (1, 0),
+                2 => (1, 1),
+                3 => (0, 1),
+                _ => (0, 0),
+            };
+            Coord::new(m.gc.u - du, m.gc.v - dv)
+        };
+        let cells: Vec<(Coord, f32)> = self
+            .markers
+            .iter()
+            .map(|m| (cell_of(m), m.border_score))
+            .collect();
+        let Some(min_u) = cells.iter().map(|(c, _)| c.u).min() else {
+            return RectifiedQualityMap {
+                origin: Coord::new(0, 0),
+                cols: 0,
+                rows: 0,
+                scores: Vec::new(),
+            };
+        };
+        let min_v = cells.iter().map(|(c, _)| c.v).min().unwrap_or(0);
+        let max_u = cells.iter().map(|(c, _)| c.u).max().unwrap_or(0);
+        let max_v = cells.iter().map(|(c, _)| c.v).max().unwrap_or(0);
+        let cols = (max_u - min_u + 1) as usize;
+        let rows = (max_v - min_v + 1) as usize;
+        let mut scores = vec![0.0_f32; cols * rows];
+        for (c, score) in cells {
+            let k = (c.v - min_v) as usize * cols + (c.u - min_u) as usize;
+            scores[k] = scores[k].max(score);
+        }
+        RectifiedQualityMap {
+            origin: Coord::new(min_u, min_v),
+            cols,
+            rows,
+            scores,
+        }
+    }
+
+    /// Predicted image position of the centre of board square `(i, j)`, from
+    /// a least-squares board → image homography fitted over [`Self::corners`].
+    ///
+    /// Square `(i, j)` is bounded by board corners `(i, j)` and
+    /// `(i + 1, j + 1)`, the frame of [`CharucoCorner::grid`], so the query
+    /// works for any square — including border squares and ones whose
+    /// corners were not detected. Returns `None` with fewer than four
+    /// corners or a degenerate fit.
+    pub fn cell_center_image(&self, i: i32, j: i32) -> Option<Point2<f32>> {
+        let h = self.board_to_image_homography()?;
+        Some(h.apply(Point2::new(i as f32 + 0.5, j as f32 + 0.5)))
+    }
+
+    /// Model-predicted image position of every detected corner, as
+    /// `(id, position)` in the order of [`Self::corners`].
+    ///
+    /// Each corner's board `grid` is projected through the same least-squares
+    /// board → image homography as [`Self::cell_center_image`], for drawing
+    /// predicted against observed positions. A large gap marks a corner that
+    /// disagrees with the rest of the board. Under lens distortion the gaps
+    /// grow towards the image edges for every corner alike. Empty with fewer
+    /// than four corners or a degenerate fit.
+    pub fn expected_corner_positions(&self) -> Vec<(u32, Point2<f32>)> {
+        let Some(h) = self.board_to_image_homography() else {
+            return Vec::new();
+        };
+        self.corners
+            .iter()
+            .map(|c| {
+                let grid = Point2::new(c.grid.u as f32, c.grid.v as f32);
+                (c.id, h.apply(grid))
+            })
+            .collect()
+    }
+
+    fn board_to_image_homography(&self) -> Option<Homography> {
+        let (board, image): (Vec<Point2<f32>>, Vec<Point2<f32>>) = self
+            .corners
+            .iter()
+            .map(|c| (Point2::new(c.grid.u as f32, c.grid.v as f32), c.position))
+            .unzip();
+        estimate_homography_rect_to_img(&board, &image)
+    }
+
+    /// Image scale of the detected board in pixels per board unit (the unit
+    /// of [`CharucoCorner::target_position`], millimetres for boards built
+    /// from a spec).
+    ///
+    /// The median, over every pair of detected corners one square apart, of
+    /// their image distance divided by their board distance. Under
+    /// perspective this is the scale near the middle of the detection, which
+    /// is enough to sanity-check `cell_size` / `px_per_square` assumptions.
+    /// Returns `None` when no two adjacent corners were detected.
+    pub fn estimate_pixel_scale(&self) -> Option<f32> {
+        let by_grid: HashMap<Coord, &CharucoCorner> =
+            self.corners.iter().map(|c| (c.grid, c)).collect();
+        let mut ratios: Vec<f32> = self
+            .corners
+            .iter()
+            .flat_map(|a| {
+                [
+                    Coord::new(a.grid.u + 1, a.grid.v),
+                    Coord::new(a.grid.u, a.grid.v + 1),
+                ]
+                .into_iter()
+                .filter_map(|n| by_grid.get(&n))
+                .map(move |b| (a, *b))
+            })
+            .filter_map(|(a, b)| {
+                let board = (b.target_position - a.target_position).norm();
+                (board > f32::EPSILON).then(|| (b.position - a.position).norm() / board)
+            })
+            .collect();
+        if ratios.is_empty() {
+            return None;
+        }
+        let mid = ratios.len() / 2;
+        let (_, median, _) = ratios.select_nth_unstable_by(mid, f32::total_cmp);
+        Some(*median)
+    }
+
     /// Convert typed corners into the shared `TargetDetection` carrier.
+    ///
+    /// The ChArUco layer: [`TargetKind::Charuco`], every corner carrying its
+    /// board `grid`, absolute `id` and `target_position`. See
+    /// [`Self::chessboard_detection`] for the plain-grid view.
     pub fn target_detection(&self) -> TargetDetection {
         TargetDetection::new(
             TargetKind::Charuco,
             self.corners.iter().map(CharucoCorner::to_labeled).collect(),
         )
     }
+
+    /// Ground-truth-free quality terms measured against `board`, the board
+    /// this result was detected on.
+    ///
+    /// Completeness is the share of the board's inner corners that were
+    /// detected, and marker coverage the share of its markers that were
+    /// decoded, so a clean fragment of the board scores below a full view.
+    /// See [`DetectionQuality`] for the other terms.
+    pub fn quality(&self, board: &CharucoBoard) -> DetectionQuality {
+        let mut quality = self.target_detection().quality();
+        let inner = board.expected_inner_rows() as usize * board.expected_inner_cols() as usize;
+        quality.completeness = (self.corners.len() as f32 / inner.max(1) as f32).min(1.0);
+        let markers = board.marker_count().max(1);
+        quality.with_marker_coverage(self.markers.len() as f32 / markers as f32)
+    }
+
+    /// Composite quality in `[0, 1]` of [`Self::quality`], for picking the
+    /// best of many frames; see [`DetectionQuality::score`] for the weighting.
+    pub fn quality_score(&self, board: &CharucoBoard) -> f32 {
+        self.quality(board).score()
+    }
+
+    /// The same corners as a plain chessboard grid, for solvers that only
+    /// need integer-labelled inner corners.
+    ///
+    /// [`TargetKind::Chessboard`], with each corner's position, score and
+    /// board `grid` only — no `id` or `target_position`. The labels are
+    /// still the marker-aligned board coordinates, not the chessboard
+    /// detector's pre-alignment labels, which the result does not keep.
+    pub fn chessboard_detection(&self) -> TargetDetection {
+        TargetDetection::new(
+            TargetKind::Chessboard,
+            self.corners
+                .iter()
+                .map(|c| LabeledCorner::new(c.position, c.score).with_grid(c.grid))
+                .collect(),
+        )
+    }
+}
+
+impl Summarize for CharucoDetectionResult {
+    /// Corner summary with the inlier marker count and the board alignment.
+    fn summary(&self) -> DetectionSummary {
+        DetectionSummary::from_detection(&self.target_detection())
+            .with_markers(self.markers.len())
+            .with_transform(self.alignment)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::detector::test_support;
+    use nalgebra::Vector2;
+
+    fn marker(id: u32, gc: Coord, rotation: u8, border_score: f32) -> MarkerDetection {
+        MarkerDetection {
+            rotation,
+            border_score,
+            ..test_support::marker(id, gc)
+        }
+    }
+
+    /// Inner corners `(1..=cols, 1..=rows)` of a board drawn at `square` px
+    /// per square from the image origin, ids row-major, target positions in
+    /// squares and unit scores.
+    fn grid_corners(cols: i32, rows: i32, square: f32) -> Vec<CharucoCorner> {
+        (1..=rows)
+            .flat_map(|v| (1..=cols).map(move |u| (u, v)))
+            .enumerate()
+            .map(|(id, (u, v))| {
+                CharucoCorner::new(
+                    Point2::new(square * u as f32, square * v as f32),
+                    Coord::new(u, v),
+                    id as u32,
+                    Point2::new(u as f32, v as f32),
+                    1.0,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn quality_map_scores_decoded_cells_only() {
+        // Checkerboard of decoded cells at (1,1), (3,1), (2,2); the rotated
+        // marker reports its gc shifted by one cell in `u`.
+        let markers = vec![
+            marker(0, Coord::new(1, 1), 0, 0.9),
+            marker(1, Coord::new(4, 1), 1, 0.8),
+            marker(2, Coord::new(2, 2), 0, 0.95),
+        ];
+        let result = CharucoDetectionResult::new(Vec::new(), markers, GridAlignment::IDENTITY);
+        let map = result.rectified_quality_map();
+
+        assert_eq!(map.origin, Coord::new(1, 1));
+        assert_eq!((map.cols, map.rows), (3, 2));
+        assert_eq!(map.scores.len(), 6);
+        for (cell, expected) in [
+            (Coord::new(1, 1), 0.9),
+            (Coord::new(3, 1), 0.8),
+            (Coord::new(2, 2), 0.95),
+        ] {
+            assert_eq!(map.score_at(cell), Some(expected));
+        }
+        for cell in [Coord::new(2, 1), Coord::new(1, 2), Coord::new(3, 2)] {
+            assert_eq!(map.score_at(cell), Some(0.0));
+        }
+        assert_eq!(map.scores.iter().filter(|&&s| s > 0.0).count(), 3);
+        assert_eq!(map.score_at(Coord::new(0, 0)), None);
+
+        let empty = CharucoDetectionResult::new(Vec::new(), Vec::new(), GridAlignment::IDENTITY)
+            .rectified_quality_map();
+        assert_eq!((empty.cols, empty.rows), (0, 0));
+    }
+
+    #[test]
+    fn cell_centers_follow_the_board_homography() {
+        // Inner corners (1..=4, 1..=3) of a 5 × 4 board at 30 px per square.
+        let to_image = |u: f32, v: f32| Point2::new(40.0 + 30.0 * u, 25.0 + 30.0 * v);
+        let mut corners = grid_corners(4, 3, 30.0);
+        for c in &mut corners {
+            c.position = to_image(c.grid.u as f32, c.grid.v as f32);
+        }
+        let result = CharucoDetectionResult::new(corners, Vec::new(), GridAlignment::IDENTITY);
+
+        // Square (0, 0) sits on the board border; no corner bounds it on
+        // three sides, yet its centre is still predicted.
+        for (i, j) in [(0, 0), (2, 1), (4, 3)] {
+            let got = result.cell_center_image(i, j).expect("cell centre");
+            let expected = to_image(i as f32 + 0.5, j as f32 + 0.5);
+            assert!((got - expected).norm() < 1e-2, "{got:?} vs {expected:?}");
+        }
+        let empty = CharucoDetectionResult::new(Vec::new(), Vec::new(), GridAlignment::IDENTITY);
+        assert!(empty.cell_center_image(0, 0).is_none());
+    }
+
+    #[test]
+    fn expected_corners_track_a_clean_perspective_detection() {
+        // Inner corners of a 6 × 5 board under a mild perspective warp.
+        let to_image = |u: f32, v: f32| {
+            let w = 1.0 + 0.03 * u;
+            Point2::new((50.0 + 30.0 * u) / w, (20.0 + 30.0 * v + 2.0 * u) / w)
+        };
+        let mut corners = grid_corners(5, 4, 30.0);
+        for c in &mut corners {
+            c.position = to_image(c.grid.u as f32, c.grid.v as f32);
+        }
+        let clean =
+            CharucoDetectionResult::new(corners.clone(), Vec::new(), GridAlignment::IDENTITY);
+        let expected = clean.expected_corner_positions();
+        assert_eq!(expected.len(), clean.corners.len());
+        for ((id, predicted), c) in expected.iter().zip(&clean.corners) {
+            assert_eq!(*id, c.id);
+            assert!((predicted - c.position).norm() < 1e-2, "{id}");
+        }
+
+        // A corner pulled 4 px off its true position has the largest gap.
+        corners[7].position.x += 4.0;
+        let off = CharucoDetectionResult::new(corners, Vec::new(), GridAlignment::IDENTITY);
+        let gaps: Vec<f32> = off
+            .expected_corner_positions()
+            .iter()
+            .zip(&off.corners)
+            .map(|((_, p), c)| (p - c.position).norm())
+            .collect();
+        let worst = (0..gaps.len()).max_by(|&a, &b| gaps[a].total_cmp(&gaps[b]));
+        assert_eq!(worst, Some(7));
+        assert!(gaps[7] > 2.0, "{}", gaps[7]);
+
+        let empty = CharucoDetectionResult::new(Vec::new(), Vec::new(), GridAlignment::IDENTITY);
+        assert!(empty.expected_corner_positions().is_empty());
+    }
+
+    #[test]
+    fn chessboard_and_charuco_layers_differ_in_kind_and_labels() {
+        let corners = vec![
+            CharucoCorner::new(
+                Point2::new(10.0, 20.0),
+                Coord::new(1, 1),
+                0,
+                Point2::new(20.0, 20.0),
+                0.8,
+            ),
+            CharucoCorner::new(
+                Point2::new(40.0, 20.0),
+                Coord::new(2, 1),
+                1,
+                Point2::new(40.0, 20.0),
+                0.6,
+            ),
+        ];
+        let result = CharucoDetectionResult::new(corners, Vec::new(), GridAlignment::IDENTITY);
+        let charuco = result.target_detection();
+        let chessboard = result.chessboard_detection();
+
+        assert_eq!(charuco.kind, TargetKind::Charuco);
+        assert_eq!(chessboard.kind, TargetKind::Chessboard);
+        assert_eq!(chessboard.corners.len(), charuco.corners.len());
+        for (plain, full) in chessboard.corners.iter().zip(&charuco.corners) {
+            assert_eq!(plain.position, full.position);
+            assert_eq!(plain.grid, full.grid);
+            assert!(full.id.is_some() && full.target_position.is_some());
+            assert!(plain.id.is_none() && plain.target_position.is_none());
+        }
+    }
+
+    #[test]
+    fn summary_reports_corners_markers_and_alignment() {
+        // Full 3 × 2 block of inner corners at 30 px per square, backed by
+        // two markers.
+        let mut corners = grid_corners(3, 2, 30.0);
+        for c in &mut corners {
+            c.score = 0.8;
+        }
+        let markers = vec![
+            marker(0, Coord::new(1, 1), 0, 0.9),
+            marker(1, Coord::new(2, 0), 0, 0.9),
+        ];
+        let alignment = GridAlignment::IDENTITY;
+        let summary = CharucoDetectionResult::new(corners, markers, alignment).summary();
+
+        assert_eq!(summary.kind, TargetKind::Charuco);
+        assert_eq!(summary.num_corners, 6);
+        assert_eq!(summary.completeness, 1.0);
+        assert_eq!(summary.num_markers, 2);
+        assert!((summary.coverage - 60.0 * 30.0).abs() < 1e-3);
+        assert_eq!(summary.transform, Some(alignment));
+        assert!((summary.score - 0.8).abs() < 1e-6);
+    }
+
+    /// Board with `rows × cols` squares in the OpenCV ChArUco layout.
+    fn board(rows: u32, cols: u32) -> CharucoBoard {
+        let spec = crate::board::CharucoBoardSpec::new(
+            rows,
+            cols,
+            20.0,
+            0.75,
+            calib_targets_aruco::builtins::DICT_4X4_50,
+        )
+        .with_marker_layout(crate::board::MarkerLayout::OpenCvCharuco);
+        CharucoBoard::new(spec).expect("board")
+    }
+
+    /// `board`'s markers whose squares lie in `0..=max_u × 0..=max_v`.
+    fn markers_within(board: &CharucoBoard, max_u: i32, max_v: i32) -> Vec<MarkerDetection> {
+        board
+            .iter_marker_positions()
+            .filter(|(_, gc)| gc.u <= max_u && gc.v <= max_v)
+            .map(|(id, gc)| marker(id, gc, 0, 0.9))
+            .collect()
+    }
+
+    #[test]
+    fn quality_counts_marker_coverage() {
+        // All six inner corners of a 3 × 4-square board, which carries six
+        // markers; three are decoded.
+        let board = board(3, 4);
+        assert_eq!(board.marker_count(), 6);
+        let corners = grid_corners(3, 2, 30.0);
+        let markers: Vec<MarkerDetection> = board
+            .iter_marker_positions()
+            .take(3)
+            .map(|(id, gc)| marker(id, gc, 0, 0.9))
+            .collect();
+        let half = CharucoDetectionResult::new(corners.clone(), markers, GridAlignment::IDENTITY);
+        let bare = CharucoDetectionResult::new(corners, Vec::new(), GridAlignment::IDENTITY);
+
+        assert_eq!(half.quality(&board).marker_coverage, Some(0.5));
+        assert_eq!(bare.quality(&board).marker_coverage, Some(0.0));
+        assert_eq!(half.quality(&board).completeness, 1.0);
+        assert!(half.quality_score(&board) > bare.quality_score(&board));
+    }
+
+    #[test]
+    fn complete_fragment_scores_below_the_full_board() {
+        // A 5 × 6-square board has 4 × 5 inner corners. The fragment is a
+        // gap-free 3 × 2 block of them with every marker it borders.
+        let board = board(5, 6);
+        let full = CharucoDetectionResult::new(
+            grid_corners(5, 4, 30.0),
+            markers_within(&board, 5, 4),
+            GridAlignment::IDENTITY,
+        );
+        let fragment = CharucoDetectionResult::new(
+            grid_corners(3, 2, 30.0),
+            markers_within(&board, 3, 2),
+            GridAlignment::IDENTITY,
+        );
+
+        let (q_full, q_frag) = (full.quality(&board), fragment.quality(&board));
+        assert_eq!(q_full.completeness, 1.0);
+        assert_eq!(q_full.marker_coverage, Some(1.0));
+        assert!((q_frag.completeness - 6.0 / 20.0).abs() < 1e-6);
+        assert!(q_frag.marker_coverage.unwrap() < 0.5);
+        // Both are perfectly regular, so only board coverage separates them.
+        assert!(fragment.quality_score(&board) < full.quality_score(&board));
+    }
+
+    #[test]
+    fn pixel_scale_matches_the_rendered_square_size() {
+        // 20 mm squares rendered at 30 px per square, with two inner corners
+        // missing: 1.5 px per millimetre.
+        let (square_mm, px_per_square) = (20.0_f32, 30.0_f32);
+        let corners: Vec<CharucoCorner> = grid_corners(4, 3, px_per_square)
+            .into_iter()
+            .filter(|c| c.grid != Coord::new(2, 2) && c.grid != Coord::new(4, 1))
+            .map(|mut c| {
+                c.position += Vector2::new(12.0, 7.0);
+                c.target_position = Point2::new(
+                    square_mm * c.target_position.x,
+                    square_mm * c.target_position.y,
+                );
+                c
+            })
+            .collect();
+        let result = CharucoDetectionResult::new(corners, Vec::new(), GridAlignment::IDENTITY);
+
+        let scale = result.estimate_pixel_scale().expect("scale");
+        assert!((scale - px_per_square / square_mm).abs() < 1e-4, "{scale}");
+
+        // A lone corner has no neighbour to measure against.
+        let single = CharucoDetectionResult::new(
+            result.corners[..1].to_vec(),
+            Vec::new(),
+            GridAlignment::IDENTITY,
+        );
+        assert!(single.estimate_pixel_scale().is_none());
+    }
 }
@@ -0,0 +1,24 @@
+//! Fixtures shared by the detector's unit tests.
+
+use calib_targets_aruco::MarkerDetection;
+use calib_targets_core::Coord;
+use nalgebra::Point2;
+
+/// A clean, unrotated decode of marker `id` in grid cell `gc`: zero Hamming
+/// distance, unit scores and no image-space corners. Tests override the
+/// fields they exercise with struct-update syntax.
+pub(super) fn marker(id: u32, gc: Coord) -> MarkerDetection {
+    MarkerDetection {
+        id,
+        gc,
+        rotation: 0,
+        hamming: 0,
+        score: 1.0,
+        border_score: 1.0,
+        code: 0,
+        matched_code: None,
+        inverted: false,
+        corners_rect: [Point2::origin(); 4],
+        corners_img: None,
+    }
+}
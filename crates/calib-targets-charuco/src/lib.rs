@@ -53,10 +53,13 @@ pub mod link_check;
 #[cfg(feature = "diagnostics")]
 pub mod diagnostics;
 
-pub use board::{CharucoBoard, CharucoBoardError, CharucoBoardSpec, MarkerLayout};
+pub use board::{
+    CharucoBoard, CharucoBoardError, CharucoBoardSpec, MarkerLayout, MarkerPlacement,
+    OpenCvCharucoPlacement, StandardBoard,
+};
 pub use detector::{
     CharucoAdvancedTuning, CharucoCorner, CharucoDetectError, CharucoDetectionResult,
-    CharucoDetector, CharucoParams,
+    CharucoDetector, CharucoParams, RectifiedQualityMap,
 };
 pub use io::{
     load_board_spec_any, resolve_dictionary, BoardSpecLoadError, CharucoConfigError,
@@ -74,4 +77,6 @@ pub use link_check::{
 // directly.
 pub use calib_targets_aruco::{builtins, Dictionary};
 pub use calib_targets_chessboard::ChessCorner;
-pub use calib_targets_core::{GrayImageView, GridAlignment, GridTransform};
+pub use calib_targets_core::{
+    BoardConvention, BoardOrigin, GrayImageView, GridAlignment, GridTransform,
+};
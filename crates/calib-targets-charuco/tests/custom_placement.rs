@@ -0,0 +1,264 @@
+//! End-to-end detection of a board whose markers follow a custom
+//! [`MarkerPlacement`] rather than the OpenCV ChArUco layout.
+//!
+//! The synthetic board places markers only on the white squares of even rows.
+//! The image is rendered from that placement, the ChESS corners are
+//! synthesized at the inner intersections, and the detector must recover the
+//! marker ids and the board-frame corner ids exactly. A second board keeps the
+//! OpenCV layout but draws its markers from the odd dictionary ids only. A
+//! third run checks that corner scores carry the decoded markers' support.
+
+use calib_targets_aruco::builtins;
+use calib_targets_charuco::{
+    CharucoBoard, CharucoBoardSpec, CharucoDetector, CharucoParams, ChessCorner, MarkerPlacement,
+};
+use calib_targets_chessboard::test_util::grid_corners;
+use calib_targets_core::{Coord, GrayImageView};
+use nalgebra::Vector2;
+
+const ROWS: u32 = 6;
+const COLS: u32 = 7;
+const SQUARE_PX: usize = 40;
+const MARGIN_PX: usize = 40;
+const MARKER_SIZE_REL: f32 = 0.75;
+
+/// Markers on the white squares (`(i + j)` odd) of even rows only, numbered
+/// row-major.
+struct EvenRowsOnly {
+    cells: Vec<Coord>,
+}
+
+impl EvenRowsOnly {
+    fn new(rows: u32, cols: u32) -> Self {
+        let cells = (0..rows as i32)
+            .step_by(2)
+            .flat_map(|j| (0..cols as i32).map(move |i| Coord::new(i, j)))
+            .filter(|c| (c.u + c.v) % 2 == 1)
+            .collect();
+        Self { cells }
+    }
+}
+
+impl MarkerPlacement for EvenRowsOnly {
+    fn marker_cell(&self, id: u32) -> Option<Coord> {
+        self.cells.get(id as usize).copied()
+    }
+
+    fn count(&self) -> usize {
+        self.cells.len()
+    }
+}
+
+/// Render the board: black squares where `(i + j)` is even, and each placed
+/// marker centred in its white square (1-module black border, inner bits
+/// row-major, black = 1).
+fn render(board: &CharucoBoard) -> (usize, usize, Vec<u8>) {
+    let spec = board.spec();
+    let width = spec.cols as usize * SQUARE_PX + 2 * MARGIN_PX;
+    let height = spec.rows as usize * SQUARE_PX + 2 * MARGIN_PX;
+    let mut data = vec![255u8; width * height];
+
+    for j in 0..spec.rows as usize {
+        for i in 0..spec.cols as usize {
+            if (i + j) % 2 == 0 {
+                for y in 0..SQUARE_PX {
+                    let row = (MARGIN_PX + j * SQUARE_PX + y) * width;
+                    for x in 0..SQUARE_PX {
+                        data[row + MARGIN_PX + i * SQUARE_PX + x] = 0;
+                    }
+                }
+            }
+        }
+    }
+
+    let bits = spec.dictionary.marker_size();
+    let modules = bits + 2;
+    let marker_px = (SQUARE_PX as f32 * MARKER_SIZE_REL) as usize;
+    let module_px = marker_px / modules;
+    let inset = (SQUARE_PX - module_px * modules) / 2;
+    for (id, bc) in board.iter_marker_positions() {
+        let code = spec.dictionary.codes()[id as usize];
+        let x0 = MARGIN_PX + bc.u as usize * SQUARE_PX + inset;
+        let y0 = MARGIN_PX + bc.v as usize * SQUARE_PX + inset;
+        for my in 0..modules {
+            for mx in 0..modules {
+                let border = mx == 0 || my == 0 || mx + 1 == modules || my + 1 == modules;
+                let black = border || (code >> ((my - 1) * bits + (mx - 1))) & 1 == 1;
+                if !black {
+                    continue;
+                }
+                for y in 0..module_px {
+                    let row = (y0 + my * module_px + y) * width;
+                    for x in 0..module_px {
+                        data[row + x0 + mx * module_px + x] = 0;
+                    }
+                }
+            }
+        }
+    }
+    (width, height, data)
+}
+
+/// Inner-intersection corners with alternating axis-slot parity, shifted
+/// onto the rendered board.
+fn inner_corners(rows: u32, cols: u32) -> Vec<ChessCorner> {
+    let origin = Vector2::repeat((MARGIN_PX + SQUARE_PX) as f32);
+    let mut corners = grid_corners(
+        cols as usize - 1,
+        rows as usize - 1,
+        SQUARE_PX as f32,
+        0.0,
+        100.0,
+    );
+    for c in &mut corners {
+        c.position += origin;
+    }
+    corners
+}
+
+#[test]
+fn custom_placement_aligns_to_board_frame() {
+    let spec = CharucoBoardSpec::new(ROWS, COLS, 1.0, MARKER_SIZE_REL, builtins::DICT_4X4_50);
+    let placement = EvenRowsOnly::new(ROWS, COLS);
+    let board = CharucoBoard::with_placement(spec, &placement).expect("valid placement");
+    assert_eq!(board.marker_count(), 9);
+
+    let (width, height, data) = render(&board);
+    let image = GrayImageView {
+        width,
+        height,
+        data: &data,
+    };
+    let corners = inner_corners(ROWS, COLS);
+
+    let mut params = CharucoParams::for_board(&spec);
+    params.px_per_square = SQUARE_PX as f32;
+    let detector = CharucoDetector::with_board(params, board);
+    let result = detector.detect(&image, &corners).expect("detection");
+
+    assert!(!result.markers.is_empty());
+    for marker in &result.markers {
+        let expected = placement
+            .marker_cell(marker.id)
+            .expect("decoded id is placed on the board");
+        let quad = marker.corners_img.expect("image corners");
+        let cx = quad.iter().map(|p| p.x).sum::<f32>() / 4.0;
+        let cy = quad.iter().map(|p| p.y).sum::<f32>() / 4.0;
+        let square = Coord::new(
+            ((cx - MARGIN_PX as f32) / SQUARE_PX as f32).floor() as i32,
+            ((cy - MARGIN_PX as f32) / SQUARE_PX as f32).floor() as i32,
+        );
+        assert_eq!(square, expected, "marker {} at wrong cell", marker.id);
+    }
+
+    assert_eq!(result.corners.len(), corners.len());
+    for corner in &result.corners {
+        let i = ((corner.position.x - MARGIN_PX as f32) / SQUARE_PX as f32).round() as i32;
+        let j = ((corner.position.y - MARGIN_PX as f32) / SQUARE_PX as f32).round() as i32;
+        assert_eq!(corner.grid, Coord::new(i, j));
+        let expected_id = detector
+            .board()
+            .charuco_corner_id_from_board_corner(i, j)
+            .expect("inner corner");
+        assert_eq!(corner.id, expected_id);
+    }
+}
+
+#[test]
+fn id_mapped_board_aligns_with_odd_dictionary_ids() {
+    let spec = CharucoBoardSpec::new(ROWS, COLS, 1.0, MARKER_SIZE_REL, builtins::DICT_4X4_50);
+    let plain = CharucoBoard::new(spec).expect("board");
+    let odd_ids = (0..plain.marker_count() as u32)
+        .map(|k| 2 * k + 1)
+        .collect();
+    let board = plain.clone().with_id_map(odd_ids).expect("valid id map");
+
+    let (width, height, data) = render(&board);
+    let image = GrayImageView {
+        width,
+        height,
+        data: &data,
+    };
+    let corners = inner_corners(ROWS, COLS);
+
+    let mut params = CharucoParams::for_board(&spec);
+    params.px_per_square = SQUARE_PX as f32;
+    let detector = CharucoDetector::with_board(params, board);
+    let result = detector.detect(&image, &corners).expect("detection");
+
+    assert!(result.markers.len() >= plain.marker_count() / 2);
+    for marker in &result.markers {
+        assert_eq!(marker.id % 2, 1, "decoded an unmapped id {}", marker.id);
+        let slot = (marker.id - 1) / 2;
+        assert_eq!(
+            detector.board().marker_position(marker.id),
+            plain.marker_position(slot)
+        );
+    }
+
+    assert_eq!(result.corners.len(), corners.len());
+    for corner in &result.corners {
+        let i = ((corner.position.x - MARGIN_PX as f32) / SQUARE_PX as f32).round() as i32;
+        let j = ((corner.position.y - MARGIN_PX as f32) / SQUARE_PX as f32).round() as i32;
+        assert_eq!(corner.grid, Coord::new(i, j));
+    }
+}
+
+#[test]
+fn corner_scores_follow_decoded_marker_support() {
+    let spec = CharucoBoardSpec::new(ROWS, COLS, 1.0, MARKER_SIZE_REL, builtins::DICT_4X4_50);
+    let placement = EvenRowsOnly::new(ROWS, COLS);
+    let board = CharucoBoard::with_placement(spec, &placement).expect("valid placement");
+
+    let (width, height, data) = render(&board);
+    let image = GrayImageView {
+        width,
+        height,
+        data: &data,
+    };
+    let corners = inner_corners(ROWS, COLS);
+
+    let mut params = CharucoParams::for_board(&spec);
+    params.px_per_square = SQUARE_PX as f32;
+    let chess = calib_targets_chessboard::Detector::new(params.chessboard.clone())
+        .expect("chessboard params")
+        .detect(&corners)
+        .expect("chessboard");
+    let detector = CharucoDetector::with_board(params, board);
+    let result = detector.detect(&image, &corners).expect("detection");
+
+    // Clean decodes sit at the top of the `(0, 1]` marker score scale.
+    assert!(result
+        .markers
+        .iter()
+        .all(|m| m.score > 0.99 && m.score <= 1.0));
+
+    // Corners bordering a decoded marker keep their chessboard score; the
+    // rest are halved.
+    let supported = |g: Coord| {
+        result.markers.iter().any(|m| {
+            let cell = detector.board().marker_position(m.id).expect("placed");
+            (0..=1).contains(&(g.u - cell.u)) && (0..=1).contains(&(g.v - cell.v))
+        })
+    };
+    for corner in &result.corners {
+        let chess_score = chess
+            .corners
+            .iter()
+            .find(|c| (c.position - corner.position).norm() < 1e-3)
+            .expect("corner came from the chessboard stage")
+            .score;
+        let expected = if supported(corner.grid) {
+            chess_score
+        } else {
+            0.5 * chess_score
+        };
+        assert!(
+            (corner.score - expected).abs() < 1e-3 * chess_score,
+            "corner {} at {:?}: {} vs {expected}",
+            corner.id,
+            corner.grid,
+            corner.score
+        );
+    }
+}
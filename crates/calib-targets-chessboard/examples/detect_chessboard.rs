@@ -91,7 +91,7 @@ fn main() {
     println!("\nfirst labelled corners ((u, v) -> pixel  [input_index]):");
     for lc in labelled.iter().take(8) {
         println!(
-            "  (u={:>2}, v={:>2})  ->  ({:7.2}, {:7.2})  [{}]",
+            "  (u={:>2}, v={:>2})  ->  ({:7.2}, {:7.2})  [{:?}]",
             lc.grid.u, lc.grid.v, lc.position.x, lc.position.y, lc.input_index
         );
     }
@@ -1,6 +1,6 @@
 //! Per-corner augmented state carried through the pipeline.
 
-use calib_targets_core::AxisEstimate;
+use calib_targets_core::{AxisEstimate, PointIndex};
 use nalgebra::Point2;
 use serde::{Deserialize, Serialize};
 
@@ -44,6 +44,69 @@ impl ChessCorner {
             ..Self::default()
         }
     }
+
+    /// Construct a [`ChessCorner`] from a single orientation angle in
+    /// **radians**, for upstream detectors that report one angle per corner
+    /// rather than two axes.
+    ///
+    /// The orientation is wrapped into `[0, π)` and becomes `axes[0]`;
+    /// `axes[1]` is the orthogonal direction `axes[0] + π/2`. Both axes carry
+    /// `sigma = 0` (see [`AxisEstimate::from_angle`]). `contrast` and
+    /// `fit_rms` are left at zero, which disables the fit-quality gate for
+    /// this corner.
+    pub fn from_radians(position: Point2<f32>, orientation_rad: f32, strength: f32) -> Self {
+        let theta = orientation_rad.rem_euclid(std::f32::consts::PI);
+        Self {
+            position,
+            axes: [
+                AxisEstimate::from_angle(theta),
+                AxisEstimate::from_angle(theta + std::f32::consts::FRAC_PI_2),
+            ],
+            strength,
+            ..Self::default()
+        }
+    }
+
+    /// [`Self::from_radians`] for detectors that report the orientation in
+    /// **degrees**. Keeping the unit in the constructor name makes a
+    /// degrees/radians mix-up visible at the integration boundary.
+    pub fn from_degrees(position: Point2<f32>, orientation_deg: f32, strength: f32) -> Self {
+        Self::from_radians(position, orientation_deg.to_radians(), strength)
+    }
+}
+
+/// Drop near-duplicate corners before detection.
+///
+/// Visits corners strongest first (ties by input order) and removes every
+/// corner lying strictly closer than `min_dist` pixels to one already kept.
+/// Survivors keep their relative input order. Over-eager upstream detectors
+/// can report one physical corner twice, which inflates neighbour searches
+/// and can seed phantom grid lines. A non-positive `min_dist` is a no-op.
+pub fn dedup_corners(corners: &mut Vec<ChessCorner>, min_dist: f32) {
+    if min_dist.is_nan() || min_dist <= 0.0 || corners.len() < 2 {
+        return;
+    }
+    let positions: Vec<Point2<f32>> = corners.iter().map(|c| c.position).collect();
+    let index = PointIndex::new(&positions);
+    let mut order: Vec<usize> = (0..corners.len()).collect();
+    order.sort_by(|&a, &b| corners[b].strength.total_cmp(&corners[a].strength));
+
+    let mut removed = vec![false; corners.len()];
+    for i in order {
+        if removed[i] {
+            continue;
+        }
+        for hit in index.within_radius(positions[i], min_dist) {
+            if hit.index != i && hit.distance < min_dist {
+                removed[hit.index] = true;
+            }
+        }
+    }
+    let mut k = 0;
+    corners.retain(|_| {
+        k += 1;
+        !removed[k - 1]
+    });
 }
 
 /// Binary axis-slot label derived from the matched cluster centers.
@@ -176,4 +239,37 @@ mod tests {
         assert_eq!(ClusterLabel::Canonical.as_u8(), 0);
         assert_eq!(ClusterLabel::Swapped.as_u8(), 1);
     }
+
+    #[test]
+    fn dedup_corners_keeps_the_stronger_of_a_close_pair() {
+        let mut corners = vec![
+            ChessCorner::from_radians(Point2::new(10.0, 10.0), 0.0, 40.0),
+            ChessCorner::from_radians(Point2::new(11.0, 10.0), 0.0, 90.0),
+            ChessCorner::from_radians(Point2::new(30.0, 10.0), 0.0, 20.0),
+        ];
+        dedup_corners(&mut corners, 2.0);
+        let kept: Vec<f32> = corners.iter().map(|c| c.strength).collect();
+        assert_eq!(kept, vec![90.0, 20.0]);
+
+        dedup_corners(&mut corners, 0.0);
+        assert_eq!(corners.len(), 2);
+    }
+
+    #[test]
+    fn from_degrees_wraps_into_radians() {
+        use std::f32::consts::{FRAC_PI_2, PI};
+
+        let p = Point2::new(3.0, 4.0);
+        let c = ChessCorner::from_degrees(p, 90.0, 50.0);
+        assert!((c.axes[0].angle - FRAC_PI_2).abs() < 1e-6);
+        assert!((c.axes[1].angle - PI).abs() < 1e-6);
+        assert_eq!(c.position, p);
+        assert_eq!(c.strength, 50.0);
+
+        // Orientation is mod π: 270° is the same axis as 90°.
+        let wrapped = ChessCorner::from_degrees(p, 270.0, 50.0);
+        assert!((wrapped.axes[0].angle - FRAC_PI_2).abs() < 1e-5);
+        let negative = ChessCorner::from_degrees(p, -90.0, 50.0);
+        assert!((negative.axes[0].angle - FRAC_PI_2).abs() < 1e-5);
+    }
 }
@@ -17,7 +17,7 @@ use crate::pipeline::detect_all_topological;
 use crate::corner::ChessCorner;
 
 // Re-export the stable result types used in method signatures.
-pub use pipeline::{ChessboardCorner, ChessboardDetection};
+pub use pipeline::{ChessboardCorner, ChessboardDetection, SpacingStats};
 
 /// Top-level detector.
 pub struct Detector {
@@ -83,56 +83,27 @@ impl Detector {
 mod tests {
     use super::*;
     use crate::corner::ChessCorner;
-    use calib_targets_core::AxisEstimate;
-    use nalgebra::Point2;
-
-    fn make_corner(idx: usize, x: f32, y: f32, swapped: bool) -> ChessCorner {
-        let (a0, a1) = if swapped {
-            (std::f32::consts::FRAC_PI_2, 0.0)
-        } else {
-            (0.0, std::f32::consts::FRAC_PI_2)
-        };
-        let _ = idx;
-        ChessCorner {
-            position: Point2::new(x, y),
-            axes: [
-                AxisEstimate {
-                    angle: a0,
-                    sigma: 0.01,
-                },
-                AxisEstimate {
-                    angle: a1,
-                    sigma: 0.01,
-                },
-            ],
-            contrast: 10.0,
-            fit_rms: 1.0,
-            // A sharp synthetic corner: well above the default
-            // `min_corner_strength` floor (33.0) so these grid-building
-            // tests exercise the real default path rather than the
-            // strength pre-filter.
-            strength: 100.0,
-        }
-    }
+    use crate::test_util::{grid_corners, warp_corners};
+    use calib_targets_core::{Coord, Homography};
+    use nalgebra::{Point2, Vector2};
 
-    fn clean_grid(rows: i32, cols: i32, s: f32) -> Vec<ChessCorner> {
-        let mut corners = Vec::new();
-        let mut k = 0;
-        for j in 0..rows {
-            for i in 0..cols {
-                let x = i as f32 * s + 50.0;
-                let y = j as f32 * s + 50.0;
-                let swapped = (i + j).rem_euclid(2) == 1;
-                corners.push(make_corner(k, x, y, swapped));
-                k += 1;
-            }
-        }
+    /// Strength of the synthetic corners: well above the default
+    /// `min_corner_strength` floor (33.0) so these grid-building tests
+    /// exercise the real default path rather than the strength pre-filter.
+    const STRENGTH: f32 = 100.0;
+
+    /// An 8 × 6 grid at 20 px pitch under mild perspective, so fits through
+    /// it are true homographies rather than affinities.
+    fn perspective_grid() -> Vec<ChessCorner> {
+        let mut corners = grid_corners(8, 6, 20.0, 0.0, STRENGTH);
+        let h = Homography::from_array([[1.0, 0.0, 50.0], [0.0, 1.0, 50.0], [0.0008, 0.0, 1.04]]);
+        warp_corners(&mut corners, &h);
         corners
     }
 
     #[test]
     fn end_to_end_clean_grid() {
-        let corners = clean_grid(7, 7, 20.0);
+        let corners = grid_corners(7, 7, 20.0, 0.0, STRENGTH);
         let det = Detector::new(DetectorParams::default()).expect("default params valid");
         let d = det.detect(&corners).expect("detection");
         assert_eq!(d.corners.len(), 49);
@@ -144,7 +115,7 @@ mod tests {
     #[test]
     fn detect_populates_cell_size() {
         let s = 20.0_f32;
-        let corners = clean_grid(7, 7, s);
+        let corners = grid_corners(7, 7, s, 0.0, STRENGTH);
         let det = Detector::new(DetectorParams::default()).expect("default params valid");
         let d = det.detect(&corners).expect("detection");
         let cell = d.cell_size.expect("cell_size populated on detect() path");
@@ -154,19 +125,453 @@ mod tests {
         );
     }
 
+    /// With `normalize_strength`, one `min_corner_strength` floor detects the
+    /// same board whether the upstream detector reports strengths on a ChESS
+    /// scale or a scale 10⁴× smaller; the raw floor only accepts the former.
+    #[test]
+    fn normalized_strength_floor_is_scale_invariant() {
+        let tuning = crate::AdvancedTuning {
+            normalize_strength: true,
+            ..crate::AdvancedTuning::default()
+        };
+        let normalized = DetectorParams {
+            min_corner_strength: 0.3,
+            ..DetectorParams::default()
+        }
+        .with_advanced(tuning);
+        let raw = DetectorParams {
+            min_corner_strength: 0.3,
+            ..DetectorParams::default()
+        };
+
+        let chess_scale = grid_corners(7, 7, 20.0, 0.0, STRENGTH);
+        let mut tiny_scale = chess_scale.clone();
+        for c in &mut tiny_scale {
+            c.strength *= 1e-4;
+        }
+
+        let det = Detector::new(normalized).expect("params valid");
+        for corners in [&chess_scale, &tiny_scale] {
+            let d = det.detect(corners).expect("detection at either scale");
+            assert_eq!(d.corners.len(), 49);
+        }
+        let det = Detector::new(raw).expect("params valid");
+        assert!(det.detect(&chess_scale).is_some());
+        assert!(det.detect(&tiny_scale).is_none());
+    }
+
+    /// One `strength_percentile` keeps the board and drops a weak cluster
+    /// whether strengths come on a ChESS scale or a scale 10⁴× smaller, where
+    /// the default absolute floor rejects everything.
+    #[test]
+    fn strength_percentile_floor_is_scale_invariant() {
+        let tuning = crate::AdvancedTuning {
+            strength_percentile: Some(0.3),
+            ..crate::AdvancedTuning::default()
+        };
+        let params = DetectorParams::default().with_advanced(tuning);
+
+        // 49 board corners plus 16 weak responses well away from the board.
+        let mut chess_scale = grid_corners(7, 7, 20.0, 0.0, STRENGTH);
+        chess_scale.extend(
+            grid_corners(4, 4, 20.0, 0.0, STRENGTH)
+                .into_iter()
+                .map(|mut c| {
+                    c.position.x += 400.0;
+                    c.strength = 10.0;
+                    c
+                }),
+        );
+        let mut tiny_scale = chess_scale.clone();
+        for c in &mut tiny_scale {
+            c.strength *= 1e-4;
+        }
+
+        let det = Detector::new(params).expect("params valid");
+        for corners in [&chess_scale, &tiny_scale] {
+            let all = det.detect_all(corners);
+            assert_eq!(all.len(), 1, "the weak cluster must be filtered out");
+            assert_eq!(all[0].corners.len(), 49);
+        }
+        let det = Detector::new(DetectorParams::default()).expect("params valid");
+        assert!(det.detect(&tiny_scale).is_none());
+    }
+
+    #[test]
+    fn largest_extent_selection_prefers_sparse_large_board() {
+        // Dense 6×6 distractor at 12 px pitch (36 corners) next to a sparse
+        // 4×5 board at 60 px pitch (20 corners) spanning a far larger area.
+        let mut corners = grid_corners(6, 6, 12.0, 0.0, STRENGTH);
+        corners.extend(
+            grid_corners(5, 4, 60.0, 0.0, STRENGTH)
+                .into_iter()
+                .map(|mut c| {
+                    c.position.x += 200.0;
+                    c
+                }),
+        );
+
+        let det = Detector::new(DetectorParams::default()).expect("default params valid");
+        assert_eq!(det.detect(&corners).expect("detection").corners.len(), 36);
+
+        let tuning = crate::AdvancedTuning {
+            selection: crate::SelectionMode::LargestExtent,
+            ..crate::AdvancedTuning::default()
+        };
+        let params = DetectorParams::default().with_advanced(tuning);
+        let det = Detector::new(params).expect("params valid");
+        assert_eq!(det.detect(&corners).expect("detection").corners.len(), 20);
+    }
+
+    /// A thin strip of corners is a valid grid by default but is refused once
+    /// `min_grid_lines` exceeds its row count; `max_grid_lines` bounds the
+    /// other end.
+    #[test]
+    fn grid_line_bounds_gate_detections() {
+        let strip = grid_corners(8, 2, 20.0, 0.0, STRENGTH);
+        let det = Detector::new(DetectorParams::default()).expect("default params valid");
+        assert_eq!(
+            det.detect(&strip).expect("strip detected").corners.len(),
+            16
+        );
+
+        let tuning = crate::AdvancedTuning {
+            min_grid_lines: 4,
+            ..crate::AdvancedTuning::default()
+        };
+        let det =
+            Detector::new(DetectorParams::default().with_advanced(tuning)).expect("params valid");
+        assert!(det.detect(&strip).is_none());
+        assert!(det
+            .detect(&grid_corners(7, 7, 20.0, 0.0, STRENGTH))
+            .is_some());
+
+        let tuning = crate::AdvancedTuning {
+            max_grid_lines: 6,
+            ..crate::AdvancedTuning::default()
+        };
+        let det =
+            Detector::new(DetectorParams::default().with_advanced(tuning)).expect("params valid");
+        assert!(det
+            .detect(&grid_corners(7, 7, 20.0, 0.0, STRENGTH))
+            .is_none());
+        assert!(det
+            .detect(&grid_corners(5, 5, 20.0, 0.0, STRENGTH))
+            .is_some());
+    }
+
+    #[test]
+    fn trace_line_centers_match_grid_dimensions() {
+        use projective_grid::topological::trace::{
+            TopologicalComponentTrace, TopologicalLabelTrace,
+        };
+
+        let s = 20.0_f32;
+        let corners = grid_corners(8, 5, s, 0.0, STRENGTH);
+        let trace = crate::trace_topological(&corners, &DetectorParams::default())
+            .expect("clean grid traces");
+        let component = &trace.components[0];
+
+        let distinct = |key: fn(&TopologicalLabelTrace) -> i32| {
+            let mut values: Vec<i32> = component.labels.iter().map(key).collect();
+            values.sort_unstable();
+            values.dedup();
+            values.len()
+        };
+        assert_eq!(component.u_line_centers.len(), distinct(|l| l.u));
+        assert_eq!(component.v_line_centers.len(), distinct(|l| l.v));
+        let mut dims = [
+            component.u_line_centers.len(),
+            component.v_line_centers.len(),
+        ];
+        dims.sort_unstable();
+        assert_eq!(dims, [5, 8]);
+
+        // Axis-aligned grid: adjacent lines are one square apart.
+        for centers in [&component.u_line_centers, &component.v_line_centers] {
+            for pair in centers.windows(2) {
+                assert!((pair[1] - pair[0] - s).abs() < 1e-3, "{centers:?}");
+            }
+        }
+
+        // Traces saved before the line centres existed still load.
+        let mut json = serde_json::to_value(component).expect("serialize");
+        let fields = json.as_object_mut().expect("object");
+        for key in ["u_line_centers", "v_line_centers", "line_axes_rad"] {
+            fields.remove(key);
+        }
+        let old: TopologicalComponentTrace = serde_json::from_value(json).expect("deserialize");
+        assert!(old.u_line_centers.is_empty() && old.v_line_centers.is_empty());
+        assert_eq!(old.line_axes_rad, [0.0, 0.0]);
+    }
+
+    #[test]
+    fn image_to_board_homography_maps_corners_to_their_labels() {
+        let corners = perspective_grid();
+        let det = Detector::new(DetectorParams::default()).expect("default params valid");
+        let d = det.detect(&corners).expect("detection");
+        let h = d.image_to_board_homography().expect("homography");
+
+        for c in &d.corners {
+            let g = h.apply(c.position);
+            assert!(
+                (g.x - c.grid.u as f32).abs() < 1e-2 && (g.y - c.grid.v as f32).abs() < 1e-2,
+                "{:?} -> {g:?}, expected {:?}",
+                c.position,
+                c.grid
+            );
+        }
+        assert!(ChessboardDetection::new(Vec::new())
+            .image_to_board_homography()
+            .is_none());
+    }
+
+    #[test]
+    fn cell_centers_match_their_corner_quads() {
+        let corners = perspective_grid();
+        let det = Detector::new(DetectorParams::default()).expect("default params valid");
+        let d = det.detect(&corners).expect("detection");
+        let at: std::collections::HashMap<(i32, i32), Point2<f32>> = d
+            .corners
+            .iter()
+            .map(|c| ((c.grid.u, c.grid.v), c.position))
+            .collect();
+
+        let mut checked = 0;
+        for (&(i, j), &p00) in &at {
+            let (Some(p11), Some(p10), Some(p01)) = (
+                at.get(&(i + 1, j + 1)),
+                at.get(&(i + 1, j)),
+                at.get(&(i, j + 1)),
+            ) else {
+                continue;
+            };
+            // Under perspective the cell centre is the diagonals' crossing.
+            let (d1, d2) = (p11 - p00, p01 - p10);
+            let t = ((p10 - p00).x * d2.y - (p10 - p00).y * d2.x) / (d1.x * d2.y - d1.y * d2.x);
+            let expected = p00 + d1 * t;
+            let got = d.cell_center_image(i, j).expect("cell centre");
+            assert!(
+                (got - expected).norm() < 1e-2,
+                "({i}, {j}): {got:?} vs {expected:?}"
+            );
+            checked += 1;
+        }
+        assert_eq!(checked, 5 * 7);
+
+        // Cells outside the detected grid are still predicted.
+        assert!(d.cell_center_image(-1, -1).is_some());
+        assert!(ChessboardDetection::new(Vec::new())
+            .cell_center_image(0, 0)
+            .is_none());
+    }
+
+    #[test]
+    fn image_axes_step_from_origin_to_neighbours() {
+        let corners = perspective_grid();
+        let det = Detector::new(DetectorParams::default()).expect("default params valid");
+        let d = det.detect(&corners).expect("detection");
+        let at = |u: i32, v: i32| {
+            d.corners
+                .iter()
+                .find(|c| (c.grid.u, c.grid.v) == (u, v))
+                .map(|c| c.position)
+                .expect("corner labelled")
+        };
+
+        let (origin, step_u, step_v) = d.image_axes().expect("axes");
+        assert!((origin - at(0, 0)).norm() < 1e-2);
+        assert!((origin + step_u - at(1, 0)).norm() < 1e-2);
+        assert!((origin + step_v - at(0, 1)).norm() < 1e-2);
+        assert!(ChessboardDetection::new(Vec::new()).image_axes().is_none());
+    }
+
+    #[test]
+    fn image_rotation_matches_the_grid_angle() {
+        let det = Detector::new(DetectorParams::default()).expect("default params valid");
+        for deg in [-30.0f32, 0.0, 23.0] {
+            let corners = grid_corners(8, 6, 25.0, deg.to_radians(), 100.0);
+            let d = det.detect(&corners).expect("detection");
+            let got = d.image_rotation_deg().expect("rotation");
+            assert!((got - deg).abs() < 0.1, "{deg}: {got}");
+        }
+    }
+
+    #[test]
+    fn row_col_completeness_zeroes_a_missing_column() {
+        // 6 × 4 labels with the interior column u = 2 occluded.
+        let corners = (0..4)
+            .flat_map(|v| (0..6).map(move |u| Coord::new(u, v)))
+            .filter(|g| g.u != 2)
+            .map(|g| {
+                let p = Point2::new(g.u as f32 * 20.0, g.v as f32 * 20.0);
+                ChessboardCorner::new(p, g, 0, 1.0)
+            })
+            .collect();
+        let (rows, cols) = ChessboardDetection::new(corners).row_col_completeness();
+
+        assert_eq!(rows, vec![5.0 / 6.0; 4]);
+        assert_eq!(cols, vec![1.0, 1.0, 0.0, 1.0, 1.0, 1.0]);
+        let empty = ChessboardDetection::new(Vec::new()).row_col_completeness();
+        assert_eq!(empty, (Vec::new(), Vec::new()));
+    }
+
+    #[test]
+    fn spacing_stats_spread_grows_under_foreshortening() {
+        let grid = |warp: f32| {
+            let corners = (0..5)
+                .flat_map(|v| (0..7).map(move |u| Coord::new(u, v)))
+                .map(|g| {
+                    let (x, y) = (g.u as f32 * 20.0, g.v as f32 * 20.0);
+                    let w = 1.0 + warp * x;
+                    ChessboardCorner::new(Point2::new(x / w, y / w), g, 0, 1.0)
+                })
+                .collect();
+            ChessboardDetection::new(corners).spacing_stats()
+        };
+
+        let flat = grid(0.0);
+        assert_eq!(flat.count, 6 * 5 + 7 * 4);
+        assert!((flat.mean - 20.0).abs() < 1e-4, "{flat:?}");
+        assert!(flat.std < 1e-4 && flat.max - flat.min < 1e-4, "{flat:?}");
+
+        // The far side of the board shrinks to about half size.
+        let tilted = grid(0.008);
+        assert!(tilted.std > 2.0, "{tilted:?}");
+        assert!(tilted.min < 0.6 * tilted.max, "{tilted:?}");
+
+        let empty = ChessboardDetection::new(Vec::new()).spacing_stats();
+        assert_eq!(empty, SpacingStats::default());
+    }
+
+    #[test]
+    fn assign_corner_snaps_a_click_to_the_missing_slot() {
+        let corners = perspective_grid();
+        let det = Detector::new(DetectorParams::default()).expect("default params valid");
+        let mut d = det.detect(&corners).expect("detection");
+        let slot = Coord::new(3, 2);
+        let k = d
+            .corners
+            .iter()
+            .position(|c| c.grid == slot)
+            .expect("corner labelled");
+        let truth = d.corners.remove(k).position;
+        let n = d.corners.len();
+
+        // A click a few pixels off the true corner lands in its slot.
+        let click = truth + Vector2::new(4.0, -3.0);
+        assert_eq!(d.assign_corner(click), Some(slot));
+        assert_eq!(d.corners.len(), n + 1);
+        let added = d.corners.last().expect("appended");
+        assert_eq!((added.grid, added.position), (slot, click));
+        assert_eq!(added.input_index, None);
+
+        // The slot is now taken; a second click is refused.
+        assert_eq!(d.assign_corner(truth), None);
+        assert_eq!(d.corners.len(), n + 1);
+        assert_eq!(
+            ChessboardDetection::new(Vec::new()).assign_corner(truth),
+            None
+        );
+    }
+
+    #[test]
+    fn axis_residuals_stay_small_and_flag_a_swapped_label() {
+        let det = Detector::new(DetectorParams::default()).expect("default params valid");
+        let mut d = det
+            .detect(&grid_corners(8, 6, 20.0, 0.0, STRENGTH))
+            .expect("detection");
+        let (u_res, v_res) = d.axis_residuals().expect("residuals");
+        assert_eq!(
+            (u_res.len(), v_res.len()),
+            (d.corners.len(), d.corners.len())
+        );
+        for r in u_res.iter().chain(&v_res) {
+            assert!(r.abs() < 0.05, "{r}");
+        }
+
+        let a = d.corners.iter().position(|c| c.grid == Coord::new(2, 2));
+        let b = d.corners.iter().position(|c| c.grid == Coord::new(3, 2));
+        let (a, b) = (a.unwrap(), b.unwrap());
+        (d.corners[a].grid, d.corners[b].grid) = (Coord::new(3, 2), Coord::new(2, 2));
+        let (u_res, v_res) = d.axis_residuals().expect("residuals");
+        assert!(
+            u_res[a] < -0.5 && u_res[b] > 0.5,
+            "{} {}",
+            u_res[a],
+            u_res[b]
+        );
+        assert!(v_res[a].abs() < 0.1 && v_res[b].abs() < 0.1);
+    }
+
+    /// Labels come out of a `(i, j) → corner` map and a bijective
+    /// rebase/D4 canonicalisation, so a detection never labels two corners
+    /// with one `(i, j)` — even when the input reports a corner twice.
+    #[test]
+    fn each_grid_label_is_claimed_by_one_corner() {
+        let mut corners = grid_corners(7, 7, 20.0, 0.0, STRENGTH);
+        let mut twin = corners[24];
+        twin.position += Vector2::new(0.8, -0.6);
+        twin.strength *= 0.9;
+        corners.push(twin);
+
+        let det = Detector::new(DetectorParams::default()).expect("default params valid");
+        let all = det.detect_all(&corners);
+        assert!(!all.is_empty());
+        for d in &all {
+            let mut labels: Vec<Coord> = d.corners.iter().map(|c| c.grid).collect();
+            labels.sort_unstable_by_key(|g| (g.v, g.u));
+            let n = labels.len();
+            labels.dedup();
+            assert_eq!(labels.len(), n);
+        }
+    }
+
     #[test]
     fn rejects_when_too_few_corners() {
         let det = Detector::new(DetectorParams::default()).expect("default params valid");
         assert!(det.detect(&[]).is_none());
     }
 
+    #[test]
+    fn rotated_board_gets_the_same_canonical_labels() {
+        // A plain chessboard is D4-ambiguous; labels are canonicalized
+        // against the image axes, so the same 7×7 board turned by 90°
+        // (which swaps its corner polarity) labels every pixel identically.
+        let corners = grid_corners(7, 7, 20.0, 0.0, STRENGTH);
+        // Quarter turn about the board centre (60, 60).
+        let mut rotated = corners.clone();
+        let quarter_turn =
+            Homography::from_array([[0.0, -1.0, 120.0], [1.0, 0.0, 0.0], [0.0, 0.0, 1.0]]);
+        warp_corners(&mut rotated, &quarter_turn);
+        let det = Detector::new(DetectorParams::default()).expect("default params valid");
+        let labels = |input: &[ChessCorner]| {
+            let mut out: Vec<((i32, i32), (i32, i32))> = det
+                .detect(input)
+                .expect("detection")
+                .corners
+                .iter()
+                .map(|c| {
+                    let px = (c.position.x.round() as i32, c.position.y.round() as i32);
+                    (px, (c.grid.u, c.grid.v))
+                })
+                .collect();
+            out.sort_unstable();
+            out
+        };
+
+        let upright = labels(&corners);
+        assert_eq!(upright.len(), 49);
+        assert_eq!(upright, labels(&rotated));
+    }
+
     #[test]
     fn grid_origin_at_visual_top_left() {
         // Synthesize a 7×7 grid where the +x image axis corresponds to
         // (1, 0) and +y to (0, 1). Regardless of which axis-slot the
         // builder picks, `build_detection` must canonicalize so
         // (0, 0) lands at the smallest (x, y) corner.
-        let corners = clean_grid(7, 7, 20.0);
+        let corners = grid_corners(7, 7, 20.0, 0.0, STRENGTH);
         let det = Detector::new(DetectorParams::default()).expect("default params valid");
         let d = det.detect(&corners).expect("detection");
         // Locate (0, 0) and the two neighbors.
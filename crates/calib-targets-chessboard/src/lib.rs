@@ -83,11 +83,13 @@ mod mesh_warp;
 mod params;
 mod pipeline;
 mod rectified_view;
+#[cfg(any(test, feature = "test-util"))]
+pub mod test_util;
 
 // --- Public contract ---------------------------------------------------
-pub use corner::ChessCorner;
-pub use detector::{ChessboardCorner, ChessboardDetection, Detector};
+pub use corner::{dedup_corners, ChessCorner};
+pub use detector::{ChessboardCorner, ChessboardDetection, Detector, SpacingStats};
 pub use mesh_warp::{rectify_mesh_from_grid, MeshWarpError, RectifiedMeshView};
-pub use params::{AdvancedTuning, ChessboardParamsError, DetectorParams};
+pub use params::{AdvancedTuning, ChessboardParamsError, DetectorParams, SelectionMode};
 pub use pipeline::{detect_all_topological, trace_topological};
 pub use rectified_view::{rectify_from_chessboard_result, RectifiedBoardView, RectifyError};
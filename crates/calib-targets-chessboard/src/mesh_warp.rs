@@ -49,6 +49,11 @@ pub struct RectifiedMeshView {
     /// Number of cells that had all 4 corners and got a valid homography.
     pub valid_cells: usize,
 
+    /// Whether [`Self::rect_to_img`] (and so [`Self::rect`]) blends the
+    /// homographies of adjacent cells instead of using the containing
+    /// cell's alone. See [`rectify_mesh_from_grid`].
+    pub blend_cells: bool,
+
     // Per-cell homographies (cell-local rect -> image), in row-major (cj * cells_x + ci).
     // This is intentionally kept private; use `cell_rect_to_img`/`rect_to_img` accessors.
     cells: Vec<Cell>,
@@ -63,7 +68,8 @@ struct Cell {
 
 impl RectifiedMeshView {
     /// Map a point in **global rectified pixel coordinates** into the original image,
-    /// using the homography of the cell that contains it.
+    /// using the homography of the cell that contains it — or, with
+    /// [`Self::blend_cells`], a bilinear blend of the surrounding cells'.
     ///
     /// Returns `None` if the point lies outside the rectified image or the cell is invalid.
     pub fn rect_to_img(&self, p_rect: Point2<f32>) -> Option<Point2<f32>> {
@@ -78,11 +84,51 @@ impl RectifiedMeshView {
             return None;
         }
 
+        if self.blend_cells {
+            // Containing cell must be valid, as in the unblended mapping.
+            self.cell_rect_to_img(ci as usize, cj as usize, Point2::origin())?;
+            return self.blended_rect_to_img(p_rect);
+        }
+
         let x_local = p_rect.x - (ci as f32) * s;
         let y_local = p_rect.y - (cj as f32) * s;
         self.cell_rect_to_img(ci as usize, cj as usize, Point2::new(x_local, y_local))
     }
 
+    /// Blend the homographies of the four cells whose centres surround
+    /// `p_rect`, each extrapolated to `p_rect`, with bilinear weights on the
+    /// cell-centre lattice. Adjacent cells agree exactly at shared corners,
+    /// so the blend still passes through every detected corner while the
+    /// seams along cell edges disappear. Invalid neighbours are dropped and
+    /// the remaining weights renormalised.
+    fn blended_rect_to_img(&self, p_rect: Point2<f32>) -> Option<Point2<f32>> {
+        let s = self.px_per_square;
+        let gx = p_rect.x / s - 0.5;
+        let gy = p_rect.y / s - 0.5;
+        let (c0x, c0y) = (gx.floor(), gy.floor());
+        let (tx, ty) = (gx - c0x, gy - c0y);
+
+        let (mut acc_x, mut acc_y, mut weight_sum) = (0.0f32, 0.0f32, 0.0f32);
+        for (dx, wx) in [(0, 1.0 - tx), (1, tx)] {
+            for (dy, wy) in [(0, 1.0 - ty), (1, ty)] {
+                let w = wx * wy;
+                if w <= 0.0 {
+                    continue;
+                }
+                let ci = (c0x as i32 + dx).clamp(0, self.cells_x as i32 - 1);
+                let cj = (c0y as i32 + dy).clamp(0, self.cells_y as i32 - 1);
+                let p_cell = Point2::new(p_rect.x - ci as f32 * s, p_rect.y - cj as f32 * s);
+                let Some(p) = self.cell_rect_to_img(ci as usize, cj as usize, p_cell) else {
+                    continue;
+                };
+                acc_x += w * p.x;
+                acc_y += w * p.y;
+                weight_sum += w;
+            }
+        }
+        (weight_sum > 0.0).then(|| Point2::new(acc_x / weight_sum, acc_y / weight_sum))
+    }
+
     /// Map a point in **cell-local rectified pixel coordinates** into the original image.
     ///
     /// - `ci`, `cj`: cell indices in `0..cells_x × 0..cells_y`
@@ -140,11 +186,17 @@ fn build_corners_to_pix_map(
 /// - `corners`: your detection.corners
 /// - `inliers`: indices into `corners` that you trust
 /// - `px_per_square`: rectified pixels per chess square (recommend 60..120, preferably an integer)
+/// - `blend_cells`: blend adjacent cell homographies bilinearly instead of
+///   switching hard at cell edges. Per-cell homographies agree only at the
+///   shared corners, so the hard warp leaves seams along cell edges where
+///   lens distortion makes neighbouring warps differ; blending makes the
+///   rectified image seamless, for display or whole-image decoding.
 pub fn rectify_mesh_from_grid(
     src: &GrayImageView<'_>,
     corners: &[LabeledCorner],
     inliers: &[usize],
     px_per_square: f32,
+    blend_cells: bool,
 ) -> Result<RectifiedMeshView, MeshWarpError> {
     // 1) Build map: (u,v) -> image point
     let map = build_corners_to_pix_map(corners, inliers);
@@ -237,41 +289,11 @@ pub fn rectify_mesh_from_grid(
         return Err(MeshWarpError::NoValidCells);
     }
 
-    // 4) Warp: each output pixel chooses its cell and uses that cell homography
-    let mut out = vec![0u8; out_w * out_h];
-
-    for y in 0..out_h {
-        let yf = y as f32 + 0.5;
-        let cj = (yf / s).floor() as i32;
-        if cj < 0 || cj >= cells_y as i32 {
-            continue;
-        }
-        let cj_u = cj as usize;
-        let y_local = yf - (cj as f32) * s;
-
-        for x in 0..out_w {
-            let xf = x as f32 + 0.5;
-            let ci = (xf / s).floor() as i32;
-            if ci < 0 || ci >= cells_x as i32 {
-                continue;
-            }
-            let ci_u = ci as usize;
-            let x_local = xf - (ci as f32) * s;
-
-            let cell = cells[cj_u * cells_x + ci_u];
-            if cell.valid {
-                let p_cell = Point2::new(x_local, y_local);
-                let p_img = cell.h_img_from_cellrect.apply(p_cell);
-                out[y * out_w + x] = sample_bilinear_u8(src, p_img.x, p_img.y);
-            }
-        }
-    }
-
-    Ok(RectifiedMeshView {
+    let mut view = RectifiedMeshView {
         rect: GrayImage {
             width: out_w,
             height: out_h,
-            data: out,
+            data: Vec::new(),
         },
         min_i,
         min_j,
@@ -279,6 +301,69 @@ pub fn rectify_mesh_from_grid(
         cells_y,
         px_per_square,
         valid_cells,
+        blend_cells,
         cells,
-    })
+    };
+
+    // 4) Warp: each output pixel maps through its cell (or the blend of its
+    //    neighbourhood) into the source image
+    let mut out = vec![0u8; out_w * out_h];
+    for y in 0..out_h {
+        for x in 0..out_w {
+            let p_rect = Point2::new(x as f32 + 0.5, y as f32 + 0.5);
+            if let Some(p_img) = view.rect_to_img(p_rect) {
+                out[y * out_w + x] = sample_bilinear_u8(src, p_img.x, p_img.y);
+            }
+        }
+    }
+    view.rect.data = out;
+
+    Ok(view)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Rectify a 2 × 2-cell grid whose centre corner is pushed off the
+    /// regular lattice, over a vertical intensity ramp, and return the
+    /// summed intensity jump across the vertical cell boundary.
+    fn seam_jump(blend_cells: bool) -> u32 {
+        let (w, h) = (120usize, 120usize);
+        let data: Vec<u8> = (0..h)
+            .flat_map(|y| (0..w).map(move |_| (3 * y).min(255) as u8))
+            .collect();
+        let src = GrayImageView {
+            width: w,
+            height: h,
+            data: &data,
+        };
+        let corners: Vec<LabeledCorner> = (0..3)
+            .flat_map(|v| (0..3).map(move |u| (u, v)))
+            .map(|(u, v)| {
+                let mut p = Point2::new(20.0 + 35.0 * u as f32, 10.0 + 25.0 * v as f32);
+                if (u, v) == (1, 1) {
+                    p += nalgebra::Vector2::new(6.0, 12.0);
+                }
+                LabeledCorner::new(p, 1.0).with_grid(Coord::new(u, v))
+            })
+            .collect();
+        let inliers: Vec<usize> = (0..corners.len()).collect();
+        let view =
+            rectify_mesh_from_grid(&src, &corners, &inliers, 40.0, blend_cells).expect("rectify");
+
+        let centre = view.rect_to_img(Point2::new(40.0, 40.0)).expect("centre");
+        assert!((centre - corners[4].position).norm() < 1e-3, "{centre:?}");
+
+        let row = |y: usize| &view.rect.data[y * view.rect.width..(y + 1) * view.rect.width];
+        (0..view.rect.height)
+            .map(|y| u32::from(row(y)[40].abs_diff(row(y)[39])))
+            .sum()
+    }
+
+    #[test]
+    fn blending_removes_the_seam_between_cells() {
+        let (hard, blended) = (seam_jump(false), seam_jump(true));
+        assert!(10 * blended < hard, "{blended} vs {hard}");
+    }
 }
@@ -64,6 +64,24 @@ pub struct AdvancedTuning {
     /// Corners are dropped when `c.fit_rms > max_fit_rms_ratio * c.contrast`
     /// (and `c.contrast > 0`). `f32::INFINITY` disables the filter.
     pub max_fit_rms_ratio: f32,
+    /// When `true`, corner strengths are normalized by the 95th-percentile
+    /// strength of the input cloud before the
+    /// [`min_corner_strength`](super::DetectorParams::min_corner_strength)
+    /// gate, so the floor is read on a `[0, 1]`-ish scale and stays portable
+    /// across corner detectors whose responses differ by a global factor
+    /// (ChESS vs Harris). A floor of e.g. `0.3` then keeps corners at least
+    /// 30% as strong as the 95th-percentile corner.
+    ///
+    /// Default `false`: the floor is applied to raw strengths.
+    pub normalize_strength: bool,
+    /// When set, the strength floor is this quantile (in `[0, 1]`) of the
+    /// input cloud's strengths, replacing
+    /// [`min_corner_strength`](super::DetectorParams::min_corner_strength)
+    /// and [`Self::normalize_strength`]. `0.4` keeps roughly the strongest
+    /// 60% of corners whatever the detector's response scale.
+    ///
+    /// Default `None`: the absolute floor applies.
+    pub strength_percentile: Option<f32>,
 
     // --- `cluster_axes` stage -----------------------------------------------
     /// Number of histogram bins on `[0, π)` for axis-direction clustering.
@@ -91,6 +109,12 @@ pub struct AdvancedTuning {
     /// Minimal fraction of total axis-vote weight required for a peak to be
     /// considered.
     pub min_peak_weight_fraction: f32,
+    /// Width (σ, in histogram bins) of the circular Gaussian that smooths the
+    /// axis-direction histogram before peak picking. Larger values merge
+    /// nearby peaks on boards with spread orientations; smaller values keep
+    /// tight peaks sharp. `0.0` disables smoothing. Default `1.0` matches the
+    /// former fixed `[1, 4, 6, 4, 1] / 16` kernel.
+    pub smooth_sigma_bins: f32,
 
     // --- recall boosters (interior fill + line extrapolation) ----------------
     /// Candidate-search radius (fraction of `s`) around a predicted `(i, j)`
@@ -135,6 +159,15 @@ pub struct AdvancedTuning {
     /// Minimum members required to fit a line / column for the geometry
     /// check's collinearity test.
     pub line_min_members: usize,
+    /// Minimum number of distinct grid lines (labelled rows **and** labelled
+    /// columns) a detection must span. Default `2`, the smallest grid that
+    /// has cells. Raise it to the smallest realistic board extent to reject
+    /// tiny spurious grids.
+    pub min_grid_lines: usize,
+    /// Maximum number of distinct grid lines (rows **or** columns) a
+    /// detection may span. Rejects implausibly large grids when the board
+    /// size is known. Default `0`: unbounded.
+    pub max_grid_lines: usize,
     /// When `true`, the geometry check's tolerances are multiplied by a
     /// per-corner local step (computed from labelled grid neighbours) instead
     /// of the global cell size. Anisotropic thresholds catch outliers in
@@ -157,6 +190,27 @@ pub struct AdvancedTuning {
     /// target-specific detectors with their own geometry/ID alignment gates
     /// (e.g. ChArUco) may disable it to preserve recall.
     pub enable_final_edge_shape_check: bool,
+
+    // --- output ---------------------------------------------------------------
+    /// How surviving components are ranked before
+    /// [`max_components`](super::DetectorParams::max_components) truncation;
+    /// the first one is what [`Detector::detect`](crate::Detector::detect)
+    /// returns. Default [`SelectionMode::MostCorners`].
+    pub selection: SelectionMode,
+}
+
+/// Ranking of the detected grid components, best first.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SelectionMode {
+    /// Most labelled corners first.
+    #[default]
+    MostCorners,
+    /// Largest image-space bounding-box area of the labelled corners first.
+    /// Prefers a physically large but sparsely detected board over a dense
+    /// background cluster with more corners.
+    LargestExtent,
 }
 
 impl Default for AdvancedTuning {
@@ -168,6 +222,8 @@ impl Default for AdvancedTuning {
             component_merge: LocalMergeParams::default(),
 
             max_fit_rms_ratio: 0.5,
+            normalize_strength: false,
+            strength_percentile: None,
 
             num_bins: 90,
             max_iters_2means: 10,
@@ -183,6 +239,7 @@ impl Default for AdvancedTuning {
             // concentrates tightly; 0.02 is still comfortably above
             // pure-noise bins.
             min_peak_weight_fraction: 0.02,
+            smooth_sigma_bins: 1.0,
 
             attach_search_rel: 0.35,
             attach_axis_tol_deg: 15.0,
@@ -196,8 +253,12 @@ impl Default for AdvancedTuning {
             geometry_check_line_tol_rel: 0.45,
             geometry_check_local_h_tol_rel: 0.6,
             line_min_members: 3,
+            min_grid_lines: 2,
+            max_grid_lines: 0,
             validate_step_aware: false,
             enable_final_edge_shape_check: true,
+
+            selection: SelectionMode::MostCorners,
         }
     }
 }
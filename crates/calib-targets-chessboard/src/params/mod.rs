@@ -24,7 +24,7 @@
 
 mod advanced;
 
-pub use advanced::AdvancedTuning;
+pub use advanced::{AdvancedTuning, SelectionMode};
 
 use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
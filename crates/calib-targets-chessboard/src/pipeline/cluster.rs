@@ -84,6 +84,7 @@ fn cluster_params(params: &DetectorParams) -> ClusterParams {
         tuning.cluster_tol_deg.to_radians(),
         tuning.cluster_sigma_k,
     )
+    .with_smooth_sigma_bins(tuning.smooth_sigma_bins)
 }
 
 /// Collect the `Strong`-stage corners as generic axis features, in input
@@ -27,8 +27,53 @@ fn axes_from(c: &ChessCorner) -> [AxisEstimate; 2] {
     ]
 }
 
-fn prefilter(corners: &[ChessCorner], params: &DetectorParams) -> Vec<bool> {
+/// Percentile of the input strength distribution used as the unit scale when
+/// [`AdvancedTuning::normalize_strength`](crate::AdvancedTuning::normalize_strength)
+/// is set.
+const STRENGTH_NORM_PERCENTILE: f32 = 0.95;
+
+/// Strength at quantile `q` (in `[0, 1]`) of the finite strengths in
+/// `corners`; `None` when there are none.
+fn strength_quantile(corners: &[ChessCorner], q: f32) -> Option<f32> {
+    let mut strengths: Vec<f32> = corners
+        .iter()
+        .map(|c| c.strength)
+        .filter(|s| s.is_finite())
+        .collect();
+    if strengths.is_empty() {
+        return None;
+    }
+    let rank = ((strengths.len() - 1) as f32 * q).round() as usize;
+    let (_, value, _) = strengths.select_nth_unstable_by(rank, f32::total_cmp);
+    Some(*value)
+}
+
+/// The raw-strength floor the pre-filter applies to `corners`.
+///
+/// With [`AdvancedTuning::strength_percentile`](crate::AdvancedTuning::strength_percentile)
+/// set, the floor is that quantile of the strengths. Otherwise, without
+/// normalization this is simply `min_corner_strength`. With normalization,
+/// `strength / p95 >= min_corner_strength` is evaluated as
+/// `strength >= min_corner_strength * p95`, so the comparison stays on the
+/// raw values and no per-corner division is needed. A cloud with no positive
+/// strength falls back to the unnormalized floor.
+pub(super) fn strength_floor(corners: &[ChessCorner], params: &DetectorParams) -> f32 {
     let min_corner_strength = params.min_corner_strength;
+    let tuning = params.effective_tuning();
+    if let Some(q) = tuning.strength_percentile.filter(|q| q.is_finite()) {
+        return strength_quantile(corners, q.clamp(0.0, 1.0)).unwrap_or(min_corner_strength);
+    }
+    if !tuning.normalize_strength {
+        return min_corner_strength;
+    }
+    match strength_quantile(corners, STRENGTH_NORM_PERCENTILE) {
+        Some(scale) if scale > 0.0 => min_corner_strength * scale,
+        _ => min_corner_strength,
+    }
+}
+
+fn prefilter(corners: &[ChessCorner], params: &DetectorParams) -> Vec<bool> {
+    let min_corner_strength = strength_floor(corners, params);
     let max_fit_rms_ratio = params.effective_tuning().max_fit_rms_ratio;
     corners
         .iter()
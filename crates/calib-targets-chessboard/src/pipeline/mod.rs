@@ -78,7 +78,7 @@ use self::cluster::ClusterCenters;
 use self::inputs::topological_inputs;
 use self::recover::{build_topological_detections, clustered_augs, recover_topological_components};
 
-pub use types::{ChessboardCorner, ChessboardDetection};
+pub use types::{ChessboardCorner, ChessboardDetection, SpacingStats};
 
 /// Build a `projective-grid` [`NextDetectionParams`] for the
 /// chessboard adapter's topological grid finder.
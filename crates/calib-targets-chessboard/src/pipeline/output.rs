@@ -49,7 +49,7 @@ pub(crate) fn build_detection(
         chessboard_corners.push(ChessboardCorner {
             position: e.image_position,
             grid: e.coord,
-            input_index: c.input_index,
+            input_index: Some(c.input_index),
             score: c.strength,
         });
     }
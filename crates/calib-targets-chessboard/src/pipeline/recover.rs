@@ -14,10 +14,11 @@ use projective_grid::shared::merge::{merge_components_local, ComponentInput};
 use super::boosters::apply_boosters_with_directional_edge_scale;
 use super::cluster::{cluster_axes, ClusterCenters};
 use super::geometry_check::run_geometry_check;
+use super::inputs::strength_floor;
 use super::output::build_detection;
 use super::types::ChessboardDetection;
 use crate::corner::{CornerAug, CornerStage};
-use crate::params::DetectorParams;
+use crate::params::{DetectorParams, SelectionMode};
 use projective_grid::shared::grow::GrowResult;
 
 pub(super) type LabelledComponent = HashMap<(i32, i32), usize>;
@@ -146,7 +147,7 @@ pub(super) fn clustered_augs(
     corners: &[ChessCorner],
     params: &DetectorParams,
 ) -> (Vec<CornerAug>, Option<ClusterCenters>) {
-    let min_corner_strength = params.min_corner_strength;
+    let min_corner_strength = strength_floor(corners, params);
     let max_fit_rms_ratio = params.effective_tuning().max_fit_rms_ratio;
     let mut augs: Vec<CornerAug> = corners
         .iter()
@@ -383,6 +384,32 @@ pub(super) fn recover_topological_components(
     merge_components_local(&boosted_views, &tuning.component_merge).components
 }
 
+/// `true` when the labelled grid spans between `min_grid_lines` and
+/// `max_grid_lines` (`0` = unbounded) distinct rows and columns.
+fn grid_lines_in_range(labelled: &LabelledComponent, params: &DetectorParams) -> bool {
+    let tuning = params.effective_tuning();
+    let cols: HashSet<i32> = labelled.keys().map(|&(i, _)| i).collect();
+    let rows: HashSet<i32> = labelled.keys().map(|&(_, j)| j).collect();
+    let (lo, hi) = (cols.len().min(rows.len()), cols.len().max(rows.len()));
+    lo >= tuning.min_grid_lines && (tuning.max_grid_lines == 0 || hi <= tuning.max_grid_lines)
+}
+
+/// Image-space bounding-box area of a detection's corners, in pixels².
+fn bbox_area(detection: &ChessboardDetection) -> f32 {
+    let (mut lo, mut hi) = (
+        Point2::new(f32::INFINITY, f32::INFINITY),
+        Point2::new(f32::NEG_INFINITY, f32::NEG_INFINITY),
+    );
+    for c in &detection.corners {
+        lo = lo.inf(&c.position);
+        hi = hi.sup(&c.position);
+    }
+    if detection.corners.is_empty() {
+        return 0.0;
+    }
+    (hi.x - lo.x) * (hi.y - lo.y)
+}
+
 #[cfg_attr(
     feature = "tracing",
     tracing::instrument(
@@ -448,11 +475,19 @@ pub(super) fn build_topological_detections(
         if grow.labelled.len() < params.min_labeled_corners {
             continue;
         }
+        if !grid_lines_in_range(&grow.labelled, params) {
+            continue;
+        }
 
         out.push(build_detection(&augs, &grow, cell_size));
     }
 
-    out.sort_by_key(|d| std::cmp::Reverse(d.corners.len()));
+    match params.effective_tuning().selection {
+        SelectionMode::MostCorners => out.sort_by_key(|d| std::cmp::Reverse(d.corners.len())),
+        SelectionMode::LargestExtent => {
+            out.sort_by(|a, b| bbox_area(b).total_cmp(&bbox_area(a)));
+        }
+    }
     out.truncate(params.max_components.max(1) as usize);
     out
 }
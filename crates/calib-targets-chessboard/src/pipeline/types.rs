@@ -1,14 +1,19 @@
 //! Output and geometry-check types for the detector pipeline.
 //!
 //! These are pure data carriers: the [`ChessboardDetection`] result and
-//! its [`ChessboardCorner`] entries, plus the [`GeometryCheckTrace`]
+//! its [`ChessboardCorner`] entries, the [`SpacingStats`] QC summary, plus
+//! the [`GeometryCheckTrace`]
 //! returned by the mandatory final geometry check. No pipeline logic lives
 //! here — see the sibling stage modules for the stage bodies.
 
-use calib_targets_core::Coord;
+use calib_targets_core::{
+    estimate_homography_rect_to_img, Coord, DetectionQuality, DetectionSummary, Homography,
+    LabeledCorner, Summarize, TargetDetection, TargetKind,
+};
 
-use nalgebra::Point2;
+use nalgebra::{Point2, Vector2};
 use serde::Serialize;
+use std::collections::HashMap;
 
 /// A single labelled chessboard corner.
 ///
@@ -20,8 +25,10 @@ pub struct ChessboardCorner {
     pub position: Point2<f32>,
     /// Grid label `(u, v)`. A chessboard corner is always labelled — non-optional.
     pub grid: Coord,
-    /// Index into the detector's input `&[ChessCorner]` slice that produced this corner.
-    pub input_index: usize,
+    /// Index into the detector's input `&[ChessCorner]` slice that produced
+    /// this corner; `None` for a corner added with
+    /// [`ChessboardDetection::assign_corner`], which came from no input.
+    pub input_index: Option<usize>,
     /// Corner score.
     pub score: f32,
 }
@@ -32,7 +39,7 @@ impl ChessboardCorner {
         Self {
             position,
             grid,
-            input_index,
+            input_index: Some(input_index),
             score,
         }
     }
@@ -72,6 +79,250 @@ impl ChessboardDetection {
         self.cell_size = Some(cell_size);
         self
     }
+
+    /// Least-squares homography mapping image pixels to fractional board
+    /// grid coordinates `(u, v)`, fitted over every labelled corner.
+    ///
+    /// Unlike rectification, which resamples whole cells, this maps any
+    /// pixel to a continuous grid position: a point inside cell `(u, v)`
+    /// lands in `[u, u + 1) × [v, v + 1)`. A single homography assumes an
+    /// undistorted view; under strong lens distortion the mapping drifts
+    /// towards the board edges.
+    ///
+    /// Returns `None` with fewer than four corners or a degenerate fit.
+    pub fn image_to_board_homography(&self) -> Option<Homography> {
+        let (board, image): (Vec<Point2<f32>>, Vec<Point2<f32>>) = self
+            .corners
+            .iter()
+            .map(|c| (Point2::new(c.grid.u as f32, c.grid.v as f32), c.position))
+            .unzip();
+        estimate_homography_rect_to_img(&board, &image)?.inverse()
+    }
+
+    /// Per-corner label residuals along each grid axis, as `(u, v)` vectors
+    /// indexed like [`corners`](Self::corners).
+    ///
+    /// Each corner is mapped through
+    /// [`image_to_board_homography`](Self::image_to_board_homography) and its
+    /// integer label subtracted, giving the signed offset, in cells, of the
+    /// corner from its assigned grid line. A good grid keeps these near zero;
+    /// a mislabelled corner shows up as a lone outlier, and a trend across
+    /// the board points at lens distortion or a wrong spacing. Returns `None`
+    /// when the homography cannot be fitted.
+    pub fn axis_residuals(&self) -> Option<(Vec<f32>, Vec<f32>)> {
+        let h = self.image_to_board_homography()?;
+        Some(
+            self.corners
+                .iter()
+                .map(|c| {
+                    let board = h.apply(c.position);
+                    (board.x - c.grid.u as f32, board.y - c.grid.v as f32)
+                })
+                .unzip(),
+        )
+    }
+
+    /// Predicted image position of the centre of grid cell `(i, j)` — the
+    /// cell spanned by corners `(i, j)` and `(i + 1, j + 1)` — projected
+    /// through the inverse of
+    /// [`image_to_board_homography`](Self::image_to_board_homography).
+    ///
+    /// Any cell can be queried, including ones whose corners were not
+    /// detected, which makes this suitable for per-cell sampling overlays.
+    /// Returns `None` when the homography cannot be fitted.
+    pub fn cell_center_image(&self, i: i32, j: i32) -> Option<Point2<f32>> {
+        let h = self.image_to_board_homography()?.inverse()?;
+        Some(h.apply(Point2::new(i as f32 + 0.5, j as f32 + 0.5)))
+    }
+
+    /// Add a corner the detector missed, e.g. one clicked in an annotation
+    /// tool, and return its grid label.
+    ///
+    /// `pos` is mapped through
+    /// [`image_to_board_homography`](Self::image_to_board_homography) and
+    /// snapped to the nearest integer grid coordinate; the corner is
+    /// appended there at `pos` with score `0.0`. It came from no input
+    /// corner, so its [`input_index`](ChessboardCorner::input_index) is
+    /// `None`. [`cell_size`](Self::cell_size) is left unchanged.
+    ///
+    /// Returns `None`, leaving the detection untouched, when the homography
+    /// cannot be fitted or the snapped coordinate is already labelled.
+    pub fn assign_corner(&mut self, pos: Point2<f32>) -> Option<Coord> {
+        let board = self.image_to_board_homography()?.apply(pos);
+        if !(board.x.is_finite() && board.y.is_finite()) {
+            return None;
+        }
+        let grid = Coord::new(board.x.round() as i32, board.y.round() as i32);
+        if self.corners.iter().any(|c| c.grid == grid) {
+            return None;
+        }
+        self.corners.push(ChessboardCorner {
+            position: pos,
+            grid,
+            input_index: None,
+            score: 0.0,
+        });
+        Some(grid)
+    }
+
+    /// Board axes in image space, for drawing: the image position of grid
+    /// corner `(0, 0)` plus the image steps from it to `(1, 0)` (along `u`)
+    /// and to `(0, 1)` (along `v`), all projected through the inverse of
+    /// [`image_to_board_homography`](Self::image_to_board_homography).
+    ///
+    /// The steps are exact at the origin only; under perspective they
+    /// shrink or grow across the board. Returns `None` when the homography
+    /// cannot be fitted.
+    pub fn image_axes(&self) -> Option<(Point2<f32>, Vector2<f32>, Vector2<f32>)> {
+        let h = self.image_to_board_homography()?.inverse()?;
+        let origin = h.apply(Point2::new(0.0, 0.0));
+        let step_u = h.apply(Point2::new(1.0, 0.0)) - origin;
+        let step_v = h.apply(Point2::new(0.0, 1.0)) - origin;
+        Some((origin, step_u, step_v))
+    }
+
+    /// In-image rotation of the board, in degrees: the angle of the `u` step
+    /// from [`image_axes`](Self::image_axes), measured from the image `+x`
+    /// axis. Image `y` points down, so positive angles turn clockwise on
+    /// screen. Canonical labelling keeps `+u` closest to `+x`, so the value
+    /// lies in about `(-45, 45]` for an upright view. Returns `None` when the
+    /// homography cannot be fitted.
+    pub fn image_rotation_deg(&self) -> Option<f32> {
+        let (_, step_u, _) = self.image_axes()?;
+        Some(step_u.y.atan2(step_u.x).to_degrees())
+    }
+
+    /// Fraction of the image spanned by the corners' bounding box: the larger
+    /// of its width over `image_width` and its height over `image_height`.
+    ///
+    /// A board that is known to be small in frame can use this to reject
+    /// implausibly large grids grown from background texture. Returns `None`
+    /// for an empty detection or a zero-sized image.
+    pub fn image_extent_frac(&self, image_width: usize, image_height: usize) -> Option<f32> {
+        if self.corners.is_empty() || image_width == 0 || image_height == 0 {
+            return None;
+        }
+        let (mut lo, mut hi) = (self.corners[0].position, self.corners[0].position);
+        for c in &self.corners[1..] {
+            lo = lo.inf(&c.position);
+            hi = hi.sup(&c.position);
+        }
+        let span = hi - lo;
+        Some((span.x / image_width as f32).max(span.y / image_height as f32))
+    }
+
+    /// Fill fraction of each grid row and column, as `(rows, cols)`.
+    ///
+    /// The grid extent is the bounding box of the labels: `rows[k]` is the
+    /// share of that box's columns labelled in row `v = v_min + k`, and
+    /// `cols[k]` the share of its rows labelled in column `u = u_min + k`.
+    /// A low run of entries shows the direction of a partial occlusion. Both
+    /// vectors are empty for an empty detection.
+    pub fn row_col_completeness(&self) -> (Vec<f32>, Vec<f32>) {
+        let Some(first) = self.corners.first() else {
+            return (Vec::new(), Vec::new());
+        };
+        let (mut lo, mut hi) = (first.grid, first.grid);
+        for c in &self.corners[1..] {
+            lo = Coord::new(lo.u.min(c.grid.u), lo.v.min(c.grid.v));
+            hi = Coord::new(hi.u.max(c.grid.u), hi.v.max(c.grid.v));
+        }
+        let n_cols = (hi.u - lo.u + 1) as usize;
+        let n_rows = (hi.v - lo.v + 1) as usize;
+        let mut rows = vec![0.0f32; n_rows];
+        let mut cols = vec![0.0f32; n_cols];
+        for c in &self.corners {
+            rows[(c.grid.v - lo.v) as usize] += 1.0;
+            cols[(c.grid.u - lo.u) as usize] += 1.0;
+        }
+        rows.iter_mut().for_each(|r| *r /= n_cols as f32);
+        cols.iter_mut().for_each(|c| *c /= n_rows as f32);
+        (rows, cols)
+    }
+
+    /// Image-space distances between grid-adjacent corners — every labelled
+    /// pair one step apart along `u` or `v` — summarized as [`SpacingStats`].
+    ///
+    /// A flat, fronto-parallel board gives a near-zero spread; perspective,
+    /// a bent board or a mis-scaled print widen it. Duplicate labels after
+    /// the first are ignored.
+    pub fn spacing_stats(&self) -> SpacingStats {
+        let mut by_grid: HashMap<Coord, Point2<f32>> = HashMap::new();
+        for c in &self.corners {
+            by_grid.entry(c.grid).or_insert(c.position);
+        }
+        let by_grid = &by_grid;
+        let spacings: Vec<f32> = by_grid
+            .iter()
+            .flat_map(|(g, p)| {
+                [Coord::new(g.u + 1, g.v), Coord::new(g.u, g.v + 1)]
+                    .into_iter()
+                    .filter_map(move |n| by_grid.get(&n).map(|q| (q - p).norm()))
+            })
+            .collect();
+        if spacings.is_empty() {
+            return SpacingStats::default();
+        }
+
+        let count = spacings.len();
+        let mean = spacings.iter().sum::<f32>() / count as f32;
+        let var = spacings.iter().map(|s| (s - mean).powi(2)).sum::<f32>() / count as f32;
+        SpacingStats {
+            count,
+            mean,
+            std: var.sqrt(),
+            min: spacings.iter().copied().fold(f32::INFINITY, f32::min),
+            max: spacings.iter().copied().fold(f32::NEG_INFINITY, f32::max),
+        }
+    }
+
+    /// Ground-truth-free quality terms of the labelled corners; a plain
+    /// chessboard has no marker term. See [`DetectionQuality`].
+    pub fn quality(&self) -> DetectionQuality {
+        self.target_detection().quality()
+    }
+
+    /// Composite quality in `[0, 1]` of [`Self::quality`], for picking the
+    /// best of many frames; see [`DetectionQuality::score`] for the weighting.
+    pub fn quality_score(&self) -> f32 {
+        self.quality().score()
+    }
+
+    fn target_detection(&self) -> TargetDetection {
+        let corners = self
+            .corners
+            .iter()
+            .map(|c| LabeledCorner::new(c.position, c.score).with_grid(c.grid))
+            .collect();
+        TargetDetection::new(TargetKind::Chessboard, corners)
+    }
+}
+
+/// Spread of the image-space distances between grid-adjacent corners.
+///
+/// Produced by [`ChessboardDetection::spacing_stats`]. Distances are in
+/// pixels; every field is `0` when no adjacent pair is labelled.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize)]
+pub struct SpacingStats {
+    /// Number of adjacent corner pairs measured.
+    pub count: usize,
+    /// Mean spacing.
+    pub mean: f32,
+    /// Population standard deviation of the spacing.
+    pub std: f32,
+    /// Shortest spacing.
+    pub min: f32,
+    /// Longest spacing.
+    pub max: f32,
+}
+
+impl Summarize for ChessboardDetection {
+    /// Corner summary only: a plain chessboard has no markers and no board
+    /// alignment.
+    fn summary(&self) -> DetectionSummary {
+        DetectionSummary::from_detection(&self.target_detection())
+    }
 }
 
 /// Outcome of the mandatory final geometry check.
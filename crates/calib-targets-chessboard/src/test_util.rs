@@ -0,0 +1,87 @@
+//! Synthetic corner clouds for tests (enabled by the `test-util` feature).
+//!
+//! Downstream crates exercising the detector on hand-made input share these
+//! generators instead of each rebuilding a regular grid of [`ChessCorner`]s.
+
+use calib_targets_core::Homography;
+use nalgebra::{Point2, Vector2};
+
+use crate::corner::ChessCorner;
+
+/// A regular `cols × rows` grid of inner corners, `spacing` pixels apart,
+/// rotated by `orientation` radians about the origin.
+///
+/// Corner `(i, j)` sits at `R(orientation) · (i, j) · spacing`, emitted
+/// row-major. Axis slots alternate between neighbours as on a real
+/// chessboard, every corner carries `strength`, and the fit-quality fields
+/// are left at zero (see [`ChessCorner::from_radians`]). Use
+/// [`warp_corners`] to add perspective.
+pub fn grid_corners(
+    cols: usize,
+    rows: usize,
+    spacing: f32,
+    orientation: f32,
+    strength: f32,
+) -> Vec<ChessCorner> {
+    let (sin, cos) = orientation.sin_cos();
+    let mut out = Vec::with_capacity(cols * rows);
+    for j in 0..rows {
+        for i in 0..cols {
+            let (x, y) = (i as f32 * spacing, j as f32 * spacing);
+            let p = Point2::new(cos * x - sin * y, sin * x + cos * y);
+            let swapped = (i + j) % 2 == 1;
+            let theta = orientation
+                + if swapped {
+                    std::f32::consts::FRAC_PI_2
+                } else {
+                    0.0
+                };
+            out.push(ChessCorner::from_radians(p, theta, strength));
+        }
+    }
+    out
+}
+
+/// Map corner positions through `h`, turning each axis with the local
+/// direction of the warp so the axes stay aligned with the warped grid.
+pub fn warp_corners(corners: &mut [ChessCorner], h: &Homography) {
+    for c in corners.iter_mut() {
+        let p = c.position;
+        let q = h.apply(p);
+        for axis in &mut c.axes {
+            let dir = Vector2::new(axis.angle.cos(), axis.angle.sin());
+            let d = h.apply(p + dir) - q;
+            axis.angle = d.y.atan2(d.x).rem_euclid(std::f32::consts::PI);
+        }
+        c.position = q;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Detector, DetectorParams};
+
+    fn extent(corners: &[crate::ChessboardCorner]) -> (i32, i32) {
+        let cols = corners.iter().map(|c| c.grid.u).max().unwrap() + 1;
+        let rows = corners.iter().map(|c| c.grid.v).max().unwrap() + 1;
+        (cols, rows)
+    }
+
+    #[test]
+    fn generated_grid_detects_as_the_requested_board() {
+        let det = Detector::new(DetectorParams::default()).expect("default params valid");
+
+        let flat = grid_corners(9, 6, 30.0, 0.2, 100.0);
+        let d = det.detect(&flat).expect("detection");
+        assert_eq!(d.corners.len(), 54);
+        assert_eq!(extent(&d.corners), (9, 6));
+
+        let mut tilted = grid_corners(9, 6, 30.0, 0.0, 100.0);
+        let h = Homography::from_array([[1.0, 0.1, 80.0], [0.05, 0.9, 60.0], [4e-4, 2e-4, 1.0]]);
+        warp_corners(&mut tilted, &h);
+        let d = det.detect(&tilted).expect("detection");
+        assert_eq!(d.corners.len(), 54);
+        assert_eq!(extent(&d.corners), (9, 6));
+    }
+}
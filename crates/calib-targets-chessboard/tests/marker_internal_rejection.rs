@@ -142,8 +142,8 @@ fn marker_internal_corners_never_labelled() {
     let labelled_markers: Vec<(usize, (i32, i32))> = components
         .iter()
         .flat_map(|d| d.corners.iter())
-        .filter(|c| marker_set.contains(&c.input_index))
-        .map(|c| (c.input_index, (c.grid.u, c.grid.v)))
+        .filter_map(|c| c.input_index.map(|idx| (idx, (c.grid.u, c.grid.v))))
+        .filter(|(idx, _)| marker_set.contains(idx))
         .collect();
     assert!(
         labelled_markers.is_empty(),
@@ -85,10 +85,12 @@ fn detects_two_components_of_the_same_board() {
         );
         // Each component's corners must be disjoint from the other.
         for corner in &det.corners {
+            let input_index = corner
+                .input_index
+                .expect("detected corner has an input index");
             assert!(
-                seen.insert(corner.input_index),
-                "component {k}: input index {} appears in multiple components",
-                corner.input_index
+                seen.insert(input_index),
+                "component {k}: input index {input_index} appears in multiple components"
             );
         }
     }
@@ -97,7 +99,7 @@ fn detects_two_components_of_the_same_board() {
     // input indices < left_count and the other contains the rest.
     let sets: Vec<HashSet<usize>> = detections
         .iter()
-        .map(|d| d.corners.iter().map(|c| c.input_index).collect())
+        .map(|d| d.corners.iter().filter_map(|c| c.input_index).collect())
         .collect();
     let left_piece: HashSet<usize> = (0..left_count).collect();
     let right_piece: HashSet<usize> = (left_count..corners.len()).collect();
@@ -347,7 +347,7 @@ fn small3_topological_default_suppresses_weak_frontier() {
     // literal contract of the default flip, and the exact class the user
     // reported.
     for c in &detection.corners {
-        let strength = corners[c.input_index].strength;
+        let strength = corners[c.input_index.expect("detected corner has an input index")].strength;
         assert!(
             strength >= floor,
             "small3: emitted corner ({}, {}) has strength {strength:.1} below the floor {floor}",
@@ -0,0 +1,91 @@
+//! Criterion benches for [`PointIndex`] against the brute-force scans it
+//! replaces, on a 5000-point cloud (a dense ChArUco frame's corner count).
+//!
+//! Points are drawn from a seeded xorshift LCG so there is no `rand`
+//! dependency and run-to-run numbers are comparable.
+//!
+//! Run with:
+//! ```text
+//! cargo bench -p calib-targets-core --bench point_index
+//! ```
+
+use calib_targets_core::PointIndex;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use nalgebra::Point2;
+
+const N: usize = 5000;
+const K: usize = 8;
+const RADIUS: f32 = 20.0;
+
+/// Deterministic xorshift64* LCG — used only to place fixture points.
+struct Lcg(u64);
+
+impl Lcg {
+    fn new(seed: u64) -> Self {
+        Self(seed | 1)
+    }
+
+    /// Next `f32` in `[0.0, 1.0)`.
+    fn next_unit(&mut self) -> f32 {
+        let mut x = self.0;
+        x ^= x >> 12;
+        x ^= x << 25;
+        x ^= x >> 27;
+        self.0 = x;
+        let bits = x.wrapping_mul(0x2545_F491_4F6C_DD1D);
+        ((bits >> 40) as f32) / ((1u32 << 24) as f32)
+    }
+}
+
+fn fixture() -> Vec<Point2<f32>> {
+    let mut rng = Lcg::new(0x5eed);
+    (0..N)
+        .map(|_| Point2::new(rng.next_unit() * 1920.0, rng.next_unit() * 1080.0))
+        .collect()
+}
+
+fn brute_nearest(points: &[Point2<f32>], p: Point2<f32>, k: usize) -> Vec<usize> {
+    let mut order: Vec<(f32, usize)> = points
+        .iter()
+        .enumerate()
+        .map(|(i, q)| ((q - p).norm_squared(), i))
+        .collect();
+    order.select_nth_unstable_by(k, |a, b| a.0.total_cmp(&b.0));
+    order.truncate(k);
+    order.into_iter().map(|(_, i)| i).collect()
+}
+
+fn bench_point_index(c: &mut Criterion) {
+    let points = fixture();
+    let mut group = c.benchmark_group("point_index_5000");
+
+    group.bench_function("build", |b| b.iter(|| PointIndex::new(black_box(&points))));
+
+    let index = PointIndex::new(&points);
+    group.bench_function("knn_all_points", |b| {
+        b.iter(|| {
+            for &p in &points {
+                black_box(index.nearest(p, K));
+            }
+        })
+    });
+    group.bench_function("radius_all_points", |b| {
+        b.iter(|| {
+            for &p in &points {
+                black_box(index.within_radius(p, RADIUS));
+            }
+        })
+    });
+    group.bench_function("knn_all_points_brute_force", |b| {
+        b.iter(|| {
+            for &p in &points {
+                black_box(brute_nearest(&points, p, K));
+            }
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_point_index);
+criterion_main!(benches);
@@ -0,0 +1,117 @@
+//! Origin / axis convention for board grid labels.
+//!
+//! Board grid labels live on the board's square-corner lattice: a board of
+//! `cols × rows` squares has `(cols + 1) × (rows + 1)` lattice points, the
+//! outer corners included, so its first inner corner is `(1, 1)`. In the
+//! canonical frame — the one the ChArUco detector reports — `(0, 0)` is the
+//! top-left outer corner of the upright printed board, `u` right and `v`
+//! down. [`BoardConvention`] moves that origin to another board corner, so a
+//! board rendered and detected under the same convention carries the same
+//! labels.
+
+use crate::grid_alignment::{GridAlignment, GridTransform};
+use projective_grid::Coord;
+use serde::{Deserialize, Serialize};
+
+/// The board corner, as printed upright, that holds grid `(0, 0)`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BoardOrigin {
+    /// Top-left corner — the canonical frame.
+    #[default]
+    TopLeft,
+    /// Top-right corner; `u` runs right to left.
+    TopRight,
+    /// Bottom-right corner; `u` runs right to left, `v` bottom to top.
+    BottomRight,
+    /// Bottom-left corner; `v` runs bottom to top.
+    BottomLeft,
+}
+
+/// How board grid coordinates are labelled relative to the printed board.
+///
+/// `u` always runs along the board's printed rows and `v` along its
+/// columns, each counting away from [`Self::origin`]. The default is the
+/// canonical top-left frame. Only grid labels follow the convention: corner
+/// ids and millimetre `target_position`s keep the printed board's frame.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct BoardConvention {
+    /// Board corner holding grid `(0, 0)`.
+    #[serde(default)]
+    pub origin: BoardOrigin,
+}
+
+impl BoardConvention {
+    /// The canonical top-left-origin convention.
+    pub const CANONICAL: BoardConvention = BoardConvention {
+        origin: BoardOrigin::TopLeft,
+    };
+
+    /// Convention with grid `(0, 0)` at `origin`.
+    pub fn new(origin: BoardOrigin) -> Self {
+        Self { origin }
+    }
+
+    /// Alignment from canonical grid coordinates into this convention, for
+    /// a lattice spanning `0..cols` × `0..rows` — `cols` and `rows` count
+    /// lattice points, one more than the board's squares.
+    pub fn alignment(&self, cols: u32, rows: u32) -> GridAlignment {
+        let (flip_u, flip_v) = match self.origin {
+            BoardOrigin::TopLeft => (false, false),
+            BoardOrigin::TopRight => (true, false),
+            BoardOrigin::BottomRight => (true, true),
+            BoardOrigin::BottomLeft => (false, true),
+        };
+        let axis = |flip: bool, len: u32| {
+            if flip {
+                (-1, len as i32 - 1)
+            } else {
+                (1, 0)
+            }
+        };
+        let (a, tu) = axis(flip_u, cols);
+        let (d, tv) = axis(flip_v, rows);
+        GridAlignment {
+            transform: GridTransform { a, b: 0, c: 0, d },
+            translation: [tu, tv],
+        }
+    }
+
+    /// Relabel canonical `grid` into this convention on a `cols × rows`
+    /// point lattice.
+    pub fn map(&self, grid: Coord, cols: u32, rows: u32) -> Coord {
+        self.alignment(cols, rows).map(grid.u, grid.v)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn origins_relabel_the_lattice_corners() {
+        let (cols, rows) = (6, 4);
+        let far = Coord::new(5, 3);
+        let cases = [
+            (BoardOrigin::TopLeft, Coord::new(0, 0)),
+            (BoardOrigin::TopRight, Coord::new(5, 0)),
+            (BoardOrigin::BottomRight, Coord::new(5, 3)),
+            (BoardOrigin::BottomLeft, Coord::new(0, 3)),
+        ];
+        for (origin, canonical_origin) in cases {
+            let convention = BoardConvention::new(origin);
+            assert_eq!(
+                convention.map(canonical_origin, cols, rows),
+                Coord::new(0, 0)
+            );
+            let back = convention
+                .alignment(cols, rows)
+                .inverse()
+                .expect("unimodular");
+            assert_eq!(back.map(0, 0), canonical_origin, "{origin:?}");
+        }
+        assert_eq!(BoardConvention::CANONICAL.map(far, cols, rows), far);
+        assert_eq!(BoardConvention::default(), BoardConvention::CANONICAL);
+    }
+}
@@ -1,7 +1,9 @@
+use crate::PointIndex;
 use nalgebra::Point2;
 use projective_grid::Coord;
 use projective_grid::LocalAxis as NextLocalAxis;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 /// Local estimate of one undirected grid axis at a detected corner.
 ///
@@ -133,6 +135,12 @@ pub struct LabeledCorner {
     /// The meaning depends on the detector (it may be unnormalized).
     #[serde(alias = "confidence")]
     pub score: f32,
+
+    /// `true` when the position was predicted from neighbouring corners
+    /// rather than observed (see [`TargetDetection::interpolate_missing`]).
+    /// Omitted from serialized output when `false`.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub interpolated: bool,
 }
 
 impl LabeledCorner {
@@ -148,6 +156,7 @@ impl LabeledCorner {
             id: None,
             target_position: None,
             score,
+            interpolated: false,
         }
     }
 
@@ -171,6 +180,13 @@ impl LabeledCorner {
         self.target_position = Some(target_position);
         self
     }
+
+    /// Mark the corner as interpolated rather than observed.
+    #[must_use]
+    pub fn with_interpolated(mut self, interpolated: bool) -> Self {
+        self.interpolated = interpolated;
+        self
+    }
 }
 
 /// One detected target (board instance) in an image.
@@ -191,4 +207,589 @@ impl TargetDetection {
     pub fn new(kind: TargetKind, corners: Vec<LabeledCorner>) -> Self {
         Self { kind, corners }
     }
+
+    /// Sort corners row-major by grid coordinate, `(v, u)`, so the order does
+    /// not depend on the detector's internal iteration. Corners without a
+    /// grid coordinate go last, in their current order.
+    pub fn sort_by_grid(&mut self) {
+        self.corners
+            .sort_by_key(|c| c.grid.map_or((1, 0, 0), |g| (0, g.v, g.u)));
+    }
+
+    /// Inclusive grid extent `(min_u, min_v, max_u, max_v)` of the labelled
+    /// corners, for sizing per-slot buffers. Corners without a grid
+    /// coordinate are ignored; returns `None` when no corner has one.
+    pub fn grid_bounds(&self) -> Option<(i32, i32, i32, i32)> {
+        let mut grids = self.corners.iter().filter_map(|c| c.grid);
+        let first = grids.next()?;
+        Some(grids.fold(
+            (first.u, first.v, first.u, first.v),
+            |(u0, v0, u1, v1), g| (u0.min(g.u), v0.min(g.v), u1.max(g.u), v1.max(g.v)),
+        ))
+    }
+
+    /// Grid coordinates whose image positions break the monotonic ordering
+    /// of their rows or columns — the signature of swapped or inverted
+    /// labels, which corrupt homography fits.
+    ///
+    /// For each axis, the step between grid-adjacent corners (`(u, v)` to
+    /// `(u + 1, v)`, and `(u, v)` to `(u, v + 1)`) should point the same way
+    /// as the detection's mean step along that axis; perspective and lens
+    /// distortion bend the steps but never reverse them. Both ends of a
+    /// reversed step are reported, sorted row-major by `(v, u)`. The check is
+    /// independent of how the board is rotated in the image; an empty result
+    /// means no inversion was found. Corners without a grid coordinate are
+    /// ignored, and for a duplicated label only the first corner counts.
+    pub fn validate_monotonic(&self) -> Vec<Coord> {
+        let mut at: std::collections::HashMap<Coord, Point2<f32>> =
+            std::collections::HashMap::new();
+        for c in &self.corners {
+            if let Some(g) = c.grid {
+                at.entry(g).or_insert(c.position);
+            }
+        }
+
+        let mut flagged = std::collections::BTreeSet::new();
+        for (du, dv) in [(1, 0), (0, 1)] {
+            let steps: Vec<(Coord, Coord, nalgebra::Vector2<f32>)> = at
+                .iter()
+                .filter_map(|(&g, &p)| {
+                    let next = Coord::new(g.u + du, g.v + dv);
+                    at.get(&next).map(|&q| (g, next, q - p))
+                })
+                .collect();
+            let mean = steps
+                .iter()
+                .fold(nalgebra::Vector2::zeros(), |acc, (_, _, d)| acc + d);
+            for (g, next, d) in steps {
+                if d.dot(&mean) <= 0.0 {
+                    flagged.insert((g.v, g.u));
+                    flagged.insert((next.v, next.u));
+                }
+            }
+        }
+        flagged.into_iter().map(|(v, u)| Coord::new(u, v)).collect()
+    }
+
+    /// Centroid of the corner positions and a characteristic scale: the mean
+    /// corner-to-centroid distance, in pixels.
+    ///
+    /// Cheap enough for per-frame UI feedback (drawing a bounding indicator,
+    /// judging how large the target appears). Returns `None` when the
+    /// detection has no corners.
+    pub fn image_centroid_and_scale(&self) -> Option<(Point2<f32>, f32)> {
+        if self.corners.is_empty() {
+            return None;
+        }
+        let n = self.corners.len() as f32;
+        let sum = self
+            .corners
+            .iter()
+            .fold(nalgebra::Vector2::zeros(), |acc, c| acc + c.position.coords);
+        let centroid = Point2::from(sum / n);
+        let scale = self
+            .corners
+            .iter()
+            .map(|c| (c.position - centroid).norm())
+            .sum::<f32>()
+            / n;
+        Some((centroid, scale))
+    }
+
+    /// Convex hull of the corner positions, for seeding a region of interest
+    /// in the next frame.
+    ///
+    /// Vertices start at the leftmost (then topmost) corner and run
+    /// clockwise on screen (image `y` pointing down); points on a hull edge
+    /// are dropped. Fewer than three distinct positions are returned as-is
+    /// (deduplicated), so an empty detection yields an empty hull.
+    pub fn convex_hull(&self) -> Vec<Point2<f32>> {
+        let mut pts: Vec<Point2<f32>> = self.corners.iter().map(|c| c.position).collect();
+        pts.sort_by(|a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)));
+        pts.dedup();
+        if pts.len() < 3 {
+            return pts;
+        }
+
+        // Andrew's monotone chain: top chain left to right, then bottom
+        // chain back, each keeping only strict turns.
+        let cross = |o: Point2<f32>, a: Point2<f32>, b: Point2<f32>| {
+            (a.x - o.x) * (b.y - o.y) - (a.y - o.y) * (b.x - o.x)
+        };
+        let mut hull: Vec<Point2<f32>> = Vec::with_capacity(pts.len() + 1);
+        for &p in &pts {
+            while hull.len() >= 2 && cross(hull[hull.len() - 2], hull[hull.len() - 1], p) <= 0.0 {
+                hull.pop();
+            }
+            hull.push(p);
+        }
+        let floor = hull.len() + 1;
+        for &p in pts.iter().rev().skip(1) {
+            while hull.len() >= floor && cross(hull[hull.len() - 2], hull[hull.len() - 1], p) <= 0.0
+            {
+                hull.pop();
+            }
+            hull.push(p);
+        }
+        hull.pop();
+        hull
+    }
+
+    /// Fill holes in the detected grid with positions predicted from a
+    /// board → image homography.
+    ///
+    /// The homography is fitted to every gridded, observed corner; each grid
+    /// slot inside the detected `(u, v)` bounding box that has no corner gets
+    /// one at the projected position, with `score = 0.0` and
+    /// [`LabeledCorner::interpolated`] set. Existing corners are kept
+    /// unchanged and the result is sorted with [`Self::sort_by_grid`].
+    ///
+    /// The homography ignores lens distortion, so predictions are only as good
+    /// as the plane-to-image mapping is locally projective. Returns an
+    /// unchanged copy when fewer than four observed corners carry a grid
+    /// coordinate or the fit is degenerate.
+    pub fn interpolate_missing(&self) -> TargetDetection {
+        let mut out = self.clone();
+        let (board, image): (Vec<Point2<f32>>, Vec<Point2<f32>>) = self
+            .corners
+            .iter()
+            .filter(|c| !c.interpolated)
+            .filter_map(|c| {
+                c.grid
+                    .map(|g| (Point2::new(g.u as f32, g.v as f32), c.position))
+            })
+            .unzip();
+        let Some(h) = crate::estimate_homography_rect_to_img(&board, &image) else {
+            return out;
+        };
+
+        let Some((u0, v0, u1, v1)) = self.grid_bounds() else {
+            return out;
+        };
+        let present: std::collections::HashSet<Coord> =
+            self.corners.iter().filter_map(|c| c.grid).collect();
+        for v in v0..=v1 {
+            for u in u0..=u1 {
+                let g = Coord::new(u, v);
+                if present.contains(&g) {
+                    continue;
+                }
+                let p = h.apply(Point2::new(u as f32, v as f32));
+                if p.x.is_finite() && p.y.is_finite() {
+                    out.corners.push(
+                        LabeledCorner::new(p, 0.0)
+                            .with_grid(g)
+                            .with_interpolated(true),
+                    );
+                }
+            }
+        }
+        out.sort_by_grid();
+        out
+    }
+
+    /// Merge repeated detections of a static scene into one averaged
+    /// detection.
+    ///
+    /// Corners are matched by grid coordinate across `self` and `others`.
+    /// The first observation of each grid coordinate (`self`'s when present)
+    /// is the reference; observations within `pos_tol` pixels of it are
+    /// averaged, the rest are treated as outliers. The merged score is the
+    /// mean score of the agreeing observations scaled by the fraction of
+    /// detections that agree, so a corner seen in only one frame is kept with
+    /// a lower score.
+    ///
+    /// `id` and `target_position` come from the reference observation.
+    /// Corners without a grid coordinate are carried over from `self`
+    /// unchanged, and detections of a different [`TargetKind`] are ignored.
+    pub fn merge_averaged(&self, others: &[TargetDetection], pos_tol: f32) -> TargetDetection {
+        struct Acc {
+            reference: LabeledCorner,
+            sum: nalgebra::Vector2<f32>,
+            score_sum: f32,
+            count: usize,
+        }
+
+        let detections: Vec<&TargetDetection> = std::iter::once(self)
+            .chain(others.iter().filter(|d| d.kind == self.kind))
+            .collect();
+        let mut index: HashMap<Coord, usize> = HashMap::new();
+        let mut accs: Vec<Acc> = Vec::new();
+        let mut ungridded = Vec::new();
+
+        for (frame, det) in detections.iter().enumerate() {
+            for c in &det.corners {
+                let Some(g) = c.grid else {
+                    if frame == 0 {
+                        ungridded.push(c.clone());
+                    }
+                    continue;
+                };
+                match index.get(&g) {
+                    Some(&k) => {
+                        let acc = &mut accs[k];
+                        if (c.position - acc.reference.position).norm() <= pos_tol {
+                            acc.sum += c.position.coords;
+                            acc.score_sum += c.score;
+                            acc.count += 1;
+                        }
+                    }
+                    None => {
+                        index.insert(g, accs.len());
+                        accs.push(Acc {
+                            reference: c.clone(),
+                            sum: c.position.coords,
+                            score_sum: c.score,
+                            count: 1,
+                        });
+                    }
+                }
+            }
+        }
+
+        let frames = detections.len() as f32;
+        let mut corners: Vec<LabeledCorner> = accs
+            .into_iter()
+            .map(|acc| {
+                let n = acc.count as f32;
+                LabeledCorner {
+                    position: Point2::from(acc.sum / n),
+                    score: acc.score_sum / n * (n / frames),
+                    ..acc.reference
+                }
+            })
+            .collect();
+        corners.extend(ungridded);
+        TargetDetection::new(self.kind, corners)
+    }
+
+    /// Merge detections of one target from different image scales (e.g.
+    /// pyramid levels) into one, deduplicating corners by position.
+    ///
+    /// Corners of `self` and `others` are pooled and visited by descending
+    /// score; a corner is kept unless a kept corner lies within
+    /// `merge_radius_px`, so each physical corner survives once, as its most
+    /// confident observation. Ties keep the earlier detection's corner
+    /// (`self` first). Detections of a different [`TargetKind`] are ignored.
+    ///
+    /// Positions must already be in one image frame. Labels (`grid`, `id`,
+    /// `target_position`) always come from `self`, so detections need not
+    /// share a grid origin: a kept corner takes the labels of the strongest
+    /// `self` corner it absorbed, and one with no `self` corner within the
+    /// radius is left unlabelled.
+    pub fn merge_within_radius(
+        &self,
+        others: &[TargetDetection],
+        merge_radius_px: f32,
+    ) -> TargetDetection {
+        let n_self = self.corners.len();
+        let pooled: Vec<&LabeledCorner> = std::iter::once(self)
+            .chain(others.iter().filter(|d| d.kind == self.kind))
+            .flat_map(|d| &d.corners)
+            .collect();
+        let positions: Vec<Point2<f32>> = pooled.iter().map(|c| c.position).collect();
+        let index = PointIndex::new(&positions);
+
+        let mut order: Vec<usize> = (0..pooled.len()).collect();
+        order.sort_by(|&a, &b| pooled[b].score.total_cmp(&pooled[a].score));
+
+        let mut suppressed = vec![false; pooled.len()];
+        let mut corners = Vec::new();
+        for k in order {
+            if suppressed[k] {
+                continue;
+            }
+            let hits: Vec<usize> = index
+                .within_radius(positions[k], merge_radius_px)
+                .into_iter()
+                .map(|hit| hit.index)
+                .collect();
+            let labels_from = if k < n_self {
+                Some(k)
+            } else {
+                hits.iter()
+                    .copied()
+                    .filter(|&j| j < n_self && !suppressed[j])
+                    .max_by(|&a, &b| pooled[a].score.total_cmp(&pooled[b].score).then(b.cmp(&a)))
+            };
+            for &j in &hits {
+                suppressed[j] = true;
+            }
+
+            let mut corner = pooled[k].clone();
+            let labels = labels_from.map(|j| pooled[j]);
+            corner.grid = labels.and_then(|c| c.grid);
+            corner.id = labels.and_then(|c| c.id);
+            corner.target_position = labels.and_then(|c| c.target_position);
+            corners.push(corner);
+        }
+        TargetDetection::new(self.kind, corners)
+    }
+}
+
+#[cfg(test)]
+mod detection_tests {
+    use super::*;
+
+    fn noisy_grid(seed: u32, amplitude: f32) -> TargetDetection {
+        let corners = (0..25)
+            .map(|k| {
+                let (u, v) = (k % 5, k / 5);
+                // Cheap deterministic jitter in [-amplitude, amplitude].
+                let h = (k as u32 + 1)
+                    .wrapping_mul(2_654_435_761)
+                    .wrapping_add(seed.wrapping_mul(40_503));
+                let jx = ((h >> 8) % 1001) as f32 / 500.0 - 1.0;
+                let jy = ((h >> 18) % 1001) as f32 / 500.0 - 1.0;
+                let truth = Point2::new(u as f32 * 20.0, v as f32 * 20.0);
+                LabeledCorner::new(
+                    Point2::new(truth.x + jx * amplitude, truth.y + jy * amplitude),
+                    1.0,
+                )
+                .with_grid(Coord::new(u, v))
+            })
+            .collect();
+        TargetDetection::new(TargetKind::Chessboard, corners)
+    }
+
+    /// Mean distance of each corner from its noise-free grid position.
+    fn mean_error(det: &TargetDetection) -> f32 {
+        det.corners
+            .iter()
+            .map(|c| {
+                let g = c.grid.unwrap();
+                (c.position - Point2::new(g.u as f32 * 20.0, g.v as f32 * 20.0)).norm()
+            })
+            .sum::<f32>()
+            / det.corners.len() as f32
+    }
+
+    #[test]
+    fn interpolate_missing_fills_interior_hole() {
+        let mut det = noisy_grid(0, 0.0);
+        det.corners.retain(|c| c.grid != Some(Coord::new(2, 3)));
+
+        let filled = det.interpolate_missing();
+        assert_eq!(filled.corners.len(), 25);
+        let added: Vec<&LabeledCorner> = filled.corners.iter().filter(|c| c.interpolated).collect();
+        assert_eq!(added.len(), 1);
+        assert_eq!(added[0].grid, Some(Coord::new(2, 3)));
+        assert_eq!(added[0].score, 0.0);
+        assert!((added[0].position - Point2::new(40.0, 60.0)).norm() < 1e-2);
+        assert!(mean_error(&filled) < 1e-2);
+
+        let json = serde_json::to_value(&filled.corners[0]).unwrap();
+        assert!(json.get("interpolated").is_none());
+    }
+
+    #[test]
+    fn interpolate_missing_needs_four_gridded_corners() {
+        let mut det = noisy_grid(0, 0.0);
+        det.corners.truncate(3);
+        assert_eq!(det.interpolate_missing().corners.len(), 3);
+    }
+
+    #[test]
+    fn merge_averaged_tightens_noisy_copies() {
+        let frames: Vec<TargetDetection> = (1..=3).map(|s| noisy_grid(s, 0.5)).collect();
+        let merged = frames[0].merge_averaged(&frames[1..], 2.0);
+
+        assert_eq!(merged.corners.len(), 25);
+        let merged_err = mean_error(&merged);
+        for frame in &frames {
+            assert!(
+                merged_err < mean_error(frame),
+                "{merged_err} vs {}",
+                mean_error(frame)
+            );
+        }
+        assert!(merged.corners.iter().all(|c| (c.score - 1.0).abs() < 1e-6));
+    }
+
+    #[test]
+    fn merge_averaged_downweights_single_frame_corners_and_outliers() {
+        let base = noisy_grid(1, 0.0);
+        let mut extra = noisy_grid(2, 0.0);
+        extra
+            .corners
+            .push(LabeledCorner::new(Point2::new(100.0, 0.0), 1.0).with_grid(Coord::new(5, 0)));
+        // An outlier far from the reference is ignored, not averaged in.
+        extra.corners[0].position = Point2::new(50.0, 50.0);
+
+        let merged = base.merge_averaged(&[extra], 1.0);
+        let by_grid = |u, v| {
+            merged
+                .corners
+                .iter()
+                .find(|c| c.grid == Some(Coord::new(u, v)))
+                .unwrap()
+        };
+        assert_eq!(merged.corners.len(), 26);
+        assert!((by_grid(5, 0).score - 0.5).abs() < 1e-6);
+        assert_eq!(by_grid(0, 0).position, Point2::new(0.0, 0.0));
+        assert!((by_grid(0, 0).score - 0.5).abs() < 1e-6);
+        assert!((by_grid(1, 1).score - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn merge_within_radius_keeps_one_corner_per_physical_corner() {
+        // The same grid seen at two scales: slightly different positions,
+        // the coarse level less confident and labelled from another origin.
+        let fine = noisy_grid(1, 0.3);
+        let mut coarse = noisy_grid(2, 0.8);
+        for c in &mut coarse.corners {
+            c.score = 0.6;
+            c.grid = c.grid.map(|g| Coord::new(g.u + 3, g.v + 1));
+        }
+        coarse
+            .corners
+            .push(LabeledCorner::new(Point2::new(100.0, 40.0), 0.6));
+
+        let merged = fine.merge_within_radius(&[coarse], 3.0);
+        assert_eq!(merged.corners.len(), 26);
+        assert_eq!(merged.corners.iter().filter(|c| c.score == 1.0).count(), 25);
+        assert!(merged
+            .corners
+            .iter()
+            .any(|c| c.position == Point2::new(100.0, 40.0)));
+    }
+
+    #[test]
+    fn merge_within_radius_keeps_labels_in_the_self_frame() {
+        // `self` sees the three left columns; a more confident detection sees
+        // the whole grid, labelled from an origin shifted by (3, 1).
+        let mut left = noisy_grid(1, 0.3);
+        left.corners.retain(|c| c.grid.unwrap().u < 3);
+        let mut shifted = noisy_grid(2, 0.3);
+        for c in &mut shifted.corners {
+            c.score = 2.0;
+            c.grid = c.grid.map(|g| Coord::new(g.u + 3, g.v + 1));
+        }
+
+        let merged = left.merge_within_radius(&[shifted], 3.0);
+        assert_eq!(merged.corners.len(), 25);
+        assert!(merged.corners.iter().all(|c| c.score == 2.0));
+        let mut seen = std::collections::HashSet::new();
+        for c in &merged.corners {
+            let truth = Coord::new(
+                (c.position.x / 20.0).round() as i32,
+                (c.position.y / 20.0).round() as i32,
+            );
+            match c.grid {
+                Some(g) => {
+                    assert_eq!(g, truth);
+                    assert!(seen.insert(g), "grid {g:?} labelled twice");
+                }
+                None => assert!(truth.u >= 3, "{truth:?} lost its label"),
+            }
+        }
+        assert_eq!(seen.len(), 15);
+    }
+
+    #[test]
+    fn image_centroid_and_scale_of_centred_grid() {
+        // 5x5 grid at 20 px pitch centred on (100, 80).
+        let mut det = noisy_grid(0, 0.0);
+        for c in &mut det.corners {
+            c.position += nalgebra::Vector2::new(60.0, 40.0);
+        }
+        let (centroid, scale) = det.image_centroid_and_scale().expect("corners");
+        assert!((centroid - Point2::new(100.0, 80.0)).norm() < 1e-4);
+        let expected = det
+            .corners
+            .iter()
+            .map(|c| (c.position - centroid).norm())
+            .sum::<f32>()
+            / 25.0;
+        assert!((scale - expected).abs() < 1e-4);
+        assert!(scale > 20.0 && scale < 40.0, "scale {scale}");
+
+        let empty = TargetDetection::new(det.kind, Vec::new());
+        assert!(empty.image_centroid_and_scale().is_none());
+    }
+
+    #[test]
+    fn convex_hull_of_rectangular_grid_is_its_four_extremes() {
+        // 5x5 grid at 20 px pitch: extremes at 0 and 80 on both axes.
+        let hull = noisy_grid(0, 0.0).convex_hull();
+        assert_eq!(
+            hull,
+            vec![
+                Point2::new(0.0, 0.0),
+                Point2::new(80.0, 0.0),
+                Point2::new(80.0, 80.0),
+                Point2::new(0.0, 80.0),
+            ]
+        );
+
+        let empty = TargetDetection::new(TargetKind::Chessboard, Vec::new());
+        assert!(empty.convex_hull().is_empty());
+    }
+
+    #[test]
+    fn sort_by_grid_orders_rows_then_columns() {
+        let mut det = noisy_grid(1, 0.0);
+        det.corners
+            .insert(3, LabeledCorner::new(Point2::new(-5.0, -5.0), 0.5));
+        det.corners.reverse();
+        det.corners.swap(4, 17);
+
+        det.sort_by_grid();
+        let grids: Vec<Option<Coord>> = det.corners.iter().map(|c| c.grid).collect();
+        let expected: Vec<Option<Coord>> = (0..25)
+            .map(|k| Some(Coord::new(k % 5, k / 5)))
+            .chain([None])
+            .collect();
+        assert_eq!(grids, expected);
+    }
+
+    #[test]
+    fn grid_bounds_span_negative_indices_and_skip_ungridded_corners() {
+        let corners = [(-2, 3), (1, -4), (0, 0), (-1, 5)]
+            .into_iter()
+            .map(|(u, v)| {
+                LabeledCorner::new(Point2::new(0.0, 0.0), 1.0).with_grid(Coord::new(u, v))
+            })
+            .chain([LabeledCorner::new(Point2::new(9.0, 9.0), 1.0)])
+            .collect();
+        let det = TargetDetection::new(TargetKind::Chessboard, corners);
+        assert_eq!(det.grid_bounds(), Some((-2, -4, 1, 5)));
+
+        let ungridded = TargetDetection::new(
+            TargetKind::Chessboard,
+            vec![LabeledCorner::new(Point2::new(1.0, 1.0), 1.0)],
+        );
+        assert_eq!(ungridded.grid_bounds(), None);
+    }
+
+    #[test]
+    fn validate_monotonic_flags_a_swapped_pair_in_any_orientation() {
+        let clean = noisy_grid(1, 0.3);
+        assert!(clean.validate_monotonic().is_empty());
+
+        // The same board turned 180°: its axes run against the image axes,
+        // but every row and column is still monotonic.
+        let mut turned = clean.clone();
+        for c in &mut turned.corners {
+            c.position = Point2::new(-c.position.x, -c.position.y);
+        }
+        assert!(turned.validate_monotonic().is_empty());
+
+        let mut swapped = clean;
+        let a = swapped
+            .corners
+            .iter()
+            .position(|c| c.grid == Some(Coord::new(1, 2)));
+        let b = swapped
+            .corners
+            .iter()
+            .position(|c| c.grid == Some(Coord::new(2, 2)));
+        let (a, b) = (a.unwrap(), b.unwrap());
+        swapped.corners[a].grid = Some(Coord::new(2, 2));
+        swapped.corners[b].grid = Some(Coord::new(1, 2));
+        assert_eq!(
+            swapped.validate_monotonic(),
+            vec![Coord::new(1, 2), Coord::new(2, 2)]
+        );
+    }
 }
@@ -106,6 +106,23 @@ impl GridAlignment {
         Coord::new(g.u + self.translation[0], g.v + self.translation[1])
     }
 
+    /// The alignment that applies `self`, then `next`.
+    pub fn then(&self, next: &GridAlignment) -> GridAlignment {
+        let t = &next.transform;
+        let s = &self.transform;
+        let [tx, ty] = self.translation;
+        let g = next.map(tx, ty);
+        GridAlignment {
+            transform: GridTransform {
+                a: t.a * s.a + t.b * s.c,
+                b: t.a * s.b + t.b * s.d,
+                c: t.c * s.a + t.d * s.c,
+                d: t.c * s.b + t.d * s.d,
+            },
+            translation: [g.u, g.v],
+        }
+    }
+
     /// Invert the alignment if its linear part is unimodular (det = ±1).
     pub fn inverse(&self) -> Option<GridAlignment> {
         let inv = self.transform.inverse()?;
@@ -229,6 +246,12 @@ mod tests {
         assert_eq!(q, Coord::new(8, -6));
         let inv = align.inverse().expect("D4 alignment is invertible");
         assert_eq!(inv.map(q.u, q.v), p);
+        assert_eq!(align.then(&inv), GridAlignment::IDENTITY);
+        let next = GridAlignment {
+            transform: GRID_TRANSFORMS_D4[5],
+            translation: [-1, 7],
+        };
+        assert_eq!(align.then(&next).map(p.u, p.v), next.map(q.u, q.v));
     }
 
     #[test]
@@ -105,3 +105,143 @@ pub fn sample_bilinear_fast(src: &GrayImageView<'_>, x: f32, y: f32) -> f32 {
 pub fn sample_bilinear_u8(src: &GrayImageView<'_>, x: f32, y: f32) -> u8 {
     sample_bilinear(src, x, y).clamp(0.0, 255.0) as u8
 }
+
+/// Downscale a grayscale image by an integer `factor` with a box filter.
+///
+/// Output pixel `(x, y)` is the rounded mean of the `factor × factor` source
+/// block starting at `(x * factor, y * factor)`; trailing rows and columns
+/// that do not fill a whole block are dropped. Under the pixel-centre
+/// convention of [`sample_bilinear`], output position `p` maps back to
+/// source position `p * factor + (factor - 1) / 2`. A `factor` of `0` or `1`
+/// returns a copy.
+pub fn downscale_box(src: &GrayImageView<'_>, factor: usize) -> GrayImage {
+    if factor <= 1 {
+        return GrayImage {
+            width: src.width,
+            height: src.height,
+            data: src.data.to_vec(),
+        };
+    }
+    let width = src.width / factor;
+    let height = src.height / factor;
+    let area = (factor * factor) as u32;
+    let mut data = Vec::with_capacity(width * height);
+    for y in 0..height {
+        for x in 0..width {
+            let mut sum = 0u32;
+            for row in y * factor..(y + 1) * factor {
+                let start = row * src.width + x * factor;
+                sum += src.data[start..start + factor]
+                    .iter()
+                    .map(|&v| u32::from(v))
+                    .sum::<u32>();
+            }
+            data.push(((sum + area / 2) / area) as u8);
+        }
+    }
+    GrayImage {
+        width,
+        height,
+        data,
+    }
+}
+
+/// Blur a grayscale image with a separable Gaussian of standard deviation
+/// `sigma` pixels, keeping its size.
+///
+/// The kernel is truncated at `3 σ` and normalised; reads past the image
+/// edge repeat the border pixel, so a flat image stays flat. A `sigma` that
+/// is not finite and positive returns a copy. Typical denoising values for
+/// corner detection sit between `0.5` and `2.0`.
+pub fn gaussian_blur(src: &GrayImageView<'_>, sigma: f32) -> GrayImage {
+    if !(sigma.is_finite() && sigma > 0.0) || src.width == 0 || src.height == 0 {
+        return GrayImage {
+            width: src.width,
+            height: src.height,
+            data: src.data.to_vec(),
+        };
+    }
+    let radius = (3.0 * sigma).ceil() as i32;
+    let weights: Vec<f32> = (-radius..=radius)
+        .map(|k| (-(k * k) as f32 / (2.0 * sigma * sigma)).exp())
+        .collect();
+    let norm: f32 = weights.iter().sum();
+    let kernel: Vec<f32> = weights.iter().map(|w| w / norm).collect();
+
+    let (w, h) = (src.width, src.height);
+    let tap = |i: usize, k: usize, len: usize| {
+        (i as i32 + k as i32 - radius).clamp(0, len as i32 - 1) as usize
+    };
+    let mut rows = vec![0.0f32; w * h];
+    for y in 0..h {
+        let row = &src.data[y * w..(y + 1) * w];
+        for x in 0..w {
+            rows[y * w + x] = kernel
+                .iter()
+                .enumerate()
+                .map(|(k, &c)| c * row[tap(x, k, w)] as f32)
+                .sum();
+        }
+    }
+    let mut data = Vec::with_capacity(w * h);
+    for y in 0..h {
+        for x in 0..w {
+            let v: f32 = kernel
+                .iter()
+                .enumerate()
+                .map(|(k, &c)| c * rows[tap(y, k, h) * w + x])
+                .sum();
+            data.push(v.round().clamp(0.0, 255.0) as u8);
+        }
+    }
+    GrayImage {
+        width: w,
+        height: h,
+        data,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gaussian_blur_spreads_an_impulse_and_keeps_flat_regions() {
+        let mut data = vec![100u8; 9 * 9];
+        data[4 * 9 + 4] = 250;
+        let src = GrayImageView {
+            width: 9,
+            height: 9,
+            data: &data,
+        };
+        let out = gaussian_blur(&src, 1.0);
+        assert_eq!((out.width, out.height), (9, 9));
+        let at = |x: usize, y: usize| out.data[y * 9 + x];
+        // The peak is lowered and shared symmetrically with its neighbours.
+        assert!(at(4, 4) < 250 && at(4, 4) > 100);
+        assert_eq!(at(3, 4), at(5, 4));
+        assert_eq!(at(3, 4), at(4, 3));
+        assert!(at(3, 4) > 100 && at(3, 4) < at(4, 4));
+        // Far from the impulse, including at the borders, the image is flat.
+        assert_eq!(at(0, 0), 100);
+        assert_eq!(at(8, 8), 100);
+
+        assert_eq!(gaussian_blur(&src, 0.0).data, data);
+    }
+
+    #[test]
+    fn downscale_box_averages_blocks_and_drops_remainder() {
+        // 5 × 4 source: the trailing column does not fill a 2 × 2 block.
+        let data: Vec<u8> = (0..20).map(|v| v * 10).collect();
+        let src = GrayImageView {
+            width: 5,
+            height: 4,
+            data: &data,
+        };
+        let out = downscale_box(&src, 2);
+        assert_eq!((out.width, out.height), (2, 2));
+        // Block (0, 0) holds 0, 10, 50, 60 → 30.
+        assert_eq!(out.data, vec![30, 50, 130, 150]);
+        assert_eq!(downscale_box(&src, 1).data, data);
+    }
+}
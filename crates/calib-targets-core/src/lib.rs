@@ -21,9 +21,11 @@
 //! - Homography estimation and warping helpers.
 //! - Lightweight grayscale image views and sampling.
 //! - Grid alignment and target detection types.
+//! - A KD-tree [`PointIndex`] for nearest-neighbour and radius queries.
 #![deny(missing_docs)]
 
 mod bit_likelihood;
+mod board_convention;
 mod chess;
 mod corner;
 mod corner_map;
@@ -33,9 +35,14 @@ mod homography;
 mod image;
 pub mod io;
 mod logger;
+mod point_index;
+mod quality;
 mod rectify;
+mod repeatability;
+mod summary;
 
 pub use bit_likelihood::log_sigmoid;
+pub use board_convention::{BoardConvention, BoardOrigin};
 pub use grid_smoothness::square_predict_grid_position;
 pub use homography::{
     estimate_homography_rect_to_img, estimate_homography_with_quality, homography_from_4pt,
@@ -43,9 +50,14 @@ pub use homography::{
     warp_perspective_gray, Homography, HomographyQuality,
 };
 pub use image::{
-    sample_bilinear, sample_bilinear_fast, sample_bilinear_u8, GrayImage, GrayImageView,
+    downscale_box, gaussian_blur, sample_bilinear, sample_bilinear_fast, sample_bilinear_u8,
+    GrayImage, GrayImageView,
 };
+pub use point_index::{PointHit, PointIndex};
+pub use quality::DetectionQuality;
 pub use rectify::{RectToImgMapper, RectifiedView};
+pub use repeatability::{detection_repeatability, RepeatabilityStats};
+pub use summary::{DetectionSummary, Summarize};
 
 // Only the two `chess-corners` types the workspace's own public API
 // legitimately exposes are re-exported: `DetectorConfig` is the ChESS config
@@ -72,4 +84,4 @@ pub use projective_grid::Coord;
 #[cfg(feature = "tracing")]
 pub use logger::init_tracing;
 
-pub use logger::init_with_level;
+pub use logger::{init_with_level, set_log_sink, LogSink};
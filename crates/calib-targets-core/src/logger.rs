@@ -1,13 +1,15 @@
 //! Minimal logger.
 //!
 //! The logger prints `[elapsed LEVEL] message` to stderr with a simple
-//! elapsed-time prefix. Use `init_with_level` to install it once at startup.
+//! elapsed-time prefix. Use `init_with_level` to install it once at startup,
+//! and `set_log_sink` to route records to a callback (e.g. a language binding)
+//! instead of stderr.
 
 use std::io::Write;
-use std::sync::OnceLock;
+use std::sync::{Arc, OnceLock, RwLock};
 use std::time::Instant;
 
-use log::{LevelFilter, Log, Metadata, Record};
+use log::{Level, LevelFilter, Log, Metadata, Record};
 
 #[cfg(feature = "tracing")]
 use tracing_subscriber::fmt::format::FmtSpan;
@@ -16,14 +18,19 @@ use tracing_subscriber::util::SubscriberInitExt;
 #[cfg(feature = "tracing")]
 use tracing_subscriber::{fmt, EnvFilter};
 
+/// Receiver for records routed by [`set_log_sink`], called with the record's
+/// level, target, and formatted message.
+pub type LogSink = Box<dyn Fn(Level, &str, &str) + Send + Sync>;
+
+static SINK: RwLock<Option<Arc<LogSink>>> = RwLock::new(None);
+
 struct SimpleLogger {
-    level: LevelFilter,
     started: Instant,
 }
 
 impl Log for SimpleLogger {
     fn enabled(&self, metadata: &Metadata) -> bool {
-        metadata.level() <= self.level
+        metadata.level() <= log::max_level()
     }
 
     fn log(&self, record: &Record) {
@@ -31,6 +38,13 @@ impl Log for SimpleLogger {
             return;
         }
 
+        // Clone the sink out of the lock so a sink may replace itself.
+        let sink = SINK.read().ok().and_then(|guard| guard.clone());
+        if let Some(sink) = sink {
+            sink(record.level(), record.target(), &record.args().to_string());
+            return;
+        }
+
         let elapsed = self.started.elapsed().as_secs_f64();
         let mut stderr = std::io::stderr();
         let _ = writeln!(
@@ -49,20 +63,31 @@ static LOGGER: OnceLock<SimpleLogger> = OnceLock::new();
 
 /// Install the simple logger with the provided level filter.
 ///
-/// Calling this more than once is a no-op after the first successful
-/// initialization.
+/// Only the first call tries to install the logger, and fails if a
+/// different logger is already installed. Every later call just updates the
+/// level filter and returns `Ok`, even when that first install failed.
 pub fn init_with_level(level: LevelFilter) -> Result<(), log::SetLoggerError> {
     if LOGGER.get().is_none() {
         let logger = LOGGER.get_or_init(|| SimpleLogger {
-            level,
             started: Instant::now(),
         });
         log::set_logger(logger)?;
-        log::set_max_level(level);
     }
+    log::set_max_level(level);
     Ok(())
 }
 
+/// Route log records to `sink` instead of stderr; `None` restores stderr
+/// output.
+///
+/// Only affects the logger installed by [`init_with_level`]; records are
+/// still filtered by its level first.
+pub fn set_log_sink(sink: Option<LogSink>) {
+    if let Ok(mut guard) = SINK.write() {
+        *guard = sink.map(Arc::new);
+    }
+}
+
 /// Install a global `tracing` subscriber (available with the `tracing` feature).
 ///
 /// The level filter is read from the `RUST_LOG` environment variable,
@@ -90,3 +115,41 @@ pub fn init_tracing(json: bool) {
             .try_init();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    /// Clears the global sink when dropped, so a failing test cannot leave
+    /// it installed for the rest of the test binary.
+    struct ResetSink;
+
+    impl Drop for ResetSink {
+        fn drop(&mut self) {
+            set_log_sink(None);
+        }
+    }
+
+    #[test]
+    fn sink_receives_records_at_or_above_the_level() {
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let sink_seen = Arc::clone(&seen);
+        init_with_level(LevelFilter::Warn).unwrap();
+        let _reset = ResetSink;
+        set_log_sink(Some(Box::new(move |level, target, message| {
+            if target == module_path!() {
+                sink_seen.lock().unwrap().push((level, message.to_owned()));
+            }
+        })));
+        log::info!("filtered out");
+        log::warn!("rejected: {}", 3);
+        set_log_sink(None);
+        log::warn!("back to stderr");
+
+        assert_eq!(
+            *seen.lock().unwrap(),
+            vec![(Level::Warn, "rejected: 3".to_owned())]
+        );
+    }
+}
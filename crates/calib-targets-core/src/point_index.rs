@@ -0,0 +1,181 @@
+//! Static 2D point index for nearest-neighbour and radius queries.
+//!
+//! [`PointIndex`] wraps a `kiddo` KD-tree (the same structure the
+//! `projective-grid` stages build internally) behind a small, index-based API
+//! so detectors and downstream tools can replace `O(n²)` neighbour scans
+//! without taking a direct `kiddo` dependency.
+
+use kiddo::{KdTree, SquaredEuclidean};
+use nalgebra::Point2;
+
+/// One query hit: the index of the point in the slice the index was built
+/// from, and its Euclidean distance to the query point.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PointHit {
+    /// Index into the point slice passed to [`PointIndex::new`].
+    pub index: usize,
+    /// Euclidean distance from the query point, in the input units (pixels).
+    pub distance: f32,
+}
+
+/// Immutable KD-tree over a set of 2D points.
+///
+/// Query results are sorted by ascending distance, ties broken by ascending
+/// point index, so results are deterministic regardless of tree layout.
+pub struct PointIndex {
+    tree: KdTree<f32, 2>,
+    len: usize,
+}
+
+impl PointIndex {
+    /// Build an index over `points`. Hits refer back to positions in this
+    /// slice. Non-finite points are skipped (they can never be a hit).
+    pub fn new(points: &[Point2<f32>]) -> Self {
+        let mut tree: KdTree<f32, 2> = KdTree::with_capacity(points.len());
+        for (i, p) in points.iter().enumerate() {
+            if p.x.is_finite() && p.y.is_finite() {
+                tree.add(&[p.x, p.y], i as u64);
+            }
+        }
+        Self {
+            tree,
+            len: points.len(),
+        }
+    }
+
+    /// Number of points the index was built from (including skipped
+    /// non-finite ones).
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// `true` when the index was built from an empty slice.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The `k` points nearest to `p`, closest first. Returns fewer than `k`
+    /// hits when the index holds fewer points. A point coinciding with `p` is
+    /// included (at distance `0`).
+    pub fn nearest(&self, p: Point2<f32>, k: usize) -> Vec<PointHit> {
+        if k == 0 {
+            return Vec::new();
+        }
+        let hits = self.tree.nearest_n::<SquaredEuclidean>(&[p.x, p.y], k);
+        into_sorted_hits(hits.into_iter().map(|nn| (nn.item, nn.distance)))
+    }
+
+    /// Every point within distance `r` of `p` (inclusive), closest first.
+    pub fn within_radius(&self, p: Point2<f32>, r: f32) -> Vec<PointHit> {
+        if r.is_nan() || r < 0.0 {
+            return Vec::new();
+        }
+        let hits = self
+            .tree
+            .within_unsorted::<SquaredEuclidean>(&[p.x, p.y], r * r);
+        into_sorted_hits(hits.into_iter().map(|nn| (nn.item, nn.distance)))
+    }
+}
+
+fn into_sorted_hits(hits: impl Iterator<Item = (u64, f32)>) -> Vec<PointHit> {
+    let mut out: Vec<PointHit> = hits
+        .map(|(item, d2)| PointHit {
+            index: item as usize,
+            distance: d2.sqrt(),
+        })
+        .collect();
+    out.sort_by(|a, b| {
+        a.distance
+            .total_cmp(&b.distance)
+            .then(a.index.cmp(&b.index))
+    });
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Deterministic xorshift64* generator — no `rand` dependency.
+    struct Lcg(u64);
+
+    impl Lcg {
+        fn next_unit(&mut self) -> f32 {
+            let mut x = self.0;
+            x ^= x >> 12;
+            x ^= x << 25;
+            x ^= x >> 27;
+            self.0 = x;
+            let bits = x.wrapping_mul(0x2545_F491_4F6C_DD1D);
+            ((bits >> 40) as f32) / ((1u32 << 24) as f32)
+        }
+    }
+
+    fn random_points(n: usize, seed: u64) -> Vec<Point2<f32>> {
+        let mut rng = Lcg(seed | 1);
+        (0..n)
+            .map(|_| Point2::new(rng.next_unit() * 640.0, rng.next_unit() * 480.0))
+            .collect()
+    }
+
+    fn brute_force(points: &[Point2<f32>], p: Point2<f32>) -> Vec<PointHit> {
+        let mut all: Vec<PointHit> = points
+            .iter()
+            .enumerate()
+            .map(|(index, q)| PointHit {
+                index,
+                distance: (q - p).norm(),
+            })
+            .collect();
+        all.sort_by(|a, b| {
+            a.distance
+                .total_cmp(&b.distance)
+                .then(a.index.cmp(&b.index))
+        });
+        all
+    }
+
+    #[test]
+    fn nearest_matches_brute_force() {
+        let points = random_points(500, 7);
+        let index = PointIndex::new(&points);
+        for q in random_points(50, 11) {
+            let expected = brute_force(&points, q);
+            for k in [1, 4, 9] {
+                let got: Vec<usize> = index.nearest(q, k).iter().map(|h| h.index).collect();
+                let want: Vec<usize> = expected[..k].iter().map(|h| h.index).collect();
+                assert_eq!(got, want, "k = {k} at {q:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn within_radius_matches_brute_force() {
+        let points = random_points(500, 3);
+        let index = PointIndex::new(&points);
+        for q in random_points(50, 5) {
+            let want: Vec<usize> = brute_force(&points, q)
+                .into_iter()
+                .take_while(|h| h.distance <= 40.0)
+                .map(|h| h.index)
+                .collect();
+            let got: Vec<usize> = index
+                .within_radius(q, 40.0)
+                .iter()
+                .map(|h| h.index)
+                .collect();
+            assert_eq!(got, want, "at {q:?}");
+        }
+    }
+
+    #[test]
+    fn degenerate_queries_are_empty() {
+        let index = PointIndex::new(&[]);
+        assert!(index.is_empty());
+        assert!(index.nearest(Point2::origin(), 3).is_empty());
+        let index = PointIndex::new(&[Point2::new(1.0, 1.0)]);
+        assert_eq!(index.nearest(Point2::origin(), 3).len(), 1);
+        assert!(index.nearest(Point2::origin(), 0).is_empty());
+        assert!(index.within_radius(Point2::origin(), -1.0).is_empty());
+    }
+}
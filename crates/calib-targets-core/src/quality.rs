@@ -0,0 +1,195 @@
+//! Ground-truth-free detection quality, for picking the best of many frames.
+
+use crate::corner::TargetDetection;
+use nalgebra::Point2;
+use projective_grid::Coord;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Heuristic quality terms of a detection, each in `[0, 1]`, higher better.
+///
+/// Produced by [`TargetDetection::quality`]; [`Self::score`] folds them into
+/// one number. Nothing here needs ground truth, so the terms rank frames of
+/// the same board rather than measure calibration accuracy.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DetectionQuality {
+    /// Gridded corners over the slots of their `(u, v)` bounding box — the
+    /// same measure as [`DetectionSummary::completeness`](crate::DetectionSummary::completeness).
+    pub completeness: f32,
+    /// Consistency of the corner scores, `1 / (1 + cv)` with `cv` their
+    /// coefficient of variation; `0.0` when the mean score is not positive.
+    pub confidence: f32,
+    /// Local lattice regularity, `1 - 2 r` clamped to `[0, 1]`. `r` is the
+    /// mean distance of a corner from the midpoint of its two grid
+    /// neighbours along `u` or `v`, in units of their spacing; perspective
+    /// barely moves it, jitter and mislabels do. `0.0` when no corner has
+    /// both neighbours along an axis.
+    pub regularity: f32,
+    /// Share of the expected markers that were decoded, for target kinds
+    /// that carry markers; set with [`Self::with_marker_coverage`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub marker_coverage: Option<f32>,
+}
+
+impl DetectionQuality {
+    /// Weight of [`Self::completeness`] in [`Self::score`].
+    pub const COMPLETENESS_WEIGHT: f32 = 0.35;
+    /// Weight of [`Self::confidence`] in [`Self::score`].
+    pub const CONFIDENCE_WEIGHT: f32 = 0.2;
+    /// Weight of [`Self::regularity`] in [`Self::score`].
+    pub const REGULARITY_WEIGHT: f32 = 0.25;
+    /// Weight of [`Self::marker_coverage`] in [`Self::score`], when present.
+    pub const MARKER_COVERAGE_WEIGHT: f32 = 0.2;
+
+    /// Quality terms of `detection`, with no marker term.
+    pub fn from_detection(detection: &TargetDetection) -> Self {
+        Self {
+            completeness: crate::summary::grid_completeness(detection),
+            confidence: score_consistency(detection),
+            regularity: lattice_regularity(detection),
+            marker_coverage: None,
+        }
+    }
+
+    /// Set [`Self::marker_coverage`], clamped to `[0, 1]`.
+    #[must_use]
+    pub fn with_marker_coverage(mut self, coverage: f32) -> Self {
+        self.marker_coverage = Some(coverage.clamp(0.0, 1.0));
+        self
+    }
+
+    /// Weighted mean of the terms, in `[0, 1]`.
+    ///
+    /// Weights are completeness `0.35`, confidence `0.2`, regularity `0.25`
+    /// and marker coverage `0.2`. Without a marker term the other three are
+    /// rescaled to sum to one.
+    pub fn score(&self) -> f32 {
+        let mut sum = Self::COMPLETENESS_WEIGHT * self.completeness
+            + Self::CONFIDENCE_WEIGHT * self.confidence
+            + Self::REGULARITY_WEIGHT * self.regularity;
+        let mut total =
+            Self::COMPLETENESS_WEIGHT + Self::CONFIDENCE_WEIGHT + Self::REGULARITY_WEIGHT;
+        if let Some(coverage) = self.marker_coverage {
+            sum += Self::MARKER_COVERAGE_WEIGHT * coverage;
+            total += Self::MARKER_COVERAGE_WEIGHT;
+        }
+        sum / total
+    }
+}
+
+impl TargetDetection {
+    /// Ground-truth-free quality terms of this detection; see
+    /// [`DetectionQuality`].
+    pub fn quality(&self) -> DetectionQuality {
+        DetectionQuality::from_detection(self)
+    }
+
+    /// Composite quality in `[0, 1]`, for ranking frames of the same board;
+    /// see [`DetectionQuality::score`] for the weighting. `0.0` for an empty
+    /// detection.
+    pub fn quality_score(&self) -> f32 {
+        self.quality().score()
+    }
+}
+
+fn score_consistency(detection: &TargetDetection) -> f32 {
+    let n = detection.corners.len();
+    if n == 0 {
+        return 0.0;
+    }
+    let mean = detection.corners.iter().map(|c| c.score).sum::<f32>() / n as f32;
+    if mean <= 0.0 {
+        return 0.0;
+    }
+    let var = detection
+        .corners
+        .iter()
+        .map(|c| (c.score - mean).powi(2))
+        .sum::<f32>()
+        / n as f32;
+    1.0 / (1.0 + var.sqrt() / mean)
+}
+
+fn lattice_regularity(detection: &TargetDetection) -> f32 {
+    let mut by_grid: HashMap<Coord, Point2<f32>> = HashMap::new();
+    for c in &detection.corners {
+        if let Some(g) = c.grid {
+            by_grid.entry(g).or_insert(c.position);
+        }
+    }
+    let (mut sum, mut count) = (0.0f32, 0usize);
+    for (g, p) in &by_grid {
+        for (du, dv) in [(1, 0), (0, 1)] {
+            let prev = by_grid.get(&Coord::new(g.u - du, g.v - dv));
+            let next = by_grid.get(&Coord::new(g.u + du, g.v + dv));
+            let (Some(a), Some(b)) = (prev, next) else {
+                continue;
+            };
+            let span = (b - a).norm();
+            if span > f32::EPSILON {
+                let midpoint = a + (b - a) * 0.5;
+                // Offset from the midpoint, in units of one spacing.
+                sum += 2.0 * (p - midpoint).norm() / span;
+                count += 1;
+            }
+        }
+    }
+    if count == 0 {
+        return 0.0;
+    }
+    (1.0 - 2.0 * sum / count as f32).clamp(0.0, 1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{LabeledCorner, TargetKind};
+
+    fn grid(cells: impl Iterator<Item = (i32, i32, f32, f32, f32)>) -> TargetDetection {
+        let corners = cells
+            .map(|(u, v, dx, dy, score)| {
+                LabeledCorner::new(
+                    Point2::new(20.0 * u as f32 + dx, 20.0 * v as f32 + dy),
+                    score,
+                )
+                .with_grid(Coord::new(u, v))
+            })
+            .collect();
+        TargetDetection::new(TargetKind::Chessboard, corners)
+    }
+
+    #[test]
+    fn full_regular_board_outscores_sparse_irregular_one() {
+        let full = grid((0..5).flat_map(|v| (0..7).map(move |u| (u, v, 0.0, 0.0, 0.8))));
+        let quality = full.quality();
+        assert_eq!(quality.completeness, 1.0);
+        assert!((quality.confidence - 1.0).abs() < 1e-6);
+        assert!((quality.regularity - 1.0).abs() < 1e-6);
+        assert!((full.quality_score() - 1.0).abs() < 1e-6);
+
+        // A third of the corners dropped, the rest jittered by up to 4 px with
+        // uneven scores.
+        let sparse = grid(
+            (0..5)
+                .flat_map(|v| (0..7).map(move |u| (u, v)))
+                .filter(|&(u, v)| (u + v) % 3 != 0)
+                .enumerate()
+                .map(|(k, (u, v))| {
+                    let jitter = [(4.0, -3.0), (-2.0, 4.0), (3.0, 1.0)][k % 3];
+                    (u, v, jitter.0, jitter.1, [0.9, 0.2, 0.5][k % 3])
+                }),
+        );
+        let quality = sparse.quality();
+        assert!(quality.completeness < 1.0 && quality.confidence < 0.8);
+        assert!(quality.regularity < 0.9, "{quality:?}");
+        assert!(sparse.quality_score() < full.quality_score() - 0.2);
+
+        let with_markers = quality.with_marker_coverage(1.5);
+        assert_eq!(with_markers.marker_coverage, Some(1.0));
+        assert!(with_markers.score() > quality.score());
+
+        let empty = TargetDetection::new(TargetKind::Chessboard, Vec::new());
+        assert_eq!(empty.quality_score(), 0.0);
+    }
+}
@@ -0,0 +1,105 @@
+//! Frame-to-frame repeatability of a detection, for detector QC on static
+//! scenes without a ground-truth board.
+
+use crate::corner::TargetDetection;
+use projective_grid::Coord;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Position agreement between two detections of the same static target.
+///
+/// Produced by [`detection_repeatability`]. Deltas are in pixels.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct RepeatabilityStats {
+    /// Number of grid coordinates present in both detections.
+    pub common: usize,
+    /// Mean position delta over the common corners; `0.0` when `common == 0`.
+    pub mean_delta_px: f32,
+    /// Largest position delta over the common corners; `0.0` when
+    /// `common == 0`.
+    pub max_delta_px: f32,
+}
+
+/// Compare two detections of a static target, matching corners by grid
+/// coordinate.
+///
+/// Corners without a grid coordinate are ignored, as are duplicate grid
+/// coordinates after the first. The detections must share a grid origin —
+/// true for repeated frames from a detector with a stable labelling rule, or
+/// for ID-labelled targets (ChArUco, PuzzleBoard).
+pub fn detection_repeatability(a: &TargetDetection, b: &TargetDetection) -> RepeatabilityStats {
+    let mut by_grid: HashMap<Coord, nalgebra::Point2<f32>> = HashMap::new();
+    for c in &a.corners {
+        if let Some(g) = c.grid {
+            by_grid.entry(g).or_insert(c.position);
+        }
+    }
+
+    let mut stats = RepeatabilityStats::default();
+    let mut sum = 0.0f32;
+    for c in &b.corners {
+        let Some(p) = c.grid.and_then(|g| by_grid.remove(&g)) else {
+            continue;
+        };
+        let delta = (c.position - p).norm();
+        sum += delta;
+        stats.max_delta_px = stats.max_delta_px.max(delta);
+        stats.common += 1;
+    }
+    if stats.common > 0 {
+        stats.mean_delta_px = sum / stats.common as f32;
+    }
+    stats
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{LabeledCorner, TargetKind};
+    use nalgebra::{Point2, Vector2};
+
+    fn grid(offset: impl Fn(i32, i32) -> Vector2<f32>) -> TargetDetection {
+        let corners = (0..4)
+            .flat_map(|v| (0..4).map(move |u| (u, v)))
+            .map(|(u, v)| {
+                let p = Point2::new(u as f32 * 30.0, v as f32 * 30.0) + offset(u, v);
+                LabeledCorner::new(p, 1.0).with_grid(Coord::new(u, v))
+            })
+            .collect();
+        TargetDetection::new(TargetKind::Chessboard, corners)
+    }
+
+    #[test]
+    fn perturbed_copies_report_expected_deltas() {
+        let a = grid(|_, _| Vector2::zeros());
+        // Half the corners move by 0.3 px, the other half by 0.4 px.
+        let mut b = grid(|u, _| {
+            if u % 2 == 0 {
+                Vector2::new(0.3, 0.0)
+            } else {
+                Vector2::new(0.0, -0.4)
+            }
+        });
+        b.corners.pop();
+        b.corners
+            .push(LabeledCorner::new(Point2::new(500.0, 500.0), 1.0).with_grid(Coord::new(9, 9)));
+
+        let stats = detection_repeatability(&a, &b);
+        assert_eq!(stats.common, 15);
+        // 8 corners at 0.3 px, 7 at 0.4 px (the dropped (3, 3) had u odd).
+        let expected = (8.0 * 0.3 + 7.0 * 0.4) / 15.0;
+        assert!((stats.mean_delta_px - expected).abs() < 1e-5);
+        assert!((stats.max_delta_px - 0.4).abs() < 1e-5);
+    }
+
+    #[test]
+    fn disjoint_detections_have_no_common_corners() {
+        let a = grid(|_, _| Vector2::zeros());
+        let b = TargetDetection::new(TargetKind::Chessboard, Vec::new());
+        assert_eq!(
+            detection_repeatability(&a, &b),
+            RepeatabilityStats::default()
+        );
+    }
+}
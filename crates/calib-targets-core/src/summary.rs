@@ -0,0 +1,146 @@
+//! Compact per-frame detection records, for one-line capture telemetry.
+
+use crate::corner::{TargetDetection, TargetKind};
+use crate::grid_alignment::GridAlignment;
+use serde::{Deserialize, Serialize};
+
+/// One-line summary of a detection, uniform across target kinds.
+///
+/// Produced by [`Summarize::summary`]. Serializes to a small flat record
+/// (the transform is omitted when absent), suitable for logging one line
+/// per frame.
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DetectionSummary {
+    /// Which kind of calibration target was detected.
+    pub kind: TargetKind,
+    /// Number of labelled corners.
+    pub num_corners: usize,
+    /// Gridded corners over the slots of their `(u, v)` bounding box, in
+    /// `[0, 1]`; `0.0` without gridded corners. Low values flag holes or
+    /// occlusion inside the detected extent.
+    pub completeness: f32,
+    /// Number of decoded markers backing the labels; `0` for target kinds
+    /// whose result carries no markers.
+    pub num_markers: usize,
+    /// Area of the corners' convex hull, in square pixels. Divide by the
+    /// image area for the fraction of the frame covered.
+    pub coverage: f32,
+    /// Alignment of the detected grid into board coordinates, when the
+    /// target kind resolves one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub transform: Option<GridAlignment>,
+    /// Mean corner score; `0.0` for an empty detection.
+    pub score: f32,
+}
+
+impl DetectionSummary {
+    /// Summarize the corners of `detection`, with no markers and no
+    /// transform; the typed results fill those in with
+    /// [`Self::with_markers`] and [`Self::with_transform`].
+    pub fn from_detection(detection: &TargetDetection) -> Self {
+        let num_corners = detection.corners.len();
+        let score = if num_corners == 0 {
+            0.0
+        } else {
+            detection.corners.iter().map(|c| c.score).sum::<f32>() / num_corners as f32
+        };
+        Self {
+            kind: detection.kind,
+            num_corners,
+            completeness: grid_completeness(detection),
+            num_markers: 0,
+            coverage: polygon_area(&detection.convex_hull()),
+            transform: None,
+            score,
+        }
+    }
+
+    /// Set [`Self::num_markers`].
+    #[must_use]
+    pub fn with_markers(mut self, num_markers: usize) -> Self {
+        self.num_markers = num_markers;
+        self
+    }
+
+    /// Set [`Self::transform`].
+    #[must_use]
+    pub fn with_transform(mut self, transform: GridAlignment) -> Self {
+        self.transform = Some(transform);
+        self
+    }
+}
+
+/// A detection result that can report a [`DetectionSummary`].
+pub trait Summarize {
+    /// Compact per-frame summary of this detection.
+    fn summary(&self) -> DetectionSummary;
+}
+
+impl Summarize for TargetDetection {
+    fn summary(&self) -> DetectionSummary {
+        DetectionSummary::from_detection(self)
+    }
+}
+
+pub(crate) fn grid_completeness(detection: &TargetDetection) -> f32 {
+    let Some((u0, v0, u1, v1)) = detection.grid_bounds() else {
+        return 0.0;
+    };
+    let n = detection
+        .corners
+        .iter()
+        .filter(|c| c.grid.is_some())
+        .count();
+    let slots = (u1 - u0 + 1) as usize * (v1 - v0 + 1) as usize;
+    (n as f32 / slots as f32).min(1.0)
+}
+
+/// Shoelace area of a simple polygon.
+fn polygon_area(vertices: &[nalgebra::Point2<f32>]) -> f32 {
+    let n = vertices.len();
+    let twice: f32 = (0..n)
+        .map(|i| {
+            let (a, b) = (vertices[i], vertices[(i + 1) % n]);
+            a.x * b.y - b.x * a.y
+        })
+        .sum();
+    0.5 * twice.abs()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::LabeledCorner;
+    use nalgebra::Point2;
+    use projective_grid::Coord;
+
+    #[test]
+    fn summary_counts_holes_and_hull_area() {
+        // 4 × 3 grid at 10 px spacing with one interior slot missing.
+        let corners: Vec<LabeledCorner> = (0..3)
+            .flat_map(|v| (0..4).map(move |u| (u, v)))
+            .filter(|&(u, v)| (u, v) != (1, 1))
+            .map(|(u, v)| {
+                LabeledCorner::new(Point2::new(10.0 * u as f32, 10.0 * v as f32), 0.5)
+                    .with_grid(Coord::new(u, v))
+            })
+            .collect();
+        let summary = TargetDetection::new(TargetKind::Chessboard, corners).summary();
+
+        assert_eq!(summary.kind, TargetKind::Chessboard);
+        assert_eq!(summary.num_corners, 11);
+        assert!((summary.completeness - 11.0 / 12.0).abs() < 1e-6);
+        assert!((summary.coverage - 600.0).abs() < 1e-3);
+        assert_eq!((summary.num_markers, summary.transform), (0, None));
+        assert!((summary.score - 0.5).abs() < 1e-6);
+        let json = serde_json::to_string(&summary).expect("serialize");
+        assert!(!json.contains("transform"), "{json}");
+
+        let empty = TargetDetection::new(TargetKind::Charuco, Vec::new()).summary();
+        assert_eq!(
+            (empty.num_corners, empty.completeness, empty.coverage),
+            (0, 0.0, 0.0)
+        );
+    }
+}
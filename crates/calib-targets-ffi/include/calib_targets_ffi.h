@@ -327,7 +327,8 @@ typedef struct ct_chessboard_corner_t {
    */
   struct ct_grid_coords_t grid;
   /**
-   * Index into the detector's input ChESS-corner slice.
+   * Index into the detector's input ChESS-corner slice; `SIZE_MAX` for a
+   * corner that came from no input (never produced by the detector).
    */
   size_t input_index;
   /**
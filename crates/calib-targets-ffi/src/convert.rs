@@ -867,7 +867,7 @@ pub(crate) fn chessboard_corner_to_ffi(corner: &ChessboardCorner) -> ct_chessboa
     ct_chessboard_corner_t {
         position: point_to_ffi_xy(corner.position.x, corner.position.y),
         grid: grid_coords_to_ffi(corner.grid),
-        input_index: corner.input_index,
+        input_index: corner.input_index.unwrap_or(usize::MAX),
         score: corner.score,
     }
 }
@@ -288,7 +288,8 @@ pub struct ct_chessboard_corner_t {
     pub position: ct_point2f_t,
     /// Grid label (i, j).
     pub grid: ct_grid_coords_t,
-    /// Index into the detector's input ChESS-corner slice.
+    /// Index into the detector's input ChESS-corner slice; `SIZE_MAX` for a
+    /// corner that came from no input (never produced by the detector).
     pub input_index: usize,
     /// Corner score.
     pub score: f32,
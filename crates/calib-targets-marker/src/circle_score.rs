@@ -22,6 +22,25 @@ pub enum CirclePolarity {
     Black,
 }
 
+/// How per-direction disk-vs-ring contrast is combined into a circle's
+/// score.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ScoreAggregation {
+    /// Mean contrast over the whole perimeter.
+    #[default]
+    Mean,
+    /// Weakest contrast among `sectors` equal angular sectors, so a circle
+    /// must contrast with its ring all the way round. A partially occluded
+    /// circle scores by its covered side and is rejected by `min_contrast`.
+    /// `sectors` is clamped to `1..=samples`.
+    MinSector {
+        /// Number of angular sectors, e.g. 8.
+        sectors: usize,
+    },
+}
+
 /// Tuning knobs for per-cell circular-marker scoring.
 ///
 /// **Unstable:** the fields of this struct are **NOT covered by semver** and
@@ -46,6 +65,15 @@ pub struct CircleScoreParams {
     pub samples: usize,
     /// Small local search around center in patch pixels (0..3 is enough)
     pub center_search_px: i32,
+    /// Accepted disk radius range `(min, max)`, in square units. When set,
+    /// the radius is estimated from the radial intensity profile and
+    /// candidates outside the range (noise specks, smudges) are rejected.
+    /// `None` (the default) skips the estimate.
+    #[serde(default)]
+    pub radius_range_squares: Option<(f32, f32)>,
+    /// How perimeter contrast is aggregated into the score and contrast.
+    #[serde(default)]
+    pub aggregation: ScoreAggregation,
 }
 
 impl Default for CircleScoreParams {
@@ -58,6 +86,8 @@ impl Default for CircleScoreParams {
             min_contrast: 10.0,
             samples: 48,
             center_search_px: 2,
+            radius_range_squares: None,
+            aggregation: ScoreAggregation::Mean,
         }
     }
 }
@@ -66,6 +96,10 @@ impl Default for CircleScoreParams {
 #[derive(Clone, Copy, Debug, Serialize, Deserialize)]
 pub struct CircleCandidate {
     /// Circle center in image pixel coordinates.
+    ///
+    /// The contrast-weighted centroid of the disk region (sub-pixel), or the
+    /// best discrete search position when the centroid is unstable (too little
+    /// disk mass, or a centroid that wandered off the disk).
     pub center_img: Point2<f32>,
     /// Detected cell coordinates (top-left corner indices).
     pub cell: CellCoords,
@@ -162,7 +196,24 @@ pub(crate) fn score_circle_in_square(
     let (c_patch, mean_disk, mean_ring) = best?;
 
     let diff = mean_disk - mean_ring; // >0 => disk brighter than ring
-    let contrast = diff.abs();
+    let contrast = match params.aggregation {
+        ScoreAggregation::Mean => diff.abs(),
+        ScoreAggregation::MinSector { sectors } => {
+            let sample_params = SampleParams {
+                radii,
+                dirs: &dirs,
+                stride: 1,
+            };
+            min_sector_contrast(
+                img,
+                &h_img_from_patch,
+                c_patch,
+                &sample_params,
+                sectors,
+                diff,
+            )?
+        }
+    };
 
     if contrast < params.min_contrast {
         return None;
@@ -173,10 +224,34 @@ pub(crate) fn score_circle_in_square(
     } else {
         CirclePolarity::Black
     };
-    let score = diff; // signed score; magnitude = strength
-
-    // Map chosen center to image space
-    let center_img = h_img_from_patch.apply(c_patch);
+    let score = contrast.copysign(diff); // signed score; magnitude = strength
+
+    // Refine the discrete search position to a sub-pixel centroid, and map
+    // the chosen center to image space.
+    let disk = DiskRegion {
+        center: c_patch,
+        radius: r,
+        // Halfway to the ring's inner edge: wide enough to hold the whole
+        // disk after a `center_search_px` miss, narrow enough to keep the ring
+        // (and the cell's own edges) out.
+        search_radius: 0.5 * (r + radii.r0),
+    };
+    let c_refined =
+        weighted_disk_centroid(img, &h_img_from_patch, &disk, mean_ring, diff).unwrap_or(c_patch);
+
+    if let Some((min_r, max_r)) = params.radius_range_squares {
+        let profile = RadialProfile {
+            center: c_refined,
+            max_radius: radii.r1,
+            mean_ring,
+            diff,
+        };
+        let radius = estimate_disk_radius(img, &h_img_from_patch, &dirs, &profile) / s;
+        if !(min_r..=max_r).contains(&radius) {
+            return None;
+        }
+    }
+    let center_img = h_img_from_patch.apply(c_refined);
 
     Some(CircleCandidate {
         center_img,
@@ -187,6 +262,147 @@ pub(crate) fn score_circle_in_square(
     })
 }
 
+/// Weakest disk-vs-ring contrast, in the polarity of `diff`, among
+/// `sectors` contiguous angular slices of `params.dirs`; never negative.
+fn min_sector_contrast(
+    img: &GrayImageView<'_>,
+    h: &Homography,
+    center_patch: Point2<f32>,
+    params: &SampleParams<'_>,
+    sectors: usize,
+    diff: f32,
+) -> Option<f32> {
+    let n = params.dirs.len();
+    let sectors = sectors.clamp(1, n);
+    let mut weakest = f32::INFINITY;
+    for k in 0..sectors {
+        let sector = SampleParams {
+            dirs: &params.dirs[k * n / sectors..(k + 1) * n / sectors],
+            ..*params
+        };
+        let (mean_disk, mean_ring) = sample_disk_and_ring(img, h, center_patch, &sector)?;
+        weakest = weakest.min((mean_disk - mean_ring) * diff.signum());
+    }
+    Some(weakest.max(0.0))
+}
+
+/// Disk geometry in patch space for [`weighted_disk_centroid`].
+struct DiskRegion {
+    center: Point2<f32>,
+    radius: f32,
+    search_radius: f32,
+}
+
+/// Minimum accumulated disk mass, as a fraction of an ideal full-contrast
+/// disk's, for the centroid to be trusted.
+const CENTROID_MIN_MASS_FRAC: f32 = 0.5;
+/// Maximum centroid shift from the discrete center, as a fraction of the disk
+/// radius, for the centroid to be trusted.
+const CENTROID_MAX_SHIFT_FRAC: f32 = 0.5;
+
+/// Contrast-weighted centroid of the disk around `disk.center`, in patch space.
+///
+/// Each patch-pixel center within `disk.search_radius` is weighted by how far
+/// its intensity departs from the ring (background) level towards the disk
+/// level: `max(0, (I - ring) * sign(diff))`. Returns `None` when the result is
+/// unstable — the accumulated mass is below [`CENTROID_MIN_MASS_FRAC`] of an
+/// ideal disk (low contrast, partial occlusion) or the centroid moved more
+/// than [`CENTROID_MAX_SHIFT_FRAC`] of the radius.
+fn weighted_disk_centroid(
+    img: &GrayImageView<'_>,
+    h: &Homography,
+    disk: &DiskRegion,
+    mean_ring: f32,
+    diff: f32,
+) -> Option<Point2<f32>> {
+    let sign = diff.signum();
+    let reach = disk.search_radius.ceil() as i32;
+    let r2 = disk.search_radius * disk.search_radius;
+    let (cx, cy) = (disk.center.x.floor(), disk.center.y.floor());
+
+    let mut sum_w = 0.0f32;
+    let mut sum_x = 0.0f32;
+    let mut sum_y = 0.0f32;
+    for dy in -reach..=reach {
+        for dx in -reach..=reach {
+            let p = Point2::new(cx + dx as f32 + 0.5, cy + dy as f32 + 0.5);
+            let (ox, oy) = (p.x - disk.center.x, p.y - disk.center.y);
+            if ox * ox + oy * oy > r2 {
+                continue;
+            }
+            let q = h.apply(p);
+            let w = ((sample_bilinear_fast(img, q.x, q.y) - mean_ring) * sign).max(0.0);
+            sum_w += w;
+            sum_x += w * p.x;
+            sum_y += w * p.y;
+        }
+    }
+
+    let ideal_mass = diff.abs() * std::f32::consts::PI * disk.radius * disk.radius;
+    if sum_w <= 0.0 || sum_w < CENTROID_MIN_MASS_FRAC * ideal_mass {
+        return None;
+    }
+    let centroid = Point2::new(sum_x / sum_w, sum_y / sum_w);
+    if (centroid - disk.center).norm() > CENTROID_MAX_SHIFT_FRAC * disk.radius {
+        return None;
+    }
+    Some(centroid)
+}
+
+/// Radial intensity profile around a disk center, in patch space.
+struct RadialProfile {
+    center: Point2<f32>,
+    max_radius: f32,
+    mean_ring: f32,
+    diff: f32,
+}
+
+/// Radial step of the disk-edge search, in patch pixels.
+const RADIUS_STEP_PX: f32 = 0.5;
+
+/// Disk radius in patch pixels: where the mean intensity over a circle of
+/// growing radius first falls halfway from the disk level back to the ring
+/// level, linearly interpolated between steps. Saturates at
+/// `profile.max_radius` when the disk never ends inside the ring.
+fn estimate_disk_radius(
+    img: &GrayImageView<'_>,
+    h: &Homography,
+    dirs: &[(f32, f32)],
+    profile: &RadialProfile,
+) -> f32 {
+    // Signed excess over the half-contrast level: positive inside the disk.
+    let excess = |r: f32| {
+        let sum: f32 = dirs
+            .iter()
+            .map(|&(ux, uy)| {
+                let q = h.apply(Point2::new(
+                    profile.center.x + r * ux,
+                    profile.center.y + r * uy,
+                ));
+                sample_bilinear_fast(img, q.x, q.y)
+            })
+            .sum();
+        let mean = sum / dirs.len() as f32;
+        (mean - profile.mean_ring) * profile.diff.signum() - 0.5 * profile.diff.abs()
+    };
+
+    let mut r_prev = 0.0;
+    let mut e_prev = excess(0.0);
+    let mut r = RADIUS_STEP_PX;
+    while r <= profile.max_radius {
+        let e = excess(r);
+        if e <= 0.0 {
+            if e_prev <= 0.0 {
+                return r_prev;
+            }
+            return r_prev + RADIUS_STEP_PX * e_prev / (e_prev - e);
+        }
+        (r_prev, e_prev) = (r, e);
+        r += RADIUS_STEP_PX;
+    }
+    profile.max_radius
+}
+
 fn build_unit_circle_lut(samples: usize) -> Option<Vec<(f32, f32)>> {
     if samples == 0 {
         return None;
@@ -208,6 +424,7 @@ struct SampleRadii {
     r1: f32,
 }
 
+#[derive(Clone, Copy)]
 struct SampleParams<'a> {
     radii: SampleRadii,
     dirs: &'a [(f32, f32)],
@@ -267,6 +484,179 @@ fn sample_disk_and_ring(
 mod tests {
     use super::*;
 
+    /// Render a `side × side` cell (background `bg`) holding a disk of
+    /// `radius` px at `center` with intensity `fg`, anti-aliased by 8×8
+    /// supersampling so the sub-pixel offset is encoded in the pixels. Pixel
+    /// `(x, y)` covers `[x - 0.5, x + 0.5)`, matching `sample_bilinear_fast`.
+    fn render_disk(side: usize, center: Point2<f32>, radius: f32, bg: f32, fg: f32) -> Vec<u8> {
+        const SS: usize = 8;
+        let mut data = vec![0u8; side * side];
+        for y in 0..side {
+            for x in 0..side {
+                let mut inside = 0usize;
+                for sy in 0..SS {
+                    for sx in 0..SS {
+                        let px = x as f32 + (sx as f32 + 0.5) / SS as f32 - 0.5;
+                        let py = y as f32 + (sy as f32 + 0.5) / SS as f32 - 0.5;
+                        if (px - center.x).powi(2) + (py - center.y).powi(2) <= radius * radius {
+                            inside += 1;
+                        }
+                    }
+                }
+                let t = inside as f32 / (SS * SS) as f32;
+                data[y * side + x] = (bg + t * (fg - bg)).round() as u8;
+            }
+        }
+        data
+    }
+
+    fn square(side: f32) -> [Point2<f32>; 4] {
+        [
+            Point2::new(0.0, 0.0),
+            Point2::new(side, 0.0),
+            Point2::new(side, side),
+            Point2::new(0.0, side),
+        ]
+    }
+
+    #[test]
+    fn reports_subpixel_centroid_of_offset_circle() {
+        let side = 64usize;
+        let truth = Point2::new(33.3, 31.6);
+        for (bg, fg, polarity) in [
+            (20.0, 230.0, CirclePolarity::White),
+            (230.0, 20.0, CirclePolarity::Black),
+        ] {
+            let data = render_disk(side, truth, 16.0, bg, fg);
+            let img = GrayImageView {
+                width: side,
+                height: side,
+                data: &data,
+            };
+            let cand = score_circle_in_square(
+                &img,
+                &square(side as f32),
+                CellCoords { i: 0, j: 0 },
+                &CircleScoreParams::default(),
+            )
+            .expect("circle candidate");
+            assert_eq!(cand.polarity, polarity);
+            let err = (cand.center_img - truth).norm();
+            assert!(
+                err < 0.15,
+                "centroid {:?} is {err:.3} px from truth {truth:?}",
+                cand.center_img
+            );
+        }
+    }
+
+    #[test]
+    fn centroid_falls_back_when_disk_mass_is_missing() {
+        // A uniform cell has no disk mass: the centroid is rejected.
+        let side = 64usize;
+        let data = vec![128u8; side * side];
+        let img = GrayImageView {
+            width: side,
+            height: side,
+            data: &data,
+        };
+        let h = homography_from_4pt(&square(side as f32), &square(side as f32)).unwrap();
+        let disk = DiskRegion {
+            center: Point2::new(32.0, 32.0),
+            radius: 16.0,
+            search_radius: 19.0,
+        };
+        assert!(weighted_disk_centroid(&img, &h, &disk, 100.0, -40.0).is_none());
+    }
+
+    #[test]
+    fn radius_range_rejects_wrong_scale_blobs() {
+        let side = 64usize;
+        let center = Point2::new(32.0, 32.0);
+        let params = CircleScoreParams {
+            radius_range_squares: Some((0.18, 0.32)),
+            ..CircleScoreParams::default()
+        };
+        let score = |radius: f32, params: &CircleScoreParams| {
+            let data = render_disk(side, center, radius, 20.0, 230.0);
+            let img = GrayImageView {
+                width: side,
+                height: side,
+                data: &data,
+            };
+            score_circle_in_square(
+                &img,
+                &square(side as f32),
+                CellCoords { i: 0, j: 0 },
+                params,
+            )
+        };
+
+        // The nominal circle: diameter 0.5 squares, radius 16 px = 0.25.
+        assert!(score(16.0, &params).is_some());
+        // A smudge at radius 0.36 squares still contrasts with the ring and
+        // scores without the range, but is rejected with it.
+        assert!(score(23.0, &CircleScoreParams::default()).is_some());
+        assert!(score(23.0, &params).is_none());
+    }
+
+    #[test]
+    fn min_sector_rejects_a_half_occluded_circle() {
+        let side = 64usize;
+        let mut data = render_disk(side, Point2::new(32.0, 32.0), 16.0, 20.0, 230.0);
+        // An occluder the colour of the cell covers the right half.
+        for row in data.chunks_mut(side) {
+            row[side / 2..].fill(20);
+        }
+        let img = GrayImageView {
+            width: side,
+            height: side,
+            data: &data,
+        };
+        let score = |aggregation: ScoreAggregation| {
+            let params = CircleScoreParams {
+                min_contrast: 40.0,
+                aggregation,
+                ..CircleScoreParams::default()
+            };
+            score_circle_in_square(
+                &img,
+                &square(side as f32),
+                CellCoords { i: 0, j: 0 },
+                &params,
+            )
+        };
+
+        let mean = score(ScoreAggregation::Mean).expect("mean accepts the visible half");
+        assert_eq!(mean.polarity, CirclePolarity::White);
+        assert!(mean.contrast > 40.0, "{}", mean.contrast);
+        assert!(score(ScoreAggregation::MinSector { sectors: 8 }).is_none());
+    }
+
+    #[test]
+    fn min_sector_keeps_an_unoccluded_circle() {
+        let side = 64usize;
+        let data = render_disk(side, Point2::new(32.0, 32.0), 16.0, 20.0, 230.0);
+        let img = GrayImageView {
+            width: side,
+            height: side,
+            data: &data,
+        };
+        let params = CircleScoreParams {
+            aggregation: ScoreAggregation::MinSector { sectors: 8 },
+            ..CircleScoreParams::default()
+        };
+        let cand = score_circle_in_square(
+            &img,
+            &square(side as f32),
+            CellCoords { i: 0, j: 0 },
+            &params,
+        )
+        .expect("full circle");
+        assert!(cand.contrast > 150.0, "{}", cand.contrast);
+        assert_eq!(cand.score, cand.contrast);
+    }
+
     /// Workspace-internal exhaustive match — fails to compile when a new
     /// `CirclePolarity` variant is added, prompting an update to every
     /// adapter listed in the [`CirclePolarity`] doc-comment.
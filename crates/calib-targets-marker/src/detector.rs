@@ -1,7 +1,7 @@
 use crate::circle_score::CircleCandidate;
 use crate::detect::{detect_circles_via_square_warp, top_k_by_polarity};
 use crate::diagnostics::MarkerBoardDiagnostics;
-use crate::match_circles::{estimate_grid_alignment, match_expected_circles};
+use crate::match_circles::match_and_align;
 use crate::types::{CircleMatch, MarkerBoardDetectionResult, MarkerBoardParams};
 
 use nalgebra::Point2;
@@ -108,20 +108,12 @@ impl MarkerBoardDetector {
             candidates = [white, black].concat();
         }
 
-        let mut matches = match_expected_circles(
+        let (mut matches, alignment) = match_and_align(
             &self.params.layout.circles,
             &candidates,
             &self.params.match_params,
         );
-        let (alignment, alignment_inliers) = estimate_grid_alignment(
-            &matches,
-            &candidates,
-            self.params.match_params.min_offset_inliers,
-        )
-        .map(|(alignment, inliers)| (Some(alignment), inliers))
-        .unwrap_or((None, 0));
-
-        let alignment = alignment?;
+        let (alignment, alignment_inliers) = alignment?;
         for m in &mut matches {
             let Some(idx) = m.matched_index else {
                 continue;
@@ -209,13 +201,18 @@ impl MarkerBoardDetector {
 
 /// Adapt a [`ChessboardDetection`] into the generic [`TargetDetection`]
 /// the marker pipeline operates on, plus the parallel input-index list
-/// the marker diagnostics expose as `inliers`.
+/// the marker diagnostics expose as `inliers`. Corners without an input
+/// index (added by hand, never by the detector) are skipped so the two
+/// stay parallel.
 fn chessboard_detection_to_target(chess: &ChessboardDetection) -> (TargetDetection, Vec<usize>) {
     let mut corners = Vec::with_capacity(chess.corners.len());
     let mut inliers = Vec::with_capacity(chess.corners.len());
     for c in &chess.corners {
+        let Some(input_index) = c.input_index else {
+            continue;
+        };
         corners.push(LabeledCorner::new(c.position, c.score).with_grid(c.grid));
-        inliers.push(c.input_index);
+        inliers.push(input_index);
     }
     (
         TargetDetection::new(TargetKind::Chessboard, corners),
@@ -231,3 +228,26 @@ fn relabel_as_marker(mut detection: TargetDetection) -> TargetDetection {
 fn build_corner_map(det: &ChessboardDetection) -> CornerMap {
     det.corners.iter().map(|c| (c.grid, c.position)).collect()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use calib_targets_chessboard::ChessboardCorner;
+    use projective_grid::Coord;
+
+    #[test]
+    fn corners_without_an_input_index_stay_out_of_the_inliers() {
+        let mut assigned = ChessboardCorner::new(Point2::new(20.0, 0.0), Coord::new(1, 0), 0, 0.0);
+        assigned.input_index = None;
+        let chess = ChessboardDetection::new(vec![
+            ChessboardCorner::new(Point2::new(0.0, 0.0), Coord::new(0, 0), 4, 0.9),
+            assigned,
+            ChessboardCorner::new(Point2::new(0.0, 20.0), Coord::new(0, 1), 7, 0.8),
+        ]);
+
+        let (target, inliers) = chessboard_detection_to_target(&chess);
+        assert_eq!(inliers, vec![4, 7]);
+        let grids: Vec<_> = target.corners.iter().map(|c| c.grid).collect();
+        assert_eq!(grids, vec![Some(Coord::new(0, 0)), Some(Coord::new(0, 1))]);
+    }
+}
@@ -53,7 +53,7 @@ mod detector;
 
 pub mod diagnostics;
 
-pub use circle_score::{CircleCandidate, CirclePolarity, CircleScoreParams};
+pub use circle_score::{CircleCandidate, CirclePolarity, CircleScoreParams, ScoreAggregation};
 pub use coords::{CellCoords, CellOffset};
 pub use detector::MarkerBoardDetector;
 pub use diagnostics::MarkerBoardDiagnostics;
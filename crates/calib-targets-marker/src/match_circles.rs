@@ -10,6 +10,8 @@ struct MatchOption {
     index: usize,
     distance: f32,
     offset: CellOffset,
+    /// Candidate polarity differs from the layout's (auto-polarity only).
+    polarity_mismatch: bool,
 }
 
 fn distance_cells(a: CellCoords, b: CellCoords) -> f32 {
@@ -25,7 +27,8 @@ fn build_match_options(
 ) -> Vec<MatchOption> {
     let mut out = Vec::new();
     for (idx, cand) in candidates.iter().enumerate() {
-        if cand.polarity != expected.polarity {
+        let polarity_mismatch = cand.polarity != expected.polarity;
+        if polarity_mismatch && !params.auto_polarity {
             continue;
         }
         let dist = distance_cells(expected.cell, cand.cell);
@@ -42,12 +45,37 @@ fn build_match_options(
             index: idx,
             distance: dist,
             offset,
+            polarity_mismatch,
         });
     }
     out
 }
 
-/// Match expected circles to detected candidates, enforcing polarity.
+/// Ranking key for a complete assignment in [`match_expected_circles`].
+#[derive(Clone, Copy, Debug)]
+struct MatchCost {
+    matches: usize,
+    mismatches: usize,
+    total_dist: f32,
+}
+
+impl MatchCost {
+    fn better_than(&self, other: &Self) -> bool {
+        if self.matches != other.matches {
+            return self.matches > other.matches;
+        }
+        if self.mismatches != other.mismatches {
+            return self.mismatches < other.mismatches;
+        }
+        self.total_dist < other.total_dist
+    }
+}
+
+/// Match expected circles to detected candidates, enforcing polarity unless
+/// [`CircleMatchParams::auto_polarity`] is set.
+///
+/// Maximises the number of matches, then minimises polarity mismatches,
+/// then total cell distance.
 pub(crate) fn match_expected_circles(
     expected: &[MarkerCircleSpec],
     candidates: &[CircleCandidate],
@@ -58,7 +86,7 @@ pub(crate) fn match_expected_circles(
         .map(|&spec| build_match_options(spec, candidates, params))
         .collect();
 
-    let mut best: Option<(usize, f32, Vec<Option<MatchOption>>)> = None;
+    let mut best: Option<(MatchCost, Vec<Option<MatchOption>>)> = None;
     let mut current: Vec<Option<MatchOption>> = vec![None; expected.len()];
     let mut used: Vec<bool> = vec![false; candidates.len()];
 
@@ -67,22 +95,20 @@ pub(crate) fn match_expected_circles(
         options: &[Vec<MatchOption>],
         used: &mut [bool],
         current: &mut [Option<MatchOption>],
-        best: &mut Option<(usize, f32, Vec<Option<MatchOption>>)>,
+        best: &mut Option<(MatchCost, Vec<Option<MatchOption>>)>,
     ) {
         if idx == options.len() {
-            let matches = current.iter().filter(|m| m.is_some()).count();
-            let total_dist: f32 = current
-                .iter()
-                .filter_map(|m| m.map(|opt| opt.distance))
-                .sum();
-            let should_take = match best {
-                None => true,
-                Some((best_matches, best_dist, _)) => {
-                    matches > *best_matches || (matches == *best_matches && total_dist < *best_dist)
-                }
+            let assigned = current.iter().flatten();
+            let cost = MatchCost {
+                matches: assigned.clone().count(),
+                mismatches: assigned.clone().filter(|opt| opt.polarity_mismatch).count(),
+                total_dist: assigned.map(|opt| opt.distance).sum(),
             };
+            let should_take = best
+                .as_ref()
+                .is_none_or(|(best_cost, _)| cost.better_than(best_cost));
             if should_take {
-                *best = Some((matches, total_dist, current.to_vec()));
+                *best = Some((cost, current.to_vec()));
             }
             return;
         }
@@ -105,7 +131,7 @@ pub(crate) fn match_expected_circles(
     search(0, &options, &mut used, &mut current, &mut best);
 
     let assignments = best
-        .map(|(_, _, assign)| assign)
+        .map(|(_, assign)| assign)
         .unwrap_or_else(|| vec![None; expected.len()]);
 
     expected
@@ -120,6 +146,52 @@ pub(crate) fn match_expected_circles(
         .collect()
 }
 
+/// Match expected circles under every dihedral orientation of the detected
+/// grid and keep the orientation whose matches align best.
+///
+/// [`match_expected_circles`] pairs circles by cell distance, which only
+/// finds the right pairing when the detected grid is already oriented like
+/// the board; a half-turned board would pair each circle with its mirror
+/// image. Matching candidates re-expressed through each
+/// [`GRID_TRANSFORMS_D4`] element resolves the orientation — including the
+/// 180° up/down ambiguity — from the circle arrangement itself. Ties keep
+/// the earlier transform, so an upright board stays on the identity.
+///
+/// The returned matches index into `candidates`; their distances are
+/// measured in the winning orientation.
+pub(crate) fn match_and_align(
+    expected: &[MarkerCircleSpec],
+    candidates: &[CircleCandidate],
+    params: &CircleMatchParams,
+) -> (Vec<CircleMatch>, Option<(GridAlignment, usize)>) {
+    let mut best: Option<(Vec<CircleMatch>, GridAlignment, usize)> = None;
+    for transform in GRID_TRANSFORMS_D4 {
+        let oriented: Vec<CircleCandidate> = candidates
+            .iter()
+            .map(|c| {
+                let r = transform.apply(c.cell.i, c.cell.j);
+                CircleCandidate {
+                    cell: CellCoords { i: r.u, j: r.v },
+                    ..*c
+                }
+            })
+            .collect();
+        let matches = match_expected_circles(expected, &oriented, params);
+        let Some((alignment, inliers)) =
+            estimate_grid_alignment(&matches, candidates, params.min_offset_inliers)
+        else {
+            continue;
+        };
+        if best.as_ref().is_none_or(|(_, _, n)| inliers > *n) {
+            best = Some((matches, alignment, inliers));
+        }
+    }
+    match best {
+        Some((matches, alignment, inliers)) => (matches, Some((alignment, inliers))),
+        None => (match_expected_circles(expected, candidates, params), None),
+    }
+}
+
 /// Estimate a dihedral alignment from detected cell coordinates to board cell coordinates.
 ///
 /// The returned alignment maps `(cell_i, cell_j)` from the detected grid coordinate system into
@@ -251,6 +323,7 @@ mod tests {
             max_candidates_per_polarity: 6,
             max_distance_cells: Some(0.1),
             min_offset_inliers: 1,
+            auto_polarity: false,
         };
 
         let matches = match_expected_circles(&expected, &candidates, &params);
@@ -258,6 +331,59 @@ mod tests {
         assert_eq!(matched, vec![Some(0), Some(1), Some(2)]);
     }
 
+    #[test]
+    fn auto_polarity_matches_layout_with_wrong_polarity_specs() {
+        // The printed board is White/Black/White; the layout claims all Black.
+        let expected = [(5, 5), (6, 5), (6, 6)].map(|(i, j)| MarkerCircleSpec {
+            cell: CellCoords { i, j },
+            polarity: CirclePolarity::Black,
+        });
+        let candidates = vec![
+            candidate(CellCoords { i: 5, j: 5 }, CirclePolarity::White),
+            candidate(CellCoords { i: 6, j: 5 }, CirclePolarity::Black),
+            candidate(CellCoords { i: 6, j: 6 }, CirclePolarity::White),
+        ];
+        let mut params = CircleMatchParams {
+            max_candidates_per_polarity: 6,
+            max_distance_cells: Some(0.1),
+            min_offset_inliers: 1,
+            auto_polarity: false,
+        };
+
+        let matched = |params: &CircleMatchParams| -> Vec<Option<usize>> {
+            match_expected_circles(&expected, &candidates, params)
+                .iter()
+                .map(|m| m.matched_index)
+                .collect()
+        };
+        assert_eq!(matched(&params), vec![None, Some(1), None]);
+
+        params.auto_polarity = true;
+        assert_eq!(matched(&params), vec![Some(0), Some(1), Some(2)]);
+    }
+
+    #[test]
+    fn auto_polarity_prefers_polarity_consistent_assignment() {
+        // Two candidates equally far from the expected cell: the one with the
+        // layout's polarity wins.
+        let expected = [MarkerCircleSpec {
+            cell: CellCoords { i: 5, j: 5 },
+            polarity: CirclePolarity::White,
+        }];
+        let candidates = vec![
+            candidate(CellCoords { i: 4, j: 5 }, CirclePolarity::Black),
+            candidate(CellCoords { i: 6, j: 5 }, CirclePolarity::White),
+        ];
+        let params = CircleMatchParams {
+            max_candidates_per_polarity: 6,
+            max_distance_cells: None,
+            min_offset_inliers: 1,
+            auto_polarity: true,
+        };
+        let matches = match_expected_circles(&expected, &candidates, &params);
+        assert_eq!(matches[0].matched_index, Some(1));
+    }
+
     fn candidate_with_contrast(
         cell: CellCoords,
         polarity: CirclePolarity,
@@ -1,7 +1,9 @@
 use serde::{Deserialize, Serialize};
 
 use calib_targets_chessboard::DetectorParams;
-use calib_targets_core::{Coord, GridAlignment, LabeledCorner, TargetDetection, TargetKind};
+use calib_targets_core::{
+    Coord, DetectionSummary, GridAlignment, LabeledCorner, Summarize, TargetDetection, TargetKind,
+};
 use nalgebra::Point2;
 
 use crate::circle_score::{CirclePolarity, CircleScoreParams};
@@ -97,6 +99,13 @@ pub struct CircleMatchParams {
     pub max_distance_cells: Option<f32>,
     /// Minimum number of consistent matches needed to return a grid alignment.
     pub min_offset_inliers: usize,
+    /// If true, a candidate may match an expected circle of either polarity,
+    /// using the polarity it was scored with. Assignments that agree with
+    /// the layout's polarities still win ties. Tolerates layouts with wrong
+    /// polarity specs, but a layout whose orientation is only fixed by
+    /// polarity becomes ambiguous.
+    #[serde(default)]
+    pub auto_polarity: bool,
 }
 
 impl Default for CircleMatchParams {
@@ -105,6 +114,7 @@ impl Default for CircleMatchParams {
             max_candidates_per_polarity: 6,
             max_distance_cells: None,
             min_offset_inliers: 1,
+            auto_polarity: false,
         }
     }
 }
@@ -256,6 +266,31 @@ impl MarkerBoardDetectionResult {
     pub fn aligned_detection(&self) -> Option<TargetDetection> {
         self.alignment.map(|_| self.target_detection())
     }
+
+    /// Whether the board appears upside-down in the image, as resolved by
+    /// the circle match; `None` without an alignment.
+    ///
+    /// The chessboard stage labels the detected grid with `+u` toward image
+    /// `+x` and `+v` toward image `+y`; the board is upside-down when its
+    /// own `+v` axis points up the image. For the full orientation use
+    /// [`GridAlignment::transform`] on [`Self::alignment`].
+    pub fn is_upside_down(&self) -> Option<bool> {
+        let to_detected = self.alignment?.transform.inverse()?;
+        Some(to_detected.apply(0, 1).v < 0)
+    }
+}
+
+impl Summarize for MarkerBoardDetectionResult {
+    /// Corner summary with the board alignment when resolved. The matched
+    /// circles live in the diagnostics, not the result, so `num_markers`
+    /// is `0`.
+    fn summary(&self) -> DetectionSummary {
+        let summary = DetectionSummary::from_detection(&self.target_detection());
+        match self.alignment {
+            Some(alignment) => summary.with_transform(alignment),
+            None => summary,
+        }
+    }
 }
 
 /// A detected marker-board checkerboard corner.
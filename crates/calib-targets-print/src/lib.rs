@@ -11,7 +11,7 @@ pub use model::{
     PuzzleBoardTargetSpec, RenderOptions, ResolvedTargetLayout, ResolvedTargetPoint, StemPaths,
     TargetSpec,
 };
-pub use render::{render_target_bundle, GeneratedTargetBundle};
+pub use render::{render_board_image, render_target_bundle, GeneratedTargetBundle};
 
 use std::{
     fs,
@@ -31,6 +31,9 @@ pub enum PrintableTargetError {
     /// The requested PNG render resolution is not strictly positive.
     #[error("png_dpi must be > 0")]
     InvalidPngDpi,
+    /// The requested board raster resolution is not strictly positive.
+    #[error("px_per_square must be > 0")]
+    InvalidPxPerSquare,
     /// A chessboard spec requests fewer than 2 inner rows or columns.
     #[error("inner_rows and inner_cols must be >= 2")]
     InvalidChessboardSize,
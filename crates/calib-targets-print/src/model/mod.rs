@@ -29,7 +29,7 @@ pub(crate) use puzzleboard::validate_puzzleboard_spec;
 
 use error::SCHEMA_VERSION_V1;
 
-use calib_targets_core::Coord;
+use calib_targets_core::{BoardConvention, Coord};
 use calib_targets_marker::MarkerBoardSpec;
 use calib_targets_puzzleboard::MASTER_COLS;
 use serde::{Deserialize, Serialize};
@@ -110,6 +110,16 @@ impl TargetSpec {
         }
     }
 
+    /// Side length of one board square, in millimeters.
+    pub fn square_size_mm(&self) -> f64 {
+        match self {
+            Self::Chessboard(spec) => spec.square_size_mm,
+            Self::Charuco(spec) => spec.square_size_mm,
+            Self::MarkerBoard(spec) => spec.square_size_mm,
+            Self::PuzzleBoard(spec) => spec.square_size_mm,
+        }
+    }
+
     /// Compute board-space coordinates for every output point.
     pub fn resolved_points(&self) -> Result<Vec<ResolvedTargetPoint>, PrintableTargetError> {
         match self {
@@ -197,6 +207,30 @@ impl TargetSpec {
             }
         }
     }
+
+    /// [`Self::resolved_points`] with grid labels on the board's
+    /// square-corner lattice in `convention` — the frame the board detectors
+    /// report, where the canonical first inner corner is `(1, 1)` rather
+    /// than `(0, 0)`. PuzzleBoard labels are master-absolute and pass
+    /// through unchanged.
+    pub fn resolved_points_in(
+        &self,
+        convention: BoardConvention,
+    ) -> Result<Vec<ResolvedTargetPoint>, PrintableTargetError> {
+        let mut points = self.resolved_points()?;
+        if matches!(self, Self::PuzzleBoard(_)) {
+            return Ok(points);
+        }
+        let (width_mm, height_mm) = self.board_size_mm()?;
+        let square_mm = self.square_size_mm();
+        let cols = (width_mm / square_mm).round() as u32 + 1;
+        let rows = (height_mm / square_mm).round() as u32 + 1;
+        let relabel = convention.alignment(cols, rows);
+        for grid in points.iter_mut().filter_map(|p| p.grid.as_mut()) {
+            *grid = relabel.map(grid.u + 1, grid.v + 1);
+        }
+        Ok(points)
+    }
 }
 
 /// Top-level printable target document (the JSON file on disk).
@@ -4,6 +4,7 @@ use crate::model::{
     PrintableTargetError, PuzzleBoardTargetSpec, RenderOptions, ResolvedTargetLayout, TargetSpec,
 };
 use calib_targets_charuco::CharucoBoard;
+use calib_targets_core::GrayImage;
 use calib_targets_marker::CirclePolarity;
 use calib_targets_puzzleboard::code_maps;
 use png::{BitDepth, ColorType, Encoder, PixelDimensions, Unit};
@@ -118,7 +119,7 @@ pub fn render_target_bundle(
         height_mm: layout.page_height_mm,
         fill: Fill::White,
     });
-    build_board_scene(&mut scene, document, &layout)?;
+    build_board_scene(&mut scene, &document.target, &layout)?;
     // DXF must never carry debug annotations — render it from the
     // pre-debug scene snapshot so a hardware handoff file is always
     // pattern-only, even when the SVG/PNG render is annotated.
@@ -134,12 +135,45 @@ pub fn render_target_bundle(
     ))
 }
 
+/// Rasterize the board of `target` alone — no page, margins or debug
+/// annotations — at `px_per_square` pixels per board square.
+///
+/// The image spans exactly the board area, so a ChArUco board of
+/// `rows × cols` squares comes out `rows * px_per_square` pixels tall and
+/// `cols * px_per_square` wide. Useful for synthetic-detection tests and
+/// for compositing the board into a scene of your own.
+pub fn render_board_image(
+    target: &TargetSpec,
+    px_per_square: u32,
+) -> Result<GrayImage, PrintableTargetError> {
+    if px_per_square == 0 {
+        return Err(PrintableTargetError::InvalidPxPerSquare);
+    }
+    let (board_width_mm, board_height_mm) = target.board_size_mm()?;
+    let layout = ResolvedTargetLayout {
+        page_width_mm: board_width_mm,
+        page_height_mm: board_height_mm,
+        board_origin_mm: [0.0, 0.0],
+        board_width_mm,
+        board_height_mm,
+        points: Vec::new(),
+    };
+    let mut scene = Scene::new(board_width_mm, board_height_mm);
+    build_board_scene(&mut scene, target, &layout)?;
+    let (width, height, data) = rasterize(&scene, px_per_square as f64 / target.square_size_mm());
+    Ok(GrayImage {
+        width,
+        height,
+        data,
+    })
+}
+
 fn build_board_scene(
     scene: &mut Scene,
-    document: &PrintableTargetDocument,
+    target: &TargetSpec,
     layout: &ResolvedTargetLayout,
 ) -> Result<(), PrintableTargetError> {
-    match &document.target {
+    match target {
         TargetSpec::Chessboard(spec) => build_chessboard(scene, spec, layout),
         TargetSpec::Charuco(spec) => build_charuco(scene, spec, layout),
         TargetSpec::MarkerBoard(spec) => build_marker_board(scene, spec, layout),
@@ -224,7 +258,7 @@ fn build_charuco(
                 } else {
                     let bx = cx - spec.border_bits;
                     let by = cy - spec.border_bits;
-                    let idx = by * bits + bx;
+                    let idx = spec.dictionary.bit_order().bit_index(bx, by, bits);
                     ((code >> idx) & 1) == 1
                 };
                 scene.primitives.push(Primitive::Rect {
@@ -476,7 +510,25 @@ fn render_svg(scene: &Scene) -> String {
 }
 
 fn render_png(scene: &Scene, options: &RenderOptions) -> Result<Vec<u8>, PrintableTargetError> {
-    let px_per_mm = options.png_dpi as f64 / 25.4;
+    let (width_px, height_px, data) = rasterize(scene, options.png_dpi as f64 / 25.4);
+    let mut bytes = Vec::new();
+    let mut encoder = Encoder::new(&mut bytes, width_px as u32, height_px as u32);
+    encoder.set_color(ColorType::Grayscale);
+    encoder.set_depth(BitDepth::Eight);
+    encoder.set_pixel_dims(Some(PixelDimensions {
+        xppu: (options.png_dpi as f64 / 25.4 * 1000.0).round() as u32,
+        yppu: (options.png_dpi as f64 / 25.4 * 1000.0).round() as u32,
+        unit: Unit::Meter,
+    }));
+    let mut writer = encoder.write_header()?;
+    writer.write_image_data(&data)?;
+    writer.finish()?;
+    Ok(bytes)
+}
+
+/// Rasterize `scene` onto a white 8-bit canvas; returns
+/// `(width_px, height_px, row-major pixels)`.
+fn rasterize(scene: &Scene, px_per_mm: f64) -> (usize, usize, Vec<u8>) {
     let width_px = (scene.width_mm * px_per_mm).round().max(1.0) as usize;
     let height_px = (scene.height_mm * px_per_mm).round().max(1.0) as usize;
     let mut data = vec![255u8; width_px * height_px];
@@ -510,20 +562,7 @@ fn render_png(scene: &Scene, options: &RenderOptions) -> Result<Vec<u8>, Printab
             } => fill_circle(&mut canvas, [*cx_mm, *cy_mm], *radius_mm, fill.gray()),
         }
     }
-
-    let mut bytes = Vec::new();
-    let mut encoder = Encoder::new(&mut bytes, width_px as u32, height_px as u32);
-    encoder.set_color(ColorType::Grayscale);
-    encoder.set_depth(BitDepth::Eight);
-    encoder.set_pixel_dims(Some(PixelDimensions {
-        xppu: (options.png_dpi as f64 / 25.4 * 1000.0).round() as u32,
-        yppu: (options.png_dpi as f64 / 25.4 * 1000.0).round() as u32,
-        unit: Unit::Meter,
-    }));
-    let mut writer = encoder.write_header()?;
-    writer.write_image_data(&data)?;
-    writer.finish()?;
-    Ok(bytes)
+    (width_px, height_px, data)
 }
 
 struct RasterCanvas<'a> {
@@ -679,4 +718,29 @@ mod tests {
         let rect_count = bundle.svg_text.matches("<rect ").count();
         assert!(rect_count > 35);
     }
+
+    #[test]
+    fn board_image_spans_exactly_the_board_squares() {
+        let target = TargetSpec::Charuco(CharucoTargetSpec {
+            rows: 5,
+            cols: 7,
+            square_size_mm: 15.0,
+            marker_size_rel: 0.75,
+            dictionary: builtins::builtin_dictionary("DICT_4X4_50").expect("dict"),
+            marker_layout: MarkerLayout::OpenCvCharuco,
+            border_bits: 1,
+        });
+        let image = render_board_image(&target, 40).expect("board image");
+        assert_eq!((image.width, image.height), (7 * 40, 5 * 40));
+        assert_eq!(image.data.len(), image.width * image.height);
+        // Square (0, 0) is solid black; square (1, 0) carries a marker
+        // inside a white quiet ring.
+        assert_eq!(image.data[2 * image.width + 2], 0);
+        assert_eq!(image.data[2 * image.width + 40 + 2], 255);
+
+        assert!(matches!(
+            render_board_image(&target, 0),
+            Err(PrintableTargetError::InvalidPxPerSquare)
+        ));
+    }
 }
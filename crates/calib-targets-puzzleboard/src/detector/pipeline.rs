@@ -342,7 +342,10 @@ impl PuzzleBoardDetector {
             );
         }
 
-        let detection = TargetDetection::new(TargetKind::PuzzleBoard, out_corners);
+        let mut detection = TargetDetection::new(TargetKind::PuzzleBoard, out_corners);
+        // The D4 alignment reorders rows and columns; report in master
+        // row-major order like the other detectors.
+        detection.sort_by_grid();
         let scoring_mode = self.params.decode.scoring_mode;
         // Soft-only score fields are `None` under hard-weighted scoring.
         let (score_best, score_margin) = match scoring_mode {
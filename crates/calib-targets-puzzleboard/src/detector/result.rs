@@ -1,6 +1,8 @@
 //! Detector output types.
 
-use calib_targets_core::{Coord, GridAlignment, LabeledCorner, TargetDetection, TargetKind};
+use calib_targets_core::{
+    Coord, DetectionSummary, GridAlignment, LabeledCorner, Summarize, TargetDetection, TargetKind,
+};
 use nalgebra::Point2;
 use serde::Serialize;
 
@@ -91,7 +93,8 @@ pub struct PuzzleBoardDecodeInfo {
 #[non_exhaustive]
 #[derive(Clone, Debug, Serialize)]
 pub struct PuzzleBoardDetectionResult {
-    /// Labelled corners in absolute master-board coordinates.
+    /// Labelled corners in absolute master-board coordinates, sorted
+    /// row-major by `(v, u)`.
     pub corners: Vec<PuzzleBoardCorner>,
     /// Alignment from the detected local grid into master-board coordinates.
     pub alignment: GridAlignment,
@@ -140,3 +143,11 @@ impl PuzzleBoardDetectionResult {
         )
     }
 }
+
+impl Summarize for PuzzleBoardDetectionResult {
+    /// Corner summary with the master-board alignment; PuzzleBoard labels
+    /// come from edge bits, not markers, so `num_markers` is `0`.
+    fn summary(&self) -> DetectionSummary {
+        DetectionSummary::from_detection(&self.target_detection()).with_transform(self.alignment)
+    }
+}
@@ -1,6 +1,7 @@
 from __future__ import annotations
 
 from .api import (
+    CharucoDetector,
     detect_charuco,
     detect_charuco_best,
     detect_charuco_with_diagnostics,
@@ -15,9 +16,12 @@ from .api import (
     detect_puzzleboard,
     detect_puzzleboard_best,
     detect_puzzleboard_with_diagnostics,
+    set_log_callback,
+    set_log_level,
 )
 from .config import (
     AxisClusterCenters,
+    BoardConvention,
     CenterOfMassConfig,
     CharucoBoardSpec,
     CharucoDetectorParams,
@@ -48,6 +52,7 @@ from .config import (
     RefinerConfig,
     SaddlePointConfig,
     ScanDecodeConfig,
+    ScoreAggregation,
     Threshold,
     TopologicalParams,
     UpscaleConfig,
@@ -71,14 +76,18 @@ from .printing import (
     chessboard_document,
     marker_board_document,
     puzzleboard_document,
+    render_charuco_board,
+    render_marker_board,
     render_target_bundle,
     write_target_bundle,
 )
 from .enums import (
     DICTIONARY_NAMES,
     DictionaryName,
+    BoardOrigin,
     CirclePolarity,
     MarkerLayout,
+    SelectionMode,
     TargetKind,
 )
 from .results import (
@@ -114,6 +123,7 @@ from .results import (
 )
 
 __all__ = [
+    "CharucoDetector",
     "detect_charuco",
     "detect_charuco_best",
     "detect_charuco_with_diagnostics",
@@ -128,11 +138,15 @@ __all__ = [
     "detect_puzzleboard",
     "detect_puzzleboard_best",
     "detect_puzzleboard_with_diagnostics",
+    "set_log_callback",
+    "set_log_level",
     "DICTIONARY_NAMES",
     "DictionaryName",
     "TargetKind",
     "CirclePolarity",
     "MarkerLayout",
+    "BoardOrigin",
+    "SelectionMode",
     "CenterOfMassConfig",
     "ForstnerConfig",
     "SaddlePointConfig",
@@ -155,11 +169,13 @@ __all__ = [
     "ChessboardParams",
     "ScanDecodeConfig",
     "CharucoBoardSpec",
+    "BoardConvention",
     "CharucoParams",
     "CharucoDetectorParams",
     "MarkerCircleSpec",
     "MarkerBoardSpec",
     "MarkerBoardLayout",
+    "ScoreAggregation",
     "CircleScoreParams",
     "CircleMatchParams",
     "MarkerBoardParams",
@@ -183,6 +199,8 @@ __all__ = [
     "WrittenTargetBundle",
     "render_target_bundle",
     "write_target_bundle",
+    "render_charuco_board",
+    "render_marker_board",
     "chessboard_document",
     "charuco_document",
     "puzzleboard_document",
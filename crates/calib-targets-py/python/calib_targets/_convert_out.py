@@ -258,7 +258,7 @@ def grid_alignment_from_dict(data: Mapping[str, Any]) -> GridAlignment:
 
 
 def labeled_corner_to_dict(value: LabeledCorner) -> dict[str, Any]:
-    return {
+    out: dict[str, Any] = {
         "position": _point2_to_list(value.position),
         "grid": coord_to_dict(value.grid) if value.grid is not None else None,
         "id": int(value.id) if value.id is not None else None,
@@ -267,13 +267,23 @@ def labeled_corner_to_dict(value: LabeledCorner) -> dict[str, Any]:
         else None,
         "score": float(value.score),
     }
+    if value.interpolated:
+        out["interpolated"] = True
+    return out
 
 
 def labeled_corner_from_dict(data: Mapping[str, Any]) -> LabeledCorner:
     obj = _ensure_mapping(data, "LabeledCorner")
     _validate_keys(
         obj,
-        allowed={"position", "grid", "id", "target_position", "score"},
+        allowed={
+            "position",
+            "grid",
+            "id",
+            "target_position",
+            "score",
+            "interpolated",
+        },
         required={"position", "grid", "id", "target_position", "score"},
         ctx="LabeledCorner",
     )
@@ -287,6 +297,9 @@ def labeled_corner_from_dict(data: Mapping[str, Any]) -> LabeledCorner:
             obj["target_position"], "LabeledCorner.target_position"
         ),
         score=_to_float(obj["score"], "LabeledCorner.score"),
+        interpolated=_to_bool(
+            obj.get("interpolated", False), "LabeledCorner.interpolated"
+        ),
     )
 
 
@@ -426,7 +439,7 @@ def chessboard_corner_to_dict(value: ChessboardCorner) -> dict[str, Any]:
     return {
         "position": _point2_to_list(value.position),
         "grid": coord_to_dict(value.grid),
-        "input_index": int(value.input_index),
+        "input_index": int(value.input_index) if value.input_index is not None else None,
         "score": float(value.score),
     }
 
@@ -441,10 +454,13 @@ def chessboard_corner_from_dict(data: Mapping[str, Any]) -> ChessboardCorner:
         required={"position", "grid", "input_index", "score"},
         ctx="ChessboardCorner",
     )
+    input_index = obj["input_index"]
     return ChessboardCorner(
         position=_to_point2(obj["position"], "ChessboardCorner.position"),
         grid=coord_from_dict(obj["grid"]),
-        input_index=_to_int(obj["input_index"], "ChessboardCorner.input_index"),
+        input_index=_to_int(input_index, "ChessboardCorner.input_index")
+        if input_index is not None
+        else None,
         score=_to_float(obj["score"], "ChessboardCorner.score"),
     )
 
@@ -496,6 +512,7 @@ def marker_detection_to_dict(value: MarkerDetection) -> dict[str, Any]:
         "score": float(value.score),
         "border_score": float(value.border_score),
         "code": int(value.code),
+        "matched_code": int(value.matched_code) if value.matched_code is not None else None,
         "inverted": bool(value.inverted),
         "corners_rect": _corners4_to_list(value.corners_rect),
         "corners_img": _corners4_to_list(value.corners_img)
@@ -516,6 +533,7 @@ def marker_detection_from_dict(data: Mapping[str, Any]) -> MarkerDetection:
             "score",
             "border_score",
             "code",
+            "matched_code",
             "inverted",
             "corners_rect",
             "corners_img",
@@ -535,6 +553,7 @@ def marker_detection_from_dict(data: Mapping[str, Any]) -> MarkerDetection:
         ctx="MarkerDetection",
     )
     corners_img_raw = obj["corners_img"]
+    matched_code_raw = obj.get("matched_code")
     return MarkerDetection(
         id=_to_int(obj["id"], "MarkerDetection.id"),
         gc=coord_from_dict(obj["gc"]),
@@ -548,6 +567,9 @@ def marker_detection_from_dict(data: Mapping[str, Any]) -> MarkerDetection:
         corners_img=_to_corners4(corners_img_raw, "MarkerDetection.corners_img")
         if corners_img_raw is not None
         else None,
+        matched_code=_to_int(matched_code_raw, "MarkerDetection.matched_code")
+        if matched_code_raw is not None
+        else None,
     )
 
 
@@ -669,6 +691,7 @@ def charuco_detection_result_to_dict(value: CharucoDetectionResult) -> dict[str,
         "corners": [charuco_corner_to_dict(item) for item in value.corners],
         "markers": [marker_detection_to_dict(item) for item in value.markers],
         "alignment": grid_alignment_to_dict(value.alignment),
+        "alignment_residual_cells": float(value.alignment_residual_cells),
     }
 
 
@@ -680,6 +703,7 @@ def charuco_detection_result_from_dict(data: Mapping[str, Any]) -> CharucoDetect
             "corners",
             "markers",
             "alignment",
+            "alignment_residual_cells",
         },
         required={"corners", "markers", "alignment"},
         ctx="CharucoDetectionResult",
@@ -690,6 +714,10 @@ def charuco_detection_result_from_dict(data: Mapping[str, Any]) -> CharucoDetect
         corners=[charuco_corner_from_dict(item) for item in corners],
         markers=[marker_detection_from_dict(item) for item in markers],
         alignment=grid_alignment_from_dict(obj["alignment"]),
+        alignment_residual_cells=_to_float(
+            obj.get("alignment_residual_cells", 0.0),
+            "CharucoDetectionResult.alignment_residual_cells",
+        ),
     )
 
 
@@ -3,6 +3,11 @@ from __future__ import annotations
 from typing import Any
 
 def detect_charuco(image, *, chess_cfg=None, params) -> dict[str, Any]: ...
+
+class CharucoDetector:
+    def __init__(self, *, chess_cfg=None, params) -> None: ...
+    def detect(self, image) -> dict[str, Any]: ...
+
 def detect_chessboard(image, *, chess_cfg=None, params=None) -> dict[str, Any] | None: ...
 def detect_chessboard_all(image, *, chess_cfg=None, params=None) -> Any: ...
 def trace_chessboard_topological(image, *, chess_cfg=None, params=None) -> Any: ...
@@ -18,3 +23,8 @@ def detect_puzzleboard_best(image, configs) -> dict[str, Any]: ...
 def default_puzzleboard_params(rows, cols) -> dict[str, Any]: ...
 def render_target_bundle(document) -> dict[str, Any]: ...
 def write_target_bundle(document, output_stem) -> dict[str, Any]: ...
+def render_charuco_board(board, px_per_square) -> Any: ...
+def render_marker_board(layout, px_per_square) -> Any: ...
+def set_log_level(level) -> None: ...
+def set_log_callback(callback) -> None: ...
+def _conversion_benchmark(corners, via_json) -> Any: ...
@@ -1,5 +1,6 @@
 from __future__ import annotations
 
+from collections.abc import Callable
 from typing import Any
 
 import numpy as np
@@ -117,6 +118,35 @@ def detect_charuco(
     return CharucoDetectionResult.from_dict(raw)
 
 
+class CharucoDetector:
+    """Reusable ChArUco detector for processing many frames.
+
+    The params are validated and the board and marker matcher are built once,
+    in the constructor; :meth:`detect` reuses them for every frame. Use
+    :func:`detect_charuco` for one-shot detection.
+    """
+
+    __slots__ = ("_inner",)
+
+    def __init__(
+        self,
+        *,
+        chess_cfg: ChessConfig | None = None,
+        params: CharucoDetectorParams,
+    ) -> None:
+        if chess_cfg is not None:
+            _check_type("chess_cfg", chess_cfg, ChessConfig)
+        _check_type("params", params, CharucoDetectorParams)
+
+        self._inner = _core.CharucoDetector(
+            chess_cfg=chess_config_to_payload(chess_cfg),
+            params=charuco_detector_params_to_payload(params),
+        )
+
+    def detect(self, image: npt.NDArray[np.uint8]) -> CharucoDetectionResult:
+        return CharucoDetectionResult.from_dict(self._inner.detect(image))
+
+
 def detect_marker_board(
     image: npt.NDArray[np.uint8],
     *,
@@ -315,6 +345,30 @@ def default_puzzleboard_params(rows: int, cols: int) -> PuzzleBoardParams:
     return PuzzleBoardParams.from_dict(raw)
 
 
+def set_log_level(level: str) -> None:
+    """Set the level of Rust-side log records.
+
+    ``level`` is one of ``"off"``, ``"error"``, ``"warn"``, ``"info"``,
+    ``"debug"``, ``"trace"``. Records go to stderr unless a callback is
+    installed with :func:`set_log_callback`.
+    """
+    _check_type("level", level, str)
+    _core.set_log_level(level)
+
+
+def set_log_callback(callback: Callable[[str, str, str], None] | None) -> None:
+    """Route Rust-side log records to ``callback(level, message, target)``.
+
+    ``level`` is the upper-case level name (e.g. ``"WARN"``) and ``target``
+    the emitting Rust module path. Detector rejection reasons are logged at
+    ``WARN``. Pass ``None`` to restore stderr output. Enables ``"info"``
+    logging if no level was set with :func:`set_log_level`.
+    """
+    if callback is not None and not callable(callback):
+        raise TypeError(f"callback must be callable, got {type(callback).__name__}")
+    _core.set_log_callback(callback)
+
+
 __all__ = [
     "detect_chessboard",
     "detect_chessboard_all",
@@ -330,4 +384,6 @@ __all__ = [
     "detect_marker_board_best",
     "detect_puzzleboard_best",
     "default_puzzleboard_params",
+    "set_log_callback",
+    "set_log_level",
 ]
@@ -9,7 +9,7 @@ from __future__ import annotations
 from dataclasses import dataclass, field
 from typing import Any
 
-from .enums import CirclePolarity, DictionaryName, MarkerLayout
+from .enums import BoardOrigin, CirclePolarity, DictionaryName, MarkerLayout, SelectionMode
 
 
 # ---------------------------------------------------------------------------
@@ -900,12 +900,15 @@ class LocalMergeParams:
 # deserialize with ``missing field ...``.
 _ADVANCED_SCALAR_FIELDS = (
     "max_fit_rms_ratio",
+    "normalize_strength",
+    "strength_percentile",
     "num_bins",
     "max_iters_2means",
     "cluster_tol_deg",
     "cluster_sigma_k",
     "peak_min_separation_deg",
     "min_peak_weight_fraction",
+    "smooth_sigma_bins",
     "attach_search_rel",
     "attach_axis_tol_deg",
     "attach_ambiguity_factor",
@@ -917,6 +920,8 @@ _ADVANCED_SCALAR_FIELDS = (
     "geometry_check_line_tol_rel",
     "geometry_check_local_h_tol_rel",
     "line_min_members",
+    "min_grid_lines",
+    "max_grid_lines",
     "validate_step_aware",
     "enable_final_edge_shape_check",
 )
@@ -966,6 +971,8 @@ class ChessboardParams:
     component_merge: LocalMergeParams = field(default_factory=LocalMergeParams)
     # prefilter
     max_fit_rms_ratio: float = 0.5
+    normalize_strength: bool = False
+    strength_percentile: float | None = None
     # cluster_axes
     num_bins: int = 90
     max_iters_2means: int = 10
@@ -973,6 +980,7 @@ class ChessboardParams:
     cluster_sigma_k: float = 0.0
     peak_min_separation_deg: float = 60.0
     min_peak_weight_fraction: float = 0.02
+    smooth_sigma_bins: float = 1.0
     # recall boosters (grow / line extrapolation)
     attach_search_rel: float = 0.35
     attach_axis_tol_deg: float = 15.0
@@ -986,8 +994,12 @@ class ChessboardParams:
     geometry_check_line_tol_rel: float = 0.45
     geometry_check_local_h_tol_rel: float = 0.6
     line_min_members: int = 3
+    min_grid_lines: int = 2
+    max_grid_lines: int = 0
     validate_step_aware: bool = False
     enable_final_edge_shape_check: bool = True
+    # output
+    selection: SelectionMode = SelectionMode.MOST_CORNERS
     # --- Python-side convenience carrier (NOT part of the wire shape) -------
     chess: ChessConfig = field(default_factory=ChessConfig)
 
@@ -1008,6 +1020,7 @@ class ChessboardParams:
         }
         for name in _ADVANCED_SCALAR_FIELDS:
             advanced[name] = getattr(self, name)
+        advanced["selection"] = SelectionMode(self.selection).value
         return advanced
 
     def to_dict(self) -> dict[str, Any]:
@@ -1048,6 +1061,7 @@ class ChessboardParams:
         }
         for name in _ADVANCED_SCALAR_FIELDS:
             kwargs[name] = advanced.get(name, getattr(d, name))
+        kwargs["selection"] = SelectionMode(advanced.get("selection", d.selection))
         return cls(**kwargs)
 
 
@@ -1115,6 +1129,24 @@ class CharucoBoardSpec:
             marker_layout=MarkerLayout(data.get("marker_layout", "opencv_charuco")),
         )
 
+@dataclass(slots=True)
+class BoardConvention:
+    """Board corner holding grid ``(0, 0)``; mirrors Rust ``BoardConvention``.
+
+    Labels sit on the square-corner lattice, so the canonical first inner
+    corner is ``(1, 1)``. Corner ids keep the printed board's frame.
+    """
+
+    origin: BoardOrigin = BoardOrigin.TOP_LEFT
+
+    def to_dict(self) -> dict[str, Any]:
+        return {"origin": self.origin.value}
+
+    @classmethod
+    def from_dict(cls, data: dict[str, Any]) -> BoardConvention:
+        return cls(origin=BoardOrigin(data.get("origin", "top_left")))
+
+
 def _charuco_chessboard_default() -> ChessboardParams:
     params = ChessboardParams()
     params.enable_final_edge_shape_check = False
@@ -1131,6 +1163,10 @@ class CharucoParams:
     scan: ScanDecodeConfig = field(default_factory=ScanDecodeConfig)
     min_marker_inliers: int = 3
     min_secondary_marker_inliers: int | None = None
+    min_distinct_marker_ids: int | None = None
+    min_marker_score: float | None = None
+    reject_on_duplicate_labels: bool | None = None
+    convention: BoardConvention | None = None
     grid_smoothness_threshold_rel: float | None = None
     corner_validation_threshold_rel: float | None = None
 
@@ -1144,6 +1180,14 @@ class CharucoParams:
         }
         if self.min_secondary_marker_inliers is not None:
             d["min_secondary_marker_inliers"] = self.min_secondary_marker_inliers
+        if self.min_distinct_marker_ids is not None:
+            d["min_distinct_marker_ids"] = self.min_distinct_marker_ids
+        if self.min_marker_score is not None:
+            d["min_marker_score"] = self.min_marker_score
+        if self.reject_on_duplicate_labels is not None:
+            d["reject_on_duplicate_labels"] = self.reject_on_duplicate_labels
+        if self.convention is not None:
+            d["convention"] = self.convention.to_dict()
         if self.grid_smoothness_threshold_rel is not None:
             d["grid_smoothness_threshold_rel"] = self.grid_smoothness_threshold_rel
         if self.corner_validation_threshold_rel is not None:
@@ -1170,6 +1214,14 @@ class CharucoParams:
             # ignored if present.
             min_marker_inliers=data.get("min_marker_inliers", 3),
             min_secondary_marker_inliers=data.get("min_secondary_marker_inliers"),
+            min_distinct_marker_ids=data.get("min_distinct_marker_ids"),
+            min_marker_score=data.get("min_marker_score"),
+            reject_on_duplicate_labels=data.get("reject_on_duplicate_labels"),
+            convention=(
+                BoardConvention.from_dict(data["convention"])
+                if "convention" in data
+                else None
+            ),
             grid_smoothness_threshold_rel=data.get("grid_smoothness_threshold_rel"),
             corner_validation_threshold_rel=data.get("corner_validation_threshold_rel"),
         )
@@ -1256,6 +1308,38 @@ class MarkerBoardSpec:
 MarkerBoardLayout = MarkerBoardSpec
 
 
+@dataclass(slots=True)
+class ScoreAggregation:
+    """How circle contrast is aggregated: ``"mean"`` over the whole
+    perimeter, or ``"min_sector"`` — the weakest of ``sectors`` angular
+    sectors, which rejects partially occluded circles."""
+
+    kind: str = "mean"
+    sectors: int | None = None
+
+    @classmethod
+    def mean(cls) -> ScoreAggregation:
+        return cls(kind="mean")
+
+    @classmethod
+    def min_sector(cls, sectors: int = 8) -> ScoreAggregation:
+        return cls(kind="min_sector", sectors=sectors)
+
+    def to_dict(self) -> dict[str, Any]:
+        out: dict[str, Any] = {"kind": self.kind}
+        if self.kind == "min_sector":
+            out["sectors"] = self.sectors
+        return out
+
+    @classmethod
+    def from_dict(cls, data: dict[str, Any]) -> ScoreAggregation:
+        kind = str(data.get("kind", "mean"))
+        return cls(
+            kind=kind,
+            sectors=int(data["sectors"]) if "sectors" in data else None,
+        )
+
+
 @dataclass(slots=True)
 class CircleScoreParams:
     patch_size: int = 64
@@ -1265,9 +1349,11 @@ class CircleScoreParams:
     min_contrast: float = 60.0
     samples: int = 48
     center_search_px: int = 2
+    radius_range_squares: tuple[float, float] | None = None
+    aggregation: ScoreAggregation = field(default_factory=ScoreAggregation)
 
     def to_dict(self) -> dict[str, Any]:
-        return {
+        d: dict[str, Any] = {
             "patch_size": self.patch_size,
             "diameter_frac": self.diameter_frac,
             "ring_thickness_frac": self.ring_thickness_frac,
@@ -1275,11 +1361,16 @@ class CircleScoreParams:
             "min_contrast": self.min_contrast,
             "samples": self.samples,
             "center_search_px": self.center_search_px,
+            "aggregation": self.aggregation.to_dict(),
         }
+        if self.radius_range_squares is not None:
+            d["radius_range_squares"] = list(self.radius_range_squares)
+        return d
 
     @classmethod
     def from_dict(cls, data: dict[str, Any]) -> CircleScoreParams:
         d = cls()
+        radius_range = data.get("radius_range_squares")
         return cls(
             patch_size=data.get("patch_size", d.patch_size),
             diameter_frac=data.get("diameter_frac", d.diameter_frac),
@@ -1288,6 +1379,10 @@ class CircleScoreParams:
             min_contrast=data.get("min_contrast", d.min_contrast),
             samples=data.get("samples", d.samples),
             center_search_px=data.get("center_search_px", d.center_search_px),
+            radius_range_squares=(
+                tuple(radius_range) if radius_range is not None else None  # type: ignore[arg-type]
+            ),
+            aggregation=ScoreAggregation.from_dict(data.get("aggregation", {})),
         )
 
 
@@ -1296,11 +1391,13 @@ class CircleMatchParams:
     max_candidates_per_polarity: int = 6
     max_distance_cells: float | None = None
     min_offset_inliers: int = 1
+    auto_polarity: bool = False
 
     def to_dict(self) -> dict[str, Any]:
         d: dict[str, Any] = {
             "max_candidates_per_polarity": self.max_candidates_per_polarity,
             "min_offset_inliers": self.min_offset_inliers,
+            "auto_polarity": self.auto_polarity,
         }
         if self.max_distance_cells is not None:
             d["max_distance_cells"] = self.max_distance_cells
@@ -1315,6 +1412,7 @@ class CircleMatchParams:
             ),
             max_distance_cells=data.get("max_distance_cells"),
             min_offset_inliers=data.get("min_offset_inliers", d.min_offset_inliers),
+            auto_polarity=data.get("auto_polarity", d.auto_polarity),
         )
 
 
@@ -1622,6 +1720,7 @@ __all__ = [
     "MarkerCircleSpec",
     "MarkerBoardSpec",
     "MarkerBoardLayout",  # backward-compatible alias
+    "ScoreAggregation",
     "CircleScoreParams",
     "CircleMatchParams",
     "MarkerBoardParams",
@@ -21,10 +21,24 @@ class MarkerLayout(str, Enum):
     OPENCV_CHARUCO = "opencv_charuco"
 
 
+class BoardOrigin(str, Enum):
+    TOP_LEFT = "top_left"
+    TOP_RIGHT = "top_right"
+    BOTTOM_RIGHT = "bottom_right"
+    BOTTOM_LEFT = "bottom_left"
+
+
+class SelectionMode(str, Enum):
+    MOST_CORNERS = "most_corners"
+    LARGEST_EXTENT = "largest_extent"
+
+
 __all__ = [
     "DICTIONARY_NAMES",
     "DictionaryName",
     "TargetKind",
     "CirclePolarity",
     "MarkerLayout",
+    "BoardOrigin",
+    "SelectionMode",
 ]
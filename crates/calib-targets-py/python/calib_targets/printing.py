@@ -5,7 +5,7 @@ from os import fspath
 from typing import Any
 
 from . import _core
-from .config import MarkerCircleSpec
+from .config import CharucoBoardSpec, MarkerBoardSpec, MarkerCircleSpec
 from .enums import CirclePolarity, DictionaryName, MarkerLayout
 
 
@@ -368,6 +368,18 @@ def write_target_bundle(
     return WrittenTargetBundle.from_dict(raw)
 
 
+def render_charuco_board(board: CharucoBoardSpec, px_per_square: int) -> Any:
+    """Render ``board`` alone as a ``(rows, cols) * px_per_square`` uint8 array."""
+    _ensure_type("board", board, CharucoBoardSpec)
+    return _core.render_charuco_board(board.to_dict(), px_per_square)
+
+
+def render_marker_board(layout: MarkerBoardSpec, px_per_square: int) -> Any:
+    """Render ``layout`` alone as a ``(rows + 1, cols + 1) * px_per_square`` uint8 array."""
+    _ensure_type("layout", layout, MarkerBoardSpec)
+    return _core.render_marker_board(layout.to_dict(), px_per_square)
+
+
 def chessboard_document(
     inner_rows: int,
     inner_cols: int,
@@ -486,6 +498,8 @@ __all__ = [
     "WrittenTargetBundle",
     "render_target_bundle",
     "write_target_bundle",
+    "render_charuco_board",
+    "render_marker_board",
     "chessboard_document",
     "charuco_document",
     "puzzleboard_document",
@@ -117,6 +117,7 @@ class LabeledCorner:
     id: int | None
     target_position: Point2 | None
     score: float
+    interpolated: bool = False
 
     def to_dict(self) -> dict[str, Any]:
         from ._convert_out import labeled_corner_to_dict
@@ -240,12 +241,13 @@ class ChessboardCorner:
     grid label — a chessboard corner is always labelled, so this is
     non-optional. `input_index` maps the corner back to its index in the
     caller's raw `Corner` array (useful for ChArUco alignment and similar
-    post-processing). `score` is the corner score.
+    post-processing); it is `None` for a corner that came from no input.
+    `score` is the corner score.
     """
 
     position: Point2
     grid: Coord
-    input_index: int
+    input_index: int | None
     score: float
 
     def to_dict(self) -> dict[str, Any]:
@@ -297,6 +299,7 @@ class MarkerDetection:
     inverted: bool
     corners_rect: Corners4
     corners_img: Corners4 | None
+    matched_code: int | None = None
 
     def to_dict(self) -> dict[str, Any]:
         from ._convert_out import marker_detection_to_dict
@@ -391,6 +394,7 @@ class CharucoDetectionResult:
     corners: list[CharucoCorner]
     markers: list[MarkerDetection]
     alignment: GridAlignment
+    alignment_residual_cells: float = 0.0
 
     @property
     def detection(self) -> TargetDetection:
@@ -0,0 +1,48 @@
+"""Benchmark Rust → Python result conversion: direct vs JSON round-trip.
+
+Times converting one synthetic chessboard detection (1000 corners by
+default) to Python objects, once through the direct ``Serialize`` path the
+``detect_*`` functions use and once through the former
+``serde_json::Value`` tree. Not collected by pytest; run it by hand against
+a release build of the extension::
+
+    maturin develop --release
+    python python_tests/bench_result_conversion.py --corners 1000
+"""
+
+from __future__ import annotations
+
+import argparse
+import statistics
+import timeit
+
+from calib_targets import _core
+
+
+def _per_call_us(via_json: bool, corners: int, number: int, repeat: int) -> float:
+    timer = timeit.Timer(lambda: _core._conversion_benchmark(corners, via_json))
+    runs = timer.repeat(repeat=repeat, number=number)
+    return statistics.median(runs) / number * 1e6
+
+
+def main() -> None:
+    parser = argparse.ArgumentParser(description=__doc__.splitlines()[0])
+    parser.add_argument("--corners", type=int, default=1000)
+    parser.add_argument("--number", type=int, default=200, help="calls per timing run")
+    parser.add_argument("--repeat", type=int, default=7, help="timing runs (median reported)")
+    args = parser.parse_args()
+
+    direct = _core._conversion_benchmark(args.corners, False)
+    assert direct == _core._conversion_benchmark(args.corners, True), "paths disagree"
+    assert len(direct["corners"]) == args.corners
+
+    direct_us = _per_call_us(False, args.corners, args.number, args.repeat)
+    json_us = _per_call_us(True, args.corners, args.number, args.repeat)
+    print(f"{args.corners} corners, median of {args.repeat} x {args.number} calls")
+    print(f"  direct      {direct_us:9.1f} us/call")
+    print(f"  json tree   {json_us:9.1f} us/call")
+    print(f"  speed-up    {json_us / direct_us:9.2f}x")
+
+
+if __name__ == "__main__":
+    main()
@@ -4,13 +4,14 @@ These tests run the Rust extension on real test images and verify that
 the resulting dict deserializes cleanly through Python's result wrappers
 (``*.from_dict``) and survives a ``to_dict`` → ``from_dict`` round-trip.
 
-They guard against key-name drift between Rust's ``serde_json::to_value``
-output and the Python ``_convert_out`` deserializers — the class of bug
-that hand-written fixtures cannot catch.
+They guard against key-name drift between the Rust ``Serialize`` output
+and the Python ``_convert_out`` deserializers — the class of bug that
+hand-written fixtures cannot catch.
 """
 
 from __future__ import annotations
 
+import json
 from pathlib import Path
 from typing import Any
 
@@ -208,6 +209,37 @@ def test_detect_charuco_roundtrip_exercises_marker_gc() -> None:
     _assert_roundtrip(result)
 
 
+def test_charuco_detector_reused_across_frames() -> None:
+    image = _load_gray("small2.png")
+    params = _charuco_params_small2()
+    detector = ct.CharucoDetector(params=params)
+
+    one_shot = ct.detect_charuco(image, params=params)
+    for frame in (image, np.ascontiguousarray(image.copy())):
+        result = detector.detect(frame)
+        assert isinstance(result, ct.CharucoDetectionResult)
+        assert len(result.corners) == len(one_shot.corners)
+        assert len(result.markers) == len(one_shot.markers)
+        _assert_roundtrip(result)
+
+
+def test_detect_charuco_accepts_padded_rows() -> None:
+    image = _load_gray("small2.png")
+    params = _charuco_params_small2()
+    height, width = image.shape
+    # A camera buffer with 13 bytes of row padding, viewed without a copy.
+    buffer = np.full((height, width + 13), 0x5A, dtype=np.uint8)
+    buffer[:, :width] = image
+    padded = buffer[:, :width]
+    assert not padded.flags["C_CONTIGUOUS"]
+
+    expected = ct.detect_charuco(image, params=params)
+    result = ct.detect_charuco(padded, params=params)
+    assert [c.to_dict() for c in result.corners] == [
+        c.to_dict() for c in expected.corners
+    ]
+
+
 def test_detect_charuco_best_roundtrip() -> None:
     image = _load_gray("small2.png")
     params = _charuco_params_small2()
@@ -303,6 +335,22 @@ def test_raw_charuco_dict_keys_match_python_schema() -> None:
             )
 
 
+def test_raw_results_are_plain_json_data() -> None:
+    """The extension builds result dicts straight from the Rust values; they
+    must still be exactly what a JSON round-trip yields: string keys, lists
+    for sequences, ``None`` for non-finite floats."""
+    from calib_targets._convert_in import chess_config_to_payload
+
+    image = _load_gray("mid.png")
+    raw = _core.detect_chessboard(image, chess_cfg=chess_config_to_payload(None))
+    if raw is None:
+        pytest.skip("no chessboard detected on testdata/mid.png")
+    assert json.loads(json.dumps(raw, allow_nan=False)) == raw
+
+    params = _core.default_puzzleboard_params(8, 10)
+    assert json.loads(json.dumps(params, allow_nan=False)) == params
+
+
 def test_raw_puzzleboard_dict_keys_match_python_schema() -> None:
     from calib_targets._convert_in import chess_config_to_payload, puzzleboard_params_to_payload
 
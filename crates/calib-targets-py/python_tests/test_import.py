@@ -1,4 +1,5 @@
 import numpy as np
+import pytest
 
 import calib_targets
 
@@ -30,6 +31,24 @@ def test_import_and_smoke() -> None:
     assert result is None or isinstance(result, calib_targets.MarkerBoardDetectionResult)
 
 
+def test_charuco_rejects_image_too_small_for_board() -> None:
+    board = calib_targets.CharucoBoardSpec(
+        rows=5,
+        cols=7,
+        cell_size=1.0,
+        marker_size_rel=0.75,
+        dictionary="DICT_4X4_50",
+        marker_layout=calib_targets.MarkerLayout.OPENCV_CHARUCO,
+    )
+    params = calib_targets.CharucoDetectorParams(board=board)
+    image = np.zeros((12, 24), dtype=np.uint8)
+
+    with pytest.raises(ValueError, match=r"7x5-square board: need at least 28x20 px"):
+        calib_targets.detect_charuco(image, params=params)
+    with pytest.raises(ValueError, match="too small"):
+        calib_targets.CharucoDetector(params=params).detect(image)
+
+
 def test_module_exports() -> None:
     assert callable(calib_targets.detect_chessboard)
     assert callable(calib_targets.detect_chessboard_all)
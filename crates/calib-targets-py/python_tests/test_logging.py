@@ -0,0 +1,39 @@
+"""Rust log records routed to a Python callback."""
+
+from __future__ import annotations
+
+import numpy as np
+import pytest
+
+import calib_targets as ct
+
+
+def test_failed_detection_reports_rejection_to_callback() -> None:
+    records: list[tuple[str, str, str]] = []
+    ct.set_log_callback(lambda level, message, target: records.append((level, message, target)))
+    try:
+        ct.set_log_level("warn")
+        board = ct.CharucoBoardSpec(
+            rows=5,
+            cols=5,
+            cell_size=1.0,
+            marker_size_rel=0.75,
+            dictionary="DICT_4X4_50",
+            marker_layout=ct.MarkerLayout.OPENCV_CHARUCO,
+        )
+        image = np.zeros((64, 64), dtype=np.uint8)
+        with pytest.raises(RuntimeError):
+            ct.detect_charuco(image, params=ct.CharucoDetectorParams(board=board))
+    finally:
+        ct.set_log_callback(None)
+        ct.set_log_level("off")
+
+    assert any(
+        level == "WARN" and "chessboard stage failed" in message and target.startswith("calib_targets")
+        for level, message, target in records
+    ), records
+
+
+def test_set_log_level_rejects_unknown_level() -> None:
+    with pytest.raises(ValueError):
+        ct.set_log_level("loud")
@@ -21,6 +21,22 @@ def test_detect_chessboard_typed_params() -> None:
     assert result is None or isinstance(result, calib_targets.ChessboardDetectionResult)
 
 
+def test_non_finite_param_error_names_field_and_constraint() -> None:
+    cfg = calib_targets.ChessConfig(merge_radius=float("inf"))
+    with pytest.raises(
+        ValueError, match=r"chess_cfg\.merge_radius must be finite and >= 0, got inf"
+    ):
+        calib_targets.detect_chessboard(_image(), chess_cfg=cfg)
+
+
+def test_negative_absolute_threshold_error_names_field() -> None:
+    cfg = calib_targets.ChessConfig(threshold=calib_targets.Threshold.absolute(-1.0))
+    with pytest.raises(
+        ValueError, match=r"chess_cfg\.threshold\.absolute must be finite and >= 0, got -1"
+    ):
+        calib_targets.detect_chessboard(_image(), chess_cfg=cfg)
+
+
 def test_detect_charuco_typed_params() -> None:
     board = calib_targets.CharucoBoardSpec(
         rows=3,
@@ -193,11 +209,13 @@ def test_chessboard_params_roundtrip() -> None:
         max_booster_iters=5,
         topological=calib_targets.TopologicalParams(axis_align_tol_rad=0.30),
         component_merge=calib_targets.LocalMergeParams(min_overlap=3),
+        selection=calib_targets.SelectionMode.LARGEST_EXTENT,
     )
     serialized = params.to_dict()
     restored = calib_targets.ChessboardParams.from_dict(serialized)
     assert restored.to_dict() == serialized
     assert serialized["advanced"]["component_merge"]["min_overlap"] == 3
+    assert serialized["advanced"]["selection"] == "largest_extent"
 
 
 def test_chessboard_params_no_graph_build_algorithm() -> None:
@@ -235,12 +253,15 @@ def test_chessboard_advanced_block_is_complete() -> None:
         "topological",
         "component_merge",
         "max_fit_rms_ratio",
+        "normalize_strength",
+        "strength_percentile",
         "num_bins",
         "max_iters_2means",
         "cluster_tol_deg",
         "cluster_sigma_k",
         "peak_min_separation_deg",
         "min_peak_weight_fraction",
+        "smooth_sigma_bins",
         "attach_search_rel",
         "attach_axis_tol_deg",
         "attach_ambiguity_factor",
@@ -252,8 +273,11 @@ def test_chessboard_advanced_block_is_complete() -> None:
         "geometry_check_line_tol_rel",
         "geometry_check_local_h_tol_rel",
         "line_min_members",
+        "min_grid_lines",
+        "max_grid_lines",
         "validate_step_aware",
         "enable_final_edge_shape_check",
+        "selection",
     }
     assert set(advanced["component_merge"]) == {
         "position_tol_rel",
@@ -372,6 +396,7 @@ def _sample_charuco_result() -> dict:
                 "score": 1.0,
                 "border_score": 0.99,
                 "code": 1234,
+                "matched_code": 1234,
                 "inverted": False,
                 "corners_rect": [[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0]],
                 "corners_img": [[2.0, 2.0], [3.0, 2.0], [3.0, 3.0], [2.0, 3.0]],
@@ -381,6 +406,7 @@ def _sample_charuco_result() -> dict:
             "transform": {"a": 1, "b": 0, "c": 0, "d": 1},
             "translation": [0, 0],
         },
+        "alignment_residual_cells": 0.0,
     }
 
 
@@ -49,3 +49,23 @@ def test_write_target_bundle(tmp_path: Path) -> None:
     assert "$INSUNITS\n 70\n4\n" in dxf
     doc = json.loads(json_path.read_text())
     assert doc["target"]["kind"] == "marker_board"
+
+
+def test_render_charuco_board_image() -> None:
+    board = ct.CharucoBoardSpec(
+        rows=5,
+        cols=7,
+        cell_size=15.0,
+        marker_size_rel=0.75,
+        dictionary="DICT_4X4_50",
+    )
+    image = ct.render_charuco_board(board, 40)
+    assert image.shape == (5 * 40, 7 * 40)
+    assert image.dtype == "uint8"
+    assert set(image.ravel().tolist()) <= {0, 255}
+
+
+def test_render_marker_board_image() -> None:
+    image = ct.render_marker_board(ct.MarkerBoardSpec(rows=6, cols=8), 20)
+    assert image.shape == (7 * 20, 9 * 20)
+    assert image.dtype == "uint8"
@@ -1,6 +1,6 @@
 use ::calib_targets::detect::DetectorConfig;
 use ::calib_targets::{charuco, chessboard, detect, marker, printable, puzzleboard};
-use numpy::{PyArrayDyn, PyArrayMethods, PyUntypedArrayMethods};
+use numpy::{IntoPyArray, PyArrayDyn, PyArrayMethods, PyUntypedArrayMethods};
 use pyo3::conversion::IntoPyObjectExt;
 use pyo3::exceptions::{PyRuntimeError, PyValueError};
 use pyo3::prelude::*;
@@ -9,6 +9,10 @@ use serde::de::DeserializeOwned;
 use serde::Serialize;
 use serde_json::{Map, Number, Value};
 
+mod to_py;
+
+use to_py::to_py;
+
 // ---------------------------------------------------------------------------
 // Helpers
 // ---------------------------------------------------------------------------
@@ -17,6 +21,50 @@ fn value_error(msg: impl Into<String>) -> PyErr {
     PyValueError::new_err(msg.into())
 }
 
+/// Constraint on a numeric parameter, checked while converting Python params
+/// to JSON so a bad value is reported against its field.
+#[derive(Clone, Copy)]
+enum NumberRule {
+    Finite,
+    NonNegative,
+    UnitInterval,
+}
+
+impl NumberRule {
+    /// Params with a constraint beyond finiteness, matched on the trailing
+    /// segment(s) of their path.
+    const RULES: &'static [(&'static str, NumberRule)] = &[
+        (".merge_radius", NumberRule::NonNegative),
+        (".threshold.relative", NumberRule::UnitInterval),
+        (".threshold.absolute", NumberRule::NonNegative),
+        (".min_corner_strength", NumberRule::NonNegative),
+    ];
+
+    fn for_path(path: &str) -> Self {
+        Self::RULES
+            .iter()
+            .find(|(suffix, _)| path.ends_with(suffix))
+            .map_or(NumberRule::Finite, |&(_, rule)| rule)
+    }
+
+    fn holds(self, value: f64) -> bool {
+        value.is_finite()
+            && match self {
+                NumberRule::Finite => true,
+                NumberRule::NonNegative => value >= 0.0,
+                NumberRule::UnitInterval => (0.0..=1.0).contains(&value),
+            }
+    }
+
+    fn describe(self) -> &'static str {
+        match self {
+            NumberRule::Finite => "finite",
+            NumberRule::NonNegative => "finite and >= 0",
+            NumberRule::UnitInterval => "finite and in [0, 1]",
+        }
+    }
+}
+
 fn py_to_json(obj: &Bound<'_, PyAny>, path: &str) -> PyResult<Value> {
     if obj.is_none() {
         return Ok(Value::Null);
@@ -60,15 +108,25 @@ fn py_to_json(obj: &Bound<'_, PyAny>, path: &str) -> PyResult<Value> {
         }
     }
     if let Ok(value) = obj.extract::<i64>() {
+        let rule = NumberRule::for_path(path);
+        if !rule.holds(value as f64) {
+            return Err(value_error(format!(
+                "{path} must be {}, got {value}",
+                rule.describe()
+            )));
+        }
         return Ok(Value::Number(value.into()));
     }
     if let Ok(value) = obj.extract::<u64>() {
         return Ok(Value::Number(value.into()));
     }
     if let Ok(value) = obj.extract::<f64>() {
-        let number = Number::from_f64(value).ok_or_else(|| {
-            value_error(format!("{path}: non-finite float is not JSON compatible"))
-        })?;
+        let rule = NumberRule::for_path(path);
+        let number = Number::from_f64(value)
+            .filter(|_| rule.holds(value))
+            .ok_or_else(|| {
+                value_error(format!("{path} must be {}, got {value}", rule.describe()))
+            })?;
         return Ok(Value::Number(number));
     }
     Err(value_error(format!(
@@ -146,11 +204,35 @@ fn gray_image_from_py(image: &Bound<'_, PyAny>) -> PyResult<::image::GrayImage>
         .ok_or_else(|| value_error("image has no width"))?;
     let height = u32::try_from(height).map_err(|_| value_error("image height is too large"))?;
     let width = u32::try_from(width).map_err(|_| value_error("image width is too large"))?;
-    let pixels = view.to_owned().into_raw_vec_and_offset().0;
+    // Gather in logical row-major order so padded rows (a `[:, :w]` slice of a
+    // wider buffer) and Fortran-ordered arrays are read correctly.
+    let pixels: Vec<u8> = view.iter().copied().collect();
     detect::gray_image_from_slice(width, height, &pixels)
         .map_err(|err| value_error(err.to_string()))
 }
 
+/// Smallest board square, in pixels, that can still carry a corner. Far
+/// below what the corner detector resolves, so the fit check only rejects
+/// images that cannot hold the board at all.
+const MIN_SQUARE_PX: u32 = 4;
+
+/// Reject an image too small to hold a `cols × rows`-square board at
+/// [`MIN_SQUARE_PX`] in either orientation.
+fn check_image_fits_board(img: &::image::GrayImage, cols: u32, rows: u32) -> PyResult<()> {
+    let (w, h) = img.dimensions();
+    let (min_w, min_h) = (
+        cols.saturating_mul(MIN_SQUARE_PX),
+        rows.saturating_mul(MIN_SQUARE_PX),
+    );
+    if (w >= min_w && h >= min_h) || (w >= min_h && h >= min_w) {
+        return Ok(());
+    }
+    Err(value_error(format!(
+        "image is {w}x{h} px, too small for a {cols}x{rows}-square board: \
+         need at least {min_w}x{min_h} px ({MIN_SQUARE_PX} px per square)"
+    )))
+}
+
 // ---------------------------------------------------------------------------
 // Config extraction
 // ---------------------------------------------------------------------------
@@ -253,15 +335,64 @@ fn detect_charuco(
     let img = gray_image_from_py(image)?;
     let params = charuco_params_from_py(Some(params))?;
     let chess_cfg = chess_cfg_from_py(chess_cfg)?;
+    check_image_fits_board(&img, params.board.cols, params.board.rows)?;
     let result = py.detach(move || -> Result<_, detect::DetectError> {
         let corners = detect::detect_corners(&img, &chess_cfg);
         let detector = charuco::CharucoDetector::new(params.clone())?;
         Ok(detector.detect(&detect::gray_view(&img), &corners)?)
     });
     let result = result.map_err(|err| PyRuntimeError::new_err(err.to_string()))?;
-    let json =
-        serde_json::to_value(result).map_err(|err| PyRuntimeError::new_err(err.to_string()))?;
-    json_to_py(py, &json)
+    to_py(py, &result)
+}
+
+/// Reusable ChArUco detector.
+///
+/// Parses the params, builds the board and the marker matcher once in the
+/// constructor; `detect` then reuses them for every frame.
+///
+/// Args:
+///   chess_cfg: dict with DetectorConfig fields, or None for defaults.
+///   params: dict with CharucoParams fields (must include `board`).
+#[pyclass(name = "CharucoDetector", module = "calib_targets._core", frozen)]
+struct PyCharucoDetector {
+    detector: charuco::CharucoDetector,
+    chess_cfg: DetectorConfig,
+}
+
+#[pymethods]
+impl PyCharucoDetector {
+    #[new]
+    #[pyo3(signature = (*, chess_cfg=None, params))]
+    fn new(chess_cfg: Option<&Bound<'_, PyAny>>, params: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let params = charuco_params_from_py(Some(params))?;
+        let chess_cfg = chess_cfg_from_py(chess_cfg)?;
+        let detector =
+            charuco::CharucoDetector::new(params).map_err(|err| value_error(err.to_string()))?;
+        Ok(Self {
+            detector,
+            chess_cfg,
+        })
+    }
+
+    /// Detect the board in one grayscale frame.
+    ///
+    /// Args:
+    ///   image: 2D numpy.ndarray[uint8] (H, W) grayscale image.
+    ///
+    /// Returns:
+    ///   dict with detection data, or raises RuntimeError on detection errors.
+    ///   Raises ValueError when the image is too small to hold the board.
+    fn detect(&self, py: Python<'_>, image: &Bound<'_, PyAny>) -> PyResult<Py<PyAny>> {
+        let img = gray_image_from_py(image)?;
+        let spec = self.detector.board().spec();
+        check_image_fits_board(&img, spec.cols, spec.rows)?;
+        let result = py.detach(|| {
+            let corners = detect::detect_corners(&img, &self.chess_cfg);
+            self.detector.detect(&detect::gray_view(&img), &corners)
+        });
+        let result = result.map_err(|err| PyRuntimeError::new_err(err.to_string()))?;
+        to_py(py, &result)
+    }
 }
 
 /// Detect a chessboard in a grayscale image.
@@ -293,11 +424,7 @@ fn detect_chessboard(
             .and_then(|d| d.detect(&corners))
     });
     match result {
-        Some(res) => {
-            let json = serde_json::to_value(res)
-                .map_err(|err| PyRuntimeError::new_err(err.to_string()))?;
-            Ok(Some(json_to_py(py, &json)?))
-        }
+        Some(res) => Ok(Some(to_py(py, &res)?)),
         None => Ok(None),
     }
 }
@@ -336,9 +463,7 @@ fn detect_chessboard_all(
             .map(|d| d.detect_all(&corners))
             .unwrap_or_default()
     });
-    let json =
-        serde_json::to_value(results).map_err(|err| PyRuntimeError::new_err(err.to_string()))?;
-    json_to_py(py, &json)
+    to_py(py, &results)
 }
 
 /// Run ChESS corner detection plus the topological grid trace.
@@ -442,11 +567,7 @@ fn detect_marker_board(
             .and_then(|d| d.detect_from_image_and_corners(&detect::gray_view(&img), &corners))
     });
     match result {
-        Some(res) => {
-            let json = serde_json::to_value(res)
-                .map_err(|err| PyRuntimeError::new_err(err.to_string()))?;
-            Ok(Some(json_to_py(py, &json)?))
-        }
+        Some(res) => Ok(Some(to_py(py, &res)?)),
         None => Ok(None),
     }
 }
@@ -479,9 +600,7 @@ fn detect_puzzleboard(
         Ok(detector.detect(&detect::gray_view(&img), &corners)?)
     });
     let result = result.map_err(|err| PyRuntimeError::new_err(err.to_string()))?;
-    let json =
-        serde_json::to_value(result).map_err(|err| PyRuntimeError::new_err(err.to_string()))?;
-    json_to_py(py, &json)
+    to_py(py, &result)
 }
 
 /// Detect a ChArUco board and additionally return the diagnostics channel.
@@ -506,6 +625,7 @@ fn detect_charuco_with_diagnostics(
     let img = gray_image_from_py(image)?;
     let params = charuco_params_from_py(Some(params))?;
     let chess_cfg = chess_cfg_from_py(chess_cfg)?;
+    check_image_fits_board(&img, params.board.cols, params.board.rows)?;
 
     let payload = py.detach(move || -> Result<Value, String> {
         let corners = detect::detect_corners(&img, &chess_cfg);
@@ -649,11 +769,7 @@ fn detect_chessboard_best(
 
     let result = py.detach(move || detect::detect_chessboard_best(&img, &chess_cfg, &params_vec));
     match result {
-        Some(res) => {
-            let json = serde_json::to_value(res)
-                .map_err(|err| PyRuntimeError::new_err(err.to_string()))?;
-            Ok(Some(json_to_py(py, &json)?))
-        }
+        Some(res) => Ok(Some(to_py(py, &res)?)),
         None => Ok(None),
     }
 }
@@ -686,9 +802,7 @@ fn detect_charuco_best(
 
     let result = py.detach(move || detect::detect_charuco_best(&img, &params_vec));
     let result = result.map_err(|err| PyRuntimeError::new_err(err.to_string()))?;
-    let json =
-        serde_json::to_value(result).map_err(|err| PyRuntimeError::new_err(err.to_string()))?;
-    json_to_py(py, &json)
+    to_py(py, &result)
 }
 
 /// Try multiple marker board parameter configs, return the best result (most corners).
@@ -720,11 +834,7 @@ fn detect_marker_board_best(
 
     let result = py.detach(move || detect::detect_marker_board_best(&img, &params_vec));
     match result {
-        Some(res) => {
-            let json = serde_json::to_value(res)
-                .map_err(|err| PyRuntimeError::new_err(err.to_string()))?;
-            Ok(Some(json_to_py(py, &json)?))
-        }
+        Some(res) => Ok(Some(to_py(py, &res)?)),
         None => Ok(None),
     }
 }
@@ -759,9 +869,7 @@ fn detect_puzzleboard_best(
 
     let result = py.detach(move || detect::detect_puzzleboard_best(&img, &params_vec));
     let result = result.map_err(|err| PyRuntimeError::new_err(err.to_string()))?;
-    let json =
-        serde_json::to_value(result).map_err(|err| PyRuntimeError::new_err(err.to_string()))?;
-    json_to_py(py, &json)
+    to_py(py, &result)
 }
 
 /// Return Rust-side default PuzzleBoard parameters for a board size.
@@ -770,9 +878,7 @@ fn detect_puzzleboard_best(
 fn default_puzzleboard_params(py: Python<'_>, rows: u32, cols: u32) -> PyResult<Py<PyAny>> {
     let params = detect::default_puzzleboard_params(rows, cols)
         .map_err(|err| PyRuntimeError::new_err(err.to_string()))?;
-    let json =
-        serde_json::to_value(params).map_err(|err| PyRuntimeError::new_err(err.to_string()))?;
-    json_to_py(py, &json)
+    to_py(py, &params)
 }
 
 // ---------------------------------------------------------------------------
@@ -814,6 +920,148 @@ fn write_target_bundle(
     Ok(out.into_any().unbind())
 }
 
+/// Rasterize a board alone into a 2D uint8 numpy array.
+fn board_image_to_py(
+    py: Python<'_>,
+    target: &printable::TargetSpec,
+    px_per_square: u32,
+) -> PyResult<Py<PyAny>> {
+    let image = printable::render_board_image(target, px_per_square)
+        .map_err(|err| value_error(err.to_string()))?;
+    let array = numpy::ndarray::Array2::from_shape_vec((image.height, image.width), image.data)
+        .map_err(|err| PyRuntimeError::new_err(err.to_string()))?;
+    Ok(array.into_pyarray(py).into_any().unbind())
+}
+
+/// Render a ChArUco board (no page or margins) as a grayscale image.
+///
+/// Args:
+///   board: dict with CharucoBoardSpec fields.
+///   px_per_square: pixels per board square.
+///
+/// Returns:
+///   numpy.ndarray[uint8] of shape (rows * px_per_square, cols * px_per_square).
+#[pyfunction]
+#[pyo3(signature = (board, px_per_square))]
+fn render_charuco_board(
+    py: Python<'_>,
+    board: &Bound<'_, PyAny>,
+    px_per_square: u32,
+) -> PyResult<Py<PyAny>> {
+    let board: charuco::CharucoBoardSpec = from_py_json(board, "board")?;
+    let target =
+        printable::TargetSpec::Charuco(printable::CharucoTargetSpec::from_board_spec_mm(&board));
+    board_image_to_py(py, &target, px_per_square)
+}
+
+/// Render a checkerboard marker board (no page or margins) as a grayscale
+/// image.
+///
+/// Args:
+///   layout: dict with MarkerBoardSpec fields. `cell_size` may be omitted;
+///     only `px_per_square` sets the scale.
+///   px_per_square: pixels per board square.
+///
+/// Returns:
+///   numpy.ndarray[uint8] of shape ((rows + 1) * px_per_square,
+///   (cols + 1) * px_per_square).
+#[pyfunction]
+#[pyo3(signature = (layout, px_per_square))]
+fn render_marker_board(
+    py: Python<'_>,
+    layout: &Bound<'_, PyAny>,
+    px_per_square: u32,
+) -> PyResult<Py<PyAny>> {
+    let mut layout: marker::MarkerBoardSpec = from_py_json(layout, "layout")?;
+    layout.cell_size.get_or_insert(1.0);
+    let target = printable::TargetSpec::MarkerBoard(
+        printable::MarkerBoardTargetSpec::try_from_layout_mm(&layout)
+            .map_err(|err| value_error(err.to_string()))?,
+    );
+    board_image_to_py(py, &target, px_per_square)
+}
+
+// ---------------------------------------------------------------------------
+// Logging
+// ---------------------------------------------------------------------------
+
+/// Install the Rust logger (if needed) and set its level filter.
+///
+/// `level` is one of `"off"`, `"error"`, `"warn"`, `"info"`, `"debug"`,
+/// `"trace"` (case-insensitive).
+#[pyfunction]
+#[pyo3(signature = (level))]
+fn set_log_level(level: &str) -> PyResult<()> {
+    let filter: log::LevelFilter = level.parse().map_err(|_| {
+        value_error(format!(
+            "unknown log level {level:?}; expected off, error, warn, info, debug, or trace"
+        ))
+    })?;
+    ::calib_targets::core::init_with_level(filter)
+        .map_err(|err| PyRuntimeError::new_err(err.to_string()))
+}
+
+/// Route Rust log records to `callback(level, message, target)`; `None`
+/// restores stderr output.
+///
+/// Installs the logger at `"info"` when no level has been set yet.
+/// Exceptions raised by the callback are reported as unraisable and do not
+/// abort detection.
+#[pyfunction]
+#[pyo3(signature = (callback))]
+fn set_log_callback(callback: Option<Py<PyAny>>) -> PyResult<()> {
+    let Some(callback) = callback else {
+        ::calib_targets::core::set_log_sink(None);
+        return Ok(());
+    };
+    ::calib_targets::core::set_log_sink(Some(Box::new(move |level, target, message| {
+        Python::attach(|py| {
+            let args = (level.as_str(), message, target);
+            if let Err(err) = callback.call1(py, args) {
+                err.write_unraisable(py, None);
+            }
+        });
+    })));
+    if log::max_level() == log::LevelFilter::Off {
+        set_log_level("info")?;
+    }
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Benchmark support
+// ---------------------------------------------------------------------------
+
+/// Convert a synthetic chessboard detection of `corners` corners to Python,
+/// directly or (`via_json`) through a `serde_json::Value` tree as results
+/// were converted before. Backs `python_tests/bench_result_conversion.py`;
+/// not part of the public API.
+#[pyfunction]
+#[pyo3(signature = (corners, via_json))]
+fn _conversion_benchmark(py: Python<'_>, corners: usize, via_json: bool) -> PyResult<Py<PyAny>> {
+    let cols = (corners as f64).sqrt().ceil().max(1.0) as usize;
+    let detection = chessboard::ChessboardDetection::new(
+        (0..corners)
+            .map(|i| {
+                let (u, v) = (i % cols, i / cols);
+                chessboard::ChessboardCorner::new(
+                    nalgebra::Point2::new(20.0 * u as f32 + 0.25, 20.0 * v as f32 + 0.75),
+                    ::calib_targets::Coord::new(u as i32, v as i32),
+                    i,
+                    0.9,
+                )
+            })
+            .collect(),
+    );
+    if via_json {
+        let value = serde_json::to_value(&detection)
+            .map_err(|err| PyRuntimeError::new_err(err.to_string()))?;
+        json_to_py(py, &value)
+    } else {
+        to_py(py, &detection)
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Module
 // ---------------------------------------------------------------------------
@@ -821,6 +1069,7 @@ fn write_target_bundle(
 #[pymodule]
 fn _core(_py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(detect_charuco, m)?)?;
+    m.add_class::<PyCharucoDetector>()?;
     m.add_function(wrap_pyfunction!(detect_chessboard, m)?)?;
     m.add_function(wrap_pyfunction!(detect_chessboard_all, m)?)?;
     m.add_function(wrap_pyfunction!(trace_chessboard_topological, m)?)?;
@@ -836,5 +1085,10 @@ fn _core(_py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(default_puzzleboard_params, m)?)?;
     m.add_function(wrap_pyfunction!(render_target_bundle, m)?)?;
     m.add_function(wrap_pyfunction!(write_target_bundle, m)?)?;
+    m.add_function(wrap_pyfunction!(render_charuco_board, m)?)?;
+    m.add_function(wrap_pyfunction!(render_marker_board, m)?)?;
+    m.add_function(wrap_pyfunction!(set_log_level, m)?)?;
+    m.add_function(wrap_pyfunction!(set_log_callback, m)?)?;
+    m.add_function(wrap_pyfunction!(_conversion_benchmark, m)?)?;
     Ok(())
 }
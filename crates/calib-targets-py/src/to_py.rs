@@ -0,0 +1,396 @@
+//! Direct `Serialize` → Python conversion for detector results.
+//!
+//! [`to_py`] walks a value's `serde::Serialize` impl and builds the Python
+//! objects in place, skipping the intermediate `serde_json::Value` tree the
+//! `serde_json::to_value` + [`crate::json_to_py`] route allocates. The output
+//! is the same as that round-trip: structs and maps become `dict`, sequences
+//! and tuples `list`, `Option::None` and unit `None`, unit enum variants their
+//! name, other enum variants a one-key `{variant: payload}` dict, and
+//! non-finite floats `None` (JSON has no NaN / ±inf). Map keys are stringified
+//! like `serde_json` does (integers, `bool` as `"true"` / `"false"`). Only dict
+//! key order differs: keys follow the Rust field order instead of being
+//! sorted.
+
+use std::fmt;
+
+use pyo3::conversion::IntoPyObjectExt;
+use pyo3::exceptions::PyRuntimeError;
+use pyo3::prelude::*;
+use pyo3::types::{PyBool, PyDict, PyFloat, PyInt, PyList, PyString};
+use serde::ser::{self, Serialize};
+
+/// Convert any `Serialize` value straight into a Python object.
+pub(crate) fn to_py<T: Serialize + ?Sized>(py: Python<'_>, value: &T) -> PyResult<Py<PyAny>> {
+    value
+        .serialize(PySerializer { py })
+        .map(Bound::unbind)
+        .map_err(|err| err.0)
+}
+
+/// `serde` error carrier around a [`PyErr`].
+struct SerError(PyErr);
+
+impl fmt::Debug for SerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.0, f)
+    }
+}
+
+impl fmt::Display for SerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl std::error::Error for SerError {}
+
+impl ser::Error for SerError {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Self(PyRuntimeError::new_err(msg.to_string()))
+    }
+}
+
+impl From<PyErr> for SerError {
+    fn from(err: PyErr) -> Self {
+        Self(err)
+    }
+}
+
+type Result<T> = std::result::Result<T, SerError>;
+
+#[derive(Clone, Copy)]
+struct PySerializer<'py> {
+    py: Python<'py>,
+}
+
+impl<'py> PySerializer<'py> {
+    fn any<T: IntoPyObjectExt<'py>>(self, value: T) -> Result<Bound<'py, PyAny>> {
+        Ok(value.into_bound_py_any(self.py)?)
+    }
+
+    fn float(self, value: f64) -> Result<Bound<'py, PyAny>> {
+        if value.is_finite() {
+            self.any(value)
+        } else {
+            Ok(self.py.None().into_bound(self.py))
+        }
+    }
+
+    /// `{variant: payload}`, the externally tagged enum shape.
+    fn tagged(self, variant: &str, payload: Bound<'py, PyAny>) -> Result<Bound<'py, PyAny>> {
+        let dict = PyDict::new(self.py);
+        dict.set_item(variant, payload)?;
+        Ok(dict.into_any())
+    }
+}
+
+/// Stringify a serialized map key the way `serde_json` does.
+fn map_key<'py>(key: Bound<'py, PyAny>) -> Result<Bound<'py, PyAny>> {
+    if key.is_instance_of::<PyString>() {
+        return Ok(key);
+    }
+    if key.is_instance_of::<PyBool>() {
+        let text = if key.is_truthy()? { "true" } else { "false" };
+        return Ok(PyString::new(key.py(), text).into_any());
+    }
+    if key.is_instance_of::<PyInt>() || key.is_instance_of::<PyFloat>() {
+        return Ok(key.str()?.into_any());
+    }
+    Err(ser::Error::custom("map key must be a string"))
+}
+
+impl<'py> ser::Serializer for PySerializer<'py> {
+    type Ok = Bound<'py, PyAny>;
+    type Error = SerError;
+    type SerializeSeq = SeqSerializer<'py>;
+    type SerializeTuple = SeqSerializer<'py>;
+    type SerializeTupleStruct = SeqSerializer<'py>;
+    type SerializeTupleVariant = SeqSerializer<'py>;
+    type SerializeMap = MapSerializer<'py>;
+    type SerializeStruct = MapSerializer<'py>;
+    type SerializeStructVariant = MapSerializer<'py>;
+
+    fn serialize_bool(self, v: bool) -> Result<Self::Ok> {
+        self.any(v)
+    }
+    fn serialize_i8(self, v: i8) -> Result<Self::Ok> {
+        self.any(v)
+    }
+    fn serialize_i16(self, v: i16) -> Result<Self::Ok> {
+        self.any(v)
+    }
+    fn serialize_i32(self, v: i32) -> Result<Self::Ok> {
+        self.any(v)
+    }
+    fn serialize_i64(self, v: i64) -> Result<Self::Ok> {
+        self.any(v)
+    }
+    fn serialize_i128(self, v: i128) -> Result<Self::Ok> {
+        self.any(v)
+    }
+    fn serialize_u8(self, v: u8) -> Result<Self::Ok> {
+        self.any(v)
+    }
+    fn serialize_u16(self, v: u16) -> Result<Self::Ok> {
+        self.any(v)
+    }
+    fn serialize_u32(self, v: u32) -> Result<Self::Ok> {
+        self.any(v)
+    }
+    fn serialize_u64(self, v: u64) -> Result<Self::Ok> {
+        self.any(v)
+    }
+    fn serialize_u128(self, v: u128) -> Result<Self::Ok> {
+        self.any(v)
+    }
+    fn serialize_f32(self, v: f32) -> Result<Self::Ok> {
+        self.float(f64::from(v))
+    }
+    fn serialize_f64(self, v: f64) -> Result<Self::Ok> {
+        self.float(v)
+    }
+    fn serialize_char(self, v: char) -> Result<Self::Ok> {
+        self.any(v)
+    }
+    fn serialize_str(self, v: &str) -> Result<Self::Ok> {
+        self.any(v)
+    }
+    fn serialize_bytes(self, v: &[u8]) -> Result<Self::Ok> {
+        // `serde_json` encodes bytes as an array of numbers.
+        Ok(PyList::new(self.py, v)?.into_any())
+    }
+    fn serialize_none(self) -> Result<Self::Ok> {
+        Ok(self.py.None().into_bound(self.py))
+    }
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<Self::Ok> {
+        value.serialize(self)
+    }
+    fn serialize_unit(self) -> Result<Self::Ok> {
+        self.serialize_none()
+    }
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Self::Ok> {
+        self.serialize_none()
+    }
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+    ) -> Result<Self::Ok> {
+        self.any(variant)
+    }
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok> {
+        value.serialize(self)
+    }
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok> {
+        let payload = value.serialize(self)?;
+        self.tagged(variant, payload)
+    }
+    fn serialize_seq(self, len: Option<usize>) -> Result<Self::SerializeSeq> {
+        Ok(SeqSerializer {
+            ser: self,
+            items: Vec::with_capacity(len.unwrap_or(0)),
+            variant: None,
+        })
+    }
+    fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple> {
+        self.serialize_seq(Some(len))
+    }
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleStruct> {
+        self.serialize_seq(Some(len))
+    }
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleVariant> {
+        Ok(SeqSerializer {
+            ser: self,
+            items: Vec::with_capacity(len),
+            variant: Some(variant),
+        })
+    }
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap> {
+        Ok(MapSerializer {
+            ser: self,
+            dict: PyDict::new(self.py),
+            key: None,
+            variant: None,
+        })
+    }
+    fn serialize_struct(self, _name: &'static str, len: usize) -> Result<Self::SerializeStruct> {
+        self.serialize_map(Some(len))
+    }
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant> {
+        Ok(MapSerializer {
+            ser: self,
+            dict: PyDict::new(self.py),
+            key: None,
+            variant: Some(variant),
+        })
+    }
+}
+
+/// Collects sequence / tuple elements into a `list`.
+struct SeqSerializer<'py> {
+    ser: PySerializer<'py>,
+    items: Vec<Bound<'py, PyAny>>,
+    /// Set for tuple variants, which wrap the list in `{variant: [...]}`.
+    variant: Option<&'static str>,
+}
+
+impl<'py> SeqSerializer<'py> {
+    fn push<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        self.items.push(value.serialize(self.ser)?);
+        Ok(())
+    }
+
+    fn finish(self) -> Result<Bound<'py, PyAny>> {
+        let list = PyList::new(self.ser.py, self.items)?.into_any();
+        match self.variant {
+            Some(variant) => self.ser.tagged(variant, list),
+            None => Ok(list),
+        }
+    }
+}
+
+impl<'py> ser::SerializeSeq for SeqSerializer<'py> {
+    type Ok = Bound<'py, PyAny>;
+    type Error = SerError;
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        self.push(value)
+    }
+    fn end(self) -> Result<Self::Ok> {
+        self.finish()
+    }
+}
+
+impl<'py> ser::SerializeTuple for SeqSerializer<'py> {
+    type Ok = Bound<'py, PyAny>;
+    type Error = SerError;
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        self.push(value)
+    }
+    fn end(self) -> Result<Self::Ok> {
+        self.finish()
+    }
+}
+
+impl<'py> ser::SerializeTupleStruct for SeqSerializer<'py> {
+    type Ok = Bound<'py, PyAny>;
+    type Error = SerError;
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        self.push(value)
+    }
+    fn end(self) -> Result<Self::Ok> {
+        self.finish()
+    }
+}
+
+impl<'py> ser::SerializeTupleVariant for SeqSerializer<'py> {
+    type Ok = Bound<'py, PyAny>;
+    type Error = SerError;
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        self.push(value)
+    }
+    fn end(self) -> Result<Self::Ok> {
+        self.finish()
+    }
+}
+
+/// Fills a `dict` from map entries or struct fields.
+struct MapSerializer<'py> {
+    ser: PySerializer<'py>,
+    dict: Bound<'py, PyDict>,
+    /// Key waiting for its value between `serialize_key` / `serialize_value`.
+    key: Option<Bound<'py, PyAny>>,
+    /// Set for struct variants, which wrap the dict in `{variant: {...}}`.
+    variant: Option<&'static str>,
+}
+
+impl<'py> MapSerializer<'py> {
+    fn field<T: Serialize + ?Sized>(&mut self, key: &'static str, value: &T) -> Result<()> {
+        self.dict.set_item(key, value.serialize(self.ser)?)?;
+        Ok(())
+    }
+
+    fn finish(self) -> Result<Bound<'py, PyAny>> {
+        let dict = self.dict.into_any();
+        match self.variant {
+            Some(variant) => self.ser.tagged(variant, dict),
+            None => Ok(dict),
+        }
+    }
+}
+
+impl<'py> ser::SerializeMap for MapSerializer<'py> {
+    type Ok = Bound<'py, PyAny>;
+    type Error = SerError;
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<()> {
+        self.key = Some(map_key(key.serialize(self.ser)?)?);
+        Ok(())
+    }
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        let key = self
+            .key
+            .take()
+            .ok_or_else(|| <SerError as ser::Error>::custom("map value without a key"))?;
+        self.dict.set_item(key, value.serialize(self.ser)?)?;
+        Ok(())
+    }
+    fn end(self) -> Result<Self::Ok> {
+        self.finish()
+    }
+}
+
+impl<'py> ser::SerializeStruct for MapSerializer<'py> {
+    type Ok = Bound<'py, PyAny>;
+    type Error = SerError;
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<()> {
+        self.field(key, value)
+    }
+    fn end(self) -> Result<Self::Ok> {
+        self.finish()
+    }
+}
+
+impl<'py> ser::SerializeStructVariant for MapSerializer<'py> {
+    type Ok = Bound<'py, PyAny>;
+    type Error = SerError;
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<()> {
+        self.field(key, value)
+    }
+    fn end(self) -> Result<Self::Ok> {
+        self.finish()
+    }
+}
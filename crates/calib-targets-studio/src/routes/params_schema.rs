@@ -95,8 +95,9 @@ const fn field(
 /// The committed advanced-param schema. One entry per editable scalar knob of
 /// `AdvancedTuning` (its nested `topological` / `component_merge` leaves
 /// included); the non-scalar `topological.axis_cluster_centers`
-/// (`Option<[f32; 2]>`, default `null`) is intentionally omitted — it has no
-/// scalar widget and falls through to the frontend's raw-key fallback.
+/// (`Option<[f32; 2]>`, default `null`) and the nullable `strength_percentile`
+/// (`Option<f32>`, default `null`) are intentionally omitted — they have no
+/// scalar widget and fall through to the frontend's raw-key fallback.
 fn catalogue() -> ParamSchema {
     use ParamKind::{Bool, Float, Int};
 
@@ -145,6 +146,14 @@ fn catalogue() -> ParamSchema {
             Float,
             None,
         ),
+        field(
+            "/advanced/normalize_strength",
+            "prefilter",
+            "Normalize strength",
+            "Divide corner strengths by the 95th-percentile strength before the min-strength floor, so the floor is portable across corner detectors.",
+            Bool,
+            None,
+        ),
         // --- cluster_axes ---------------------------------------------------
         field(
             "/advanced/num_bins",
@@ -194,6 +203,14 @@ fn catalogue() -> ParamSchema {
             Float,
             None,
         ),
+        field(
+            "/advanced/smooth_sigma_bins",
+            "cluster_axes",
+            "Histogram smoothing σ (bins)",
+            "Gaussian width (in bins) used to smooth the axis histogram before peak picking; larger merges nearby peaks, 0 disables smoothing.",
+            Float,
+            None,
+        ),
         // --- grow -----------------------------------------------------------
         field(
             "/advanced/attach_search_rel",
@@ -236,6 +253,22 @@ fn catalogue() -> ParamSchema {
             None,
         ),
         // --- validate -------------------------------------------------------
+        field(
+            "/advanced/min_grid_lines",
+            "validate",
+            "Min grid lines",
+            "Minimum distinct labelled rows and columns a detection must span; raise to reject tiny spurious grids.",
+            Int,
+            None,
+        ),
+        field(
+            "/advanced/max_grid_lines",
+            "validate",
+            "Max grid lines",
+            "Maximum distinct labelled rows or columns a detection may span (0 = unbounded).",
+            Int,
+            None,
+        ),
         field(
             "/advanced/line_min_members",
             "validate",
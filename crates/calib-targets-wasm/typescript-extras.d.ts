@@ -101,6 +101,8 @@ export interface LabeledCorner {
   target_position: Point2 | null;
   /** Detector-specific quality score (higher is better). */
   score: number;
+  /** Present and `true` only for grid-fill predictions, not observed corners. */
+  interpolated?: boolean;
 }
 
 export interface TargetDetection {
@@ -118,8 +120,11 @@ export interface ChessboardCorner {
   position: Point2;
   /** Grid label `(u, v)` — always present for a chessboard corner. */
   grid: Coord;
-  /** Index into the input `corners` slice that produced this corner. */
-  input_index: number;
+  /**
+   * Index into the input `corners` slice that produced this corner; `null`
+   * for a corner that came from no input.
+   */
+  input_index: number | null;
   /** Corner score (higher is better). */
   score: number;
 }
@@ -140,6 +145,8 @@ export interface MarkerDetection {
   score: number;
   border_score: number;
   code: number;
+  /** Matched dictionary code in the observed rotation; `null` if not recorded. */
+  matched_code: number | null;
   inverted: boolean;
   corners_rect: [Point2, Point2, Point2, Point2];
   corners_img: [Point2, Point2, Point2, Point2] | null;
@@ -158,6 +165,8 @@ export interface CharucoDetectionResult {
   /** Markers consistent with `alignment` (inliers of the chosen hypothesis). */
   markers: MarkerDetection[];
   alignment: GridAlignment;
+  /** Mean cell distance between aligned and best-decoding marker cells; near 1 flags a borderline alignment. */
+  alignment_residual_cells: number;
 }
 
 export interface MarkerBoardCorner {
@@ -306,18 +315,23 @@ export interface AdvancedTuning {
   topological: TopologicalParams;
   component_merge: LocalMergeParams;
   max_fit_rms_ratio: number;
+  normalize_strength: boolean;
+  strength_percentile: number | null;
   num_bins: number;
   max_iters_2means: number;
   cluster_tol_deg: number;
   cluster_sigma_k: number;
   peak_min_separation_deg: number;
   min_peak_weight_fraction: number;
+  smooth_sigma_bins: number;
   attach_search_rel: number;
   attach_axis_tol_deg: number;
   attach_ambiguity_factor: number;
   step_tol: number;
   edge_axis_tol_deg: number;
   line_min_members: number;
+  min_grid_lines: number;
+  max_grid_lines: number;
   validate_step_aware: boolean;
   geometry_check_line_tol_rel: number;
   geometry_check_local_h_tol_rel: number;
@@ -325,8 +339,12 @@ export interface AdvancedTuning {
   enable_weak_cluster_rescue: boolean;
   weak_cluster_tol_deg: number;
   max_booster_iters: number;
+  selection: ChessboardSelectionMode;
 }
 
+/** Component ranking for {@link AdvancedTuning.selection} (Rust `SelectionMode`). */
+export type ChessboardSelectionMode = "most_corners" | "largest_extent";
+
 /**
  * Chessboard detector parameters — the serialized shape of the Rust
  * `DetectorParams`. The four stable keys below are the semver-covered core;
@@ -348,6 +366,12 @@ export interface ChessboardParams {
 
 export type MarkerLayout = "opencv_charuco" | "bottom_left";
 
+export type BoardOrigin = "top_left" | "top_right" | "bottom_right" | "bottom_left";
+
+export interface BoardConvention {
+  origin: BoardOrigin;
+}
+
 export interface CharucoBoardSpec {
   rows: number;
   cols: number;
@@ -365,7 +389,24 @@ export interface ScanDecodeConfig {
   min_border_score: number;
   dedup_by_id: boolean;
   multi_threshold: boolean;
-}
+  border_policy: BorderPolicy;
+  auto_inset: boolean;
+  /** `px_per_square` multipliers tried by the rectified scan; empty = nominal only. */
+  scale_search: number[];
+  /** Reject cells whose saturated (255) sample fraction exceeds this; null = off. */
+  max_saturated_frac: number | null;
+  /** Stop the cell-quad scan after this many markers, centre cells first; null = scan all. */
+  stop_after: number | null;
+  /** Sub-samples averaged per bit before thresholding (default 1). */
+  bit_samples: number;
+  /** Centred fraction of each bit the sub-samples spread over (default 1 = whole bit). */
+  bit_core_frac: number;
+  /** Reject cell-quad decodes with more boundary-ambiguous data bits than this; null = off. */
+  max_ambiguous_bits: number | null;
+}
+
+/** Padding for pixel reads past the image edge while sampling a cell. */
+export type BorderPolicy = "zero" | "clamp" | "reflect";
 
 /**
  * Opt-in, **unstable** ChArUco board-level-matcher tuning knobs (Rust
@@ -394,6 +435,14 @@ export interface CharucoParams {
   scan: ScanDecodeConfig;
   min_marker_inliers: number;
   min_secondary_marker_inliers: number;
+  /** Minimum distinct marker ids among the inliers, per component (0 = off). */
+  min_distinct_marker_ids: number;
+  /** Minimum marker decode confidence (in (0, 1], 1 for a perfect read) to join the alignment (0 = off). */
+  min_marker_score: number;
+  /** Fail the detection when two image corners claim the same corner id. */
+  reject_on_duplicate_labels: boolean;
+  /** Board corner that grid (0, 0) labels; ids keep the printed frame. */
+  convention: BoardConvention;
   grid_smoothness_threshold_rel: number;
   corner_validation_threshold_rel: number;
   // --- opt-in, unstable tuning (omitted when unset) ---
@@ -417,6 +466,11 @@ export interface MarkerBoardLayout {
   circles: MarkerCircleSpec[];
 }
 
+/** Circle contrast aggregation; `min_sector` rejects partially occluded circles. */
+export type ScoreAggregation =
+  | { kind: "mean" }
+  | { kind: "min_sector"; sectors: number };
+
 export interface CircleScoreParams {
   patch_size: number;
   diameter_frac: number;
@@ -425,11 +479,15 @@ export interface CircleScoreParams {
   min_contrast: number;
   samples: number;
   center_search_px: number;
+  /** Accepted disk radius `[min, max]` in square units; null = any. */
+  radius_range_squares: [number, number] | null;
+  aggregation: ScoreAggregation;
 }
 
 export interface CircleMatchParams {
   max_candidates_per_polarity: number;
   min_offset_inliers: number;
+  auto_polarity: boolean;
 }
 
 export interface MarkerBoardParams {
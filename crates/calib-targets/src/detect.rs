@@ -42,6 +42,15 @@ pub enum DetectError {
         height: u32,
     },
 
+    /// A raw grayscale buffer's row stride is shorter than its width.
+    #[error("invalid grayscale image stride (width={width}, stride={stride})")]
+    InvalidGrayStride {
+        /// Declared image width in pixels.
+        width: u32,
+        /// Declared row stride in bytes.
+        stride: usize,
+    },
+
     /// Construction of the ChArUco board layout failed.
     #[error(transparent)]
     CharucoBoard(#[from] charuco::CharucoBoardError),
@@ -57,6 +66,12 @@ pub enum DetectError {
     /// PuzzleBoard detection failed.
     #[error(transparent)]
     PuzzleBoardDetect(#[from] puzzleboard::PuzzleBoardDetectError),
+
+    /// The blocking detection task was cancelled (the Tokio runtime shut down
+    /// before it completed).
+    #[cfg(feature = "tokio")]
+    #[error("detection task was cancelled before completing")]
+    Cancelled,
 }
 
 /// Reasonable default settings for the `chess-corners` ChESS detector.
@@ -98,15 +113,18 @@ pub fn gray_view(img: &::image::GrayImage) -> core::GrayImageView<'_> {
 /// favour of this explicit helper so each detection entry point takes
 /// only the (already-prepared) image and detector parameters.
 ///
+/// The blur is the separable [`core::gaussian_blur`], so the same
+/// denoising is available to callers holding a [`core::GrayImageView`]
+/// rather than an [`::image::GrayImage`]. Mild blur suppresses spurious
+/// ChESS responses on sensor noise and steadies marker bit reads.
+///
 /// Pass `blur_sigma_px = 0.0` (or any non-finite value) to get back a
 /// copy of the input unchanged. Typical values for ChESS corner
 /// detection sit between `0.5` and `2.0`.
 pub fn preprocess(img: &::image::GrayImage, blur_sigma_px: f32) -> ::image::GrayImage {
-    if blur_sigma_px.is_finite() && blur_sigma_px > 0.0 {
-        ::image::imageops::blur(img, blur_sigma_px)
-    } else {
-        img.clone()
-    }
+    let blurred = core::gaussian_blur(&gray_view(img), blur_sigma_px);
+    ::image::GrayImage::from_raw(img.width(), img.height(), blurred.data)
+        .unwrap_or_else(|| img.clone())
 }
 
 /// Detect ChESS corners and adapt them into [`calib_targets_chessboard::ChessCorner`].
@@ -154,6 +172,12 @@ pub fn detect_corners_default(img: &::image::GrayImage) -> Vec<chessboard::Chess
 ///   richest result.
 /// - [`detect_chessboard_from_gray_u8`] — takes a raw grayscale byte
 ///   buffer instead of an [`::image::GrayImage`].
+/// - [`detect_chessboard_within_extent`] — rejects components spanning too
+///   large a fraction of the image.
+/// - [`detect_chessboard_downscaled`] — detects on a box-downscaled copy
+///   for speed on high-resolution captures.
+/// - [`detect_chessboard_normalized`] — reports corner positions as
+///   fractions of the image size.
 #[cfg_attr(
     feature = "tracing",
     instrument(
@@ -194,7 +218,102 @@ pub fn detect_chessboard_all(
     detector.detect_all(&corners)
 }
 
+/// Extent-gated variant of [`detect_chessboard`]: returns the first
+/// component whose corner bounding box spans at most `max_extent_frac` of the
+/// image along each axis (see
+/// [`ChessboardDetection::image_extent_frac`](chessboard::ChessboardDetection::image_extent_frac)).
+///
+/// Use it when the board is known to be small in frame, so a grid grown
+/// from background texture across most of the image is implausible.
+pub fn detect_chessboard_within_extent(
+    img: &::image::GrayImage,
+    chess_cfg: &DetectorConfig,
+    params: &chessboard::DetectorParams,
+    max_extent_frac: f32,
+) -> Option<chessboard::ChessboardDetection> {
+    let (width, height) = (img.width() as usize, img.height() as usize);
+    detect_chessboard_all(img, chess_cfg, params)
+        .into_iter()
+        .find(|d| {
+            d.image_extent_frac(width, height)
+                .is_some_and(|frac| frac <= max_extent_frac)
+        })
+}
+
+/// Reduced-scale variant of [`detect_chessboard`]: detects on a copy
+/// box-downscaled by the integer `factor` (see [`core::downscale_box`]) and
+/// maps the corner positions and `cell_size` back to the input image frame.
+///
+/// A speed optimisation for high-resolution captures whose squares stay
+/// well above the detector's minimum size after downscaling; corner
+/// precision drops roughly with `factor`. A `factor` of `0` or `1` is
+/// plain [`detect_chessboard`].
+#[cfg_attr(
+    feature = "tracing",
+    instrument(
+        level = "info",
+        skip(img, chess_cfg, params),
+        fields(width = img.width(), height = img.height())
+    )
+)]
+pub fn detect_chessboard_downscaled(
+    img: &::image::GrayImage,
+    chess_cfg: &DetectorConfig,
+    params: &chessboard::DetectorParams,
+    factor: u32,
+) -> Option<chessboard::ChessboardDetection> {
+    if factor <= 1 {
+        return detect_chessboard(img, chess_cfg, params);
+    }
+    let small = core::downscale_box(&gray_view(img), factor as usize);
+    let small = ::image::GrayImage::from_raw(small.width as u32, small.height as u32, small.data)?;
+    let mut detection = detect_chessboard(&small, chess_cfg, params)?;
+
+    // Small-image pixel `p` averages source pixels `p * f .. p * f + f - 1`.
+    let scale = factor as f32;
+    let offset = 0.5 * (scale - 1.0);
+    for c in &mut detection.corners {
+        c.position = Point2::new(c.position.x * scale + offset, c.position.y * scale + offset);
+    }
+    detection.cell_size = detection.cell_size.map(|s| s * scale);
+    Some(detection)
+}
+
+/// Resolution-independent variant of [`detect_chessboard`]: corner positions
+/// are divided by the image width and height, so the image spans `[0, 1]`
+/// on both axes. `cell_size` stays in pixels.
+pub fn detect_chessboard_normalized(
+    img: &::image::GrayImage,
+    chess_cfg: &DetectorConfig,
+    params: &chessboard::DetectorParams,
+) -> Option<chessboard::ChessboardDetection> {
+    let mut detection = detect_chessboard(img, chess_cfg, params)?;
+    normalize_positions(
+        detection.corners.iter_mut().map(|c| &mut c.position),
+        img.width(),
+        img.height(),
+    );
+    Some(detection)
+}
+
+/// Divide image-space points by the image size in place. Shared by every
+/// `*_normalized` entry point so they agree on the convention.
+fn normalize_positions<'a>(
+    points: impl IntoIterator<Item = &'a mut Point2<f32>>,
+    width: u32,
+    height: u32,
+) {
+    let (w, h) = (width.max(1) as f32, height.max(1) as f32);
+    for p in points {
+        *p = Point2::new(p.x / w, p.y / h);
+    }
+}
+
 /// Run the ChArUco detector end-to-end: ChESS corners -> grid -> markers -> alignment -> IDs.
+///
+/// [`detect_charuco_normalized`] reports positions as fractions of the
+/// image size; so do [`detect_puzzleboard_normalized`] and
+/// [`detect_marker_board_normalized`] for the other board types.
 #[cfg_attr(
     feature = "tracing",
     instrument(
@@ -217,6 +336,56 @@ pub fn detect_charuco(
     Ok(detector.detect(&gray_view(img), &corners)?)
 }
 
+/// Resolution-independent variant of [`detect_charuco`]: corner positions
+/// and marker `corners_img` are divided by the image width and height, so
+/// the image spans `[0, 1]` on both axes. Rectified-space marker corners
+/// are left as they are.
+pub fn detect_charuco_normalized(
+    img: &::image::GrayImage,
+    params: &charuco::CharucoParams,
+) -> Result<charuco::CharucoDetectionResult, DetectError> {
+    let mut result = detect_charuco(img, params)?;
+    let corners = result.corners.iter_mut().map(|c| &mut c.position);
+    let markers = result
+        .markers
+        .iter_mut()
+        .filter_map(|m| m.corners_img.as_mut())
+        .flatten();
+    normalize_positions(corners.chain(markers), img.width(), img.height());
+    Ok(result)
+}
+
+/// Detect one of the predefined [`charuco::StandardBoard`]s with default
+/// parameters — no board spec or tuning required.
+///
+/// Equivalent to [`detect_charuco`] with
+/// [`CharucoParams::for_board`](charuco::CharucoParams::for_board) on
+/// [`StandardBoard::spec`](charuco::StandardBoard::spec).
+pub fn detect_standard_charuco(
+    img: &::image::GrayImage,
+    board: charuco::StandardBoard,
+) -> Result<charuco::CharucoDetectionResult, DetectError> {
+    detect_charuco(img, &charuco::CharucoParams::for_board(&board.spec()))
+}
+
+/// Async [`detect_charuco`]: runs the whole pipeline on Tokio's blocking
+/// pool (`spawn_blocking`) so the calling runtime's worker threads stay free.
+///
+/// The image and params are moved into the blocking task. A panic inside
+/// detection is resumed on the awaiting task. Requires the `tokio` feature
+/// and must be awaited from within a Tokio runtime.
+#[cfg(feature = "tokio")]
+pub async fn detect_charuco_async(
+    img: ::image::GrayImage,
+    params: charuco::CharucoParams,
+) -> Result<charuco::CharucoDetectionResult, DetectError> {
+    match tokio::task::spawn_blocking(move || detect_charuco(&img, &params)).await {
+        Ok(result) => result,
+        Err(err) if err.is_panic() => std::panic::resume_unwind(err.into_panic()),
+        Err(_) => Err(DetectError::Cancelled),
+    }
+}
+
 /// Run the PuzzleBoard detector end-to-end: ChESS corners → chessboard grid
 /// → edge-bit sampling → cross-correlation decode → absolute master IDs.
 #[cfg_attr(
@@ -241,6 +410,22 @@ pub fn detect_puzzleboard(
     Ok(detector.detect(&gray_view(img), &corners)?)
 }
 
+/// Resolution-independent variant of [`detect_puzzleboard`]: corner
+/// positions are divided by the image width and height, so the image spans
+/// `[0, 1]` on both axes.
+pub fn detect_puzzleboard_normalized(
+    img: &::image::GrayImage,
+    params: &puzzleboard::PuzzleBoardParams,
+) -> Result<puzzleboard::PuzzleBoardDetectionResult, DetectError> {
+    let mut result = detect_puzzleboard(img, params)?;
+    normalize_positions(
+        result.corners.iter_mut().map(|c| &mut c.position),
+        img.width(),
+        img.height(),
+    );
+    Ok(result)
+}
+
 /// Build a reasonable default PuzzleBoard parameter set for a
 /// `rows × cols` board (square counts).
 pub fn default_puzzleboard_params(
@@ -271,6 +456,22 @@ pub fn detect_marker_board(
     detector.detect_from_image_and_corners(&gray_view(img), &corners)
 }
 
+/// Resolution-independent variant of [`detect_marker_board`]: corner
+/// positions are divided by the image width and height, so the image spans
+/// `[0, 1]` on both axes.
+pub fn detect_marker_board_normalized(
+    img: &::image::GrayImage,
+    params: &marker::MarkerBoardParams,
+) -> Option<marker::MarkerBoardDetectionResult> {
+    let mut result = detect_marker_board(img, params)?;
+    normalize_positions(
+        result.corners.iter_mut().map(|c| &mut c.position),
+        img.width(),
+        img.height(),
+    );
+    Some(result)
+}
+
 // ---------------------------------------------------------------------------
 // Multi-config sweep helpers
 // ---------------------------------------------------------------------------
@@ -413,6 +614,49 @@ pub fn gray_image_from_slice(
         .ok_or(DetectError::InvalidGrayDimensions { width, height })
 }
 
+/// Build an `image::GrayImage` from a raw grayscale buffer whose rows are
+/// `stride` bytes apart (e.g. a camera frame with row padding).
+///
+/// Only the first `width` bytes of each row are read, so `pixels` needs
+/// `stride * (height - 1) + width` bytes; the final row's padding may be
+/// absent. The rows are packed once into a contiguous image, which is what
+/// every detector runs on. `stride == width` behaves like
+/// [`gray_image_from_slice`] but also accepts a longer buffer.
+pub fn gray_image_from_strided_slice(
+    width: u32,
+    height: u32,
+    stride: usize,
+    pixels: &[u8],
+) -> Result<::image::GrayImage, DetectError> {
+    let w = usize::try_from(width).ok();
+    let h = usize::try_from(height).ok();
+    let Some((w, h)) = w.zip(h) else {
+        return Err(DetectError::InvalidGrayDimensions { width, height });
+    };
+    if stride < w {
+        return Err(DetectError::InvalidGrayStride { width, stride });
+    }
+    let Some(expected) = h
+        .checked_sub(1)
+        .map_or(Some(0), |rows| stride.checked_mul(rows)?.checked_add(w))
+    else {
+        return Err(DetectError::InvalidGrayDimensions { width, height });
+    };
+    if pixels.len() < expected {
+        return Err(DetectError::InvalidGrayBuffer {
+            expected,
+            got: pixels.len(),
+        });
+    }
+    let mut packed = Vec::with_capacity(w * h);
+    for row in 0..h {
+        let start = row * stride;
+        packed.extend_from_slice(&pixels[start..start + w]);
+    }
+    ::image::GrayImage::from_raw(width, height, packed)
+        .ok_or(DetectError::InvalidGrayDimensions { width, height })
+}
+
 /// Raw-buffer variant of [`detect_chessboard`]: runs the chessboard detector
 /// from a raw grayscale byte buffer.
 ///
@@ -490,8 +734,49 @@ fn adapt_chess_corner(c: &chess_corners::CornerDescriptor) -> chessboard::ChessC
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::generate::charuco_document;
+    use crate::printable::{render_target_bundle, PageSize, PrintableTargetDocument};
     use chess_corners::DetectionStrategy;
 
+    /// Render `doc` and decode it to the grayscale image a camera would see.
+    fn render_gray(doc: &PrintableTargetDocument) -> ::image::GrayImage {
+        let png = render_target_bundle(doc).expect("render").png_bytes;
+        ::image::load_from_memory(&png)
+            .expect("decode png")
+            .to_luma8()
+    }
+
+    /// `spec` at 20 mm squares on a 170 × 130 mm page with a 5 mm margin,
+    /// rendered at 150 dpi.
+    fn charuco_test_document(spec: &charuco::CharucoBoardSpec) -> PrintableTargetDocument {
+        let mut doc = charuco_document(
+            spec.rows,
+            spec.cols,
+            20.0,
+            f64::from(spec.marker_size_rel),
+            spec.dictionary,
+        );
+        doc.page.size = PageSize::Custom {
+            width_mm: 170.0,
+            height_mm: 130.0,
+        };
+        doc.page.margin_mm = 5.0;
+        doc.render.png_dpi = 150;
+        doc
+    }
+
+    #[test]
+    fn detects_standard_board_without_configuration() {
+        let standard = charuco::StandardBoard::B5x7Dict4x4_50;
+        let spec = standard.spec();
+        let doc = charuco_test_document(&spec);
+        let img = render_gray(&doc);
+
+        let result = detect_standard_charuco(&img, standard).expect("detection");
+        assert_eq!(result.corners.len(), 4 * 6);
+        assert!(!result.markers.is_empty());
+    }
+
     #[test]
     fn default_chess_config_overrides_threshold() {
         // Workspace default deliberately overrides the upstream
@@ -525,4 +810,254 @@ mod tests {
         assert_eq!(chess.min_cluster_size, chess_baseline.min_cluster_size);
         assert_eq!(chess.refiner, chess_baseline.refiner);
     }
+
+    #[test]
+    fn preprocess_blur_suppresses_noise_corners_and_keeps_the_board() {
+        let mut doc = crate::generate::chessboard_document(6, 8, 10.0);
+        doc.page.size = PageSize::Custom {
+            width_mm: 100.0,
+            height_mm: 80.0,
+        };
+        doc.page.margin_mm = 5.0;
+        doc.render.png_dpi = 150;
+        let mut img = render_gray(&doc);
+        // Deterministic uniform noise of ±32 grey levels.
+        let mut state = 0x2545_f491_u32;
+        for p in img.pixels_mut() {
+            state = state.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+            let noise = (state >> 24) as i32 - 128;
+            p.0[0] = (p.0[0] as i32 + noise / 4).clamp(0, 255) as u8;
+        }
+
+        let cfg = default_chess_config();
+        let params = chessboard::DetectorParams::default();
+        let denoised = preprocess(&img, 1.0);
+        let raw_corners = detect_corners(&img, &cfg).len();
+        let denoised_corners = detect_corners(&denoised, &cfg).len();
+        assert!(
+            2 * denoised_corners < raw_corners,
+            "{denoised_corners} vs {raw_corners}"
+        );
+
+        let det = detect_chessboard(&denoised, &cfg, &params).expect("board detected");
+        assert_eq!(det.corners.len(), 6 * 8);
+    }
+
+    #[test]
+    fn marker_board_reports_a_half_turn_as_upside_down() {
+        let layout = marker::MarkerBoardSpec::new(6, 8, marker::MarkerBoardSpec::default().circles)
+            .with_cell_size(10.0);
+        let mut doc =
+            crate::printable::PrintableTargetDocument::try_from_marker_board_layout_mm(&layout)
+                .expect("printable layout");
+        doc.page.size = PageSize::Custom {
+            width_mm: 110.0,
+            height_mm: 90.0,
+        };
+        doc.page.margin_mm = 5.0;
+        doc.render.png_dpi = 150;
+        let upright = render_gray(&doc);
+        let params = marker::MarkerBoardParams::new(layout);
+
+        let det = detect_marker_board(&upright, &params).expect("upright detection");
+        assert_eq!(det.is_upside_down(), Some(false));
+
+        let rotated = ::image::imageops::rotate180(&upright);
+        let det = detect_marker_board(&rotated, &params).expect("rotated detection");
+        assert_eq!(det.is_upside_down(), Some(true));
+        let transform = det.alignment.expect("alignment").transform;
+        assert_eq!((transform.a, transform.d), (-1, -1));
+    }
+
+    #[test]
+    fn within_extent_rejects_grids_spanning_the_image() {
+        // 11 × 8 inner corners on a page barely larger than the board: the
+        // corner grid spans > 80 % of the image width.
+        let mut doc = crate::generate::chessboard_document(8, 11, 10.0);
+        doc.page.size = PageSize::Custom {
+            width_mm: 122.0,
+            height_mm: 92.0,
+        };
+        doc.page.margin_mm = 1.0;
+        doc.render.png_dpi = 150;
+        let full = render_gray(&doc);
+
+        let cfg = default_chess_config();
+        let params = chessboard::DetectorParams::default();
+        let det = detect_chessboard(&full, &cfg, &params).expect("board detected");
+        let frac = det
+            .image_extent_frac(full.width() as usize, full.height() as usize)
+            .expect("extent");
+        assert!(frac > 0.8, "{frac}");
+        assert!(detect_chessboard_within_extent(&full, &cfg, &params, 0.8).is_none());
+
+        // The same board in a frame twice the size passes the gate.
+        let mut framed = ::image::GrayImage::from_pixel(
+            full.width() * 2,
+            full.height() * 2,
+            ::image::Luma([255]),
+        );
+        ::image::imageops::overlay(&mut framed, &full, 100, 80);
+        let det = detect_chessboard_within_extent(&framed, &cfg, &params, 0.8)
+            .expect("small board accepted");
+        assert_eq!(det.corners.len(), 8 * 11);
+    }
+
+    #[test]
+    fn downscaled_detection_maps_corners_back_to_full_resolution() {
+        let mut doc = crate::generate::chessboard_document(6, 8, 10.0);
+        doc.page.size = PageSize::Custom {
+            width_mm: 110.0,
+            height_mm: 90.0,
+        };
+        doc.page.margin_mm = 5.0;
+        doc.render.png_dpi = 300;
+        let img = render_gray(&doc);
+
+        let cfg = default_chess_config();
+        let params = chessboard::DetectorParams::default();
+        let full = detect_chessboard(&img, &cfg, &params).expect("full-resolution detection");
+        let half =
+            detect_chessboard_downscaled(&img, &cfg, &params, 2).expect("downscaled detection");
+        assert_eq!(half.corners.len(), full.corners.len());
+
+        let full_at: std::collections::HashMap<_, _> = full
+            .corners
+            .iter()
+            .map(|c| ((c.grid.u, c.grid.v), c.position))
+            .collect();
+        // Half a small-image pixel of corner error is one full-resolution pixel.
+        for c in &half.corners {
+            let p = full_at[&(c.grid.u, c.grid.v)];
+            assert!(
+                (c.position - p).norm() < 1.5,
+                "{:?}: {:?} vs {p:?}",
+                c.grid,
+                c.position
+            );
+        }
+        let (cell_full, cell_half) = (full.cell_size.unwrap(), half.cell_size.unwrap());
+        assert!((cell_half - cell_full).abs() < 0.05 * cell_full);
+    }
+
+    #[test]
+    fn normalized_positions_are_image_fractions() {
+        let centre = chessboard::ChessboardCorner::new(
+            Point2::new(320.0, 240.0),
+            core::Coord::new(0, 0),
+            0,
+            1.0,
+        );
+        let mut d = chessboard::ChessboardDetection::new(vec![centre]);
+        normalize_positions(d.corners.iter_mut().map(|c| &mut c.position), 640, 480);
+        assert_eq!(d.corners[0].position, Point2::new(0.5, 0.5));
+
+        let mut doc = crate::generate::chessboard_document(6, 8, 10.0);
+        doc.render.png_dpi = 100;
+        let img = render_gray(&doc);
+        let cfg = default_chess_config();
+        let params = chessboard::DetectorParams::default();
+        let px = detect_chessboard(&img, &cfg, &params).expect("detection");
+        let unit = detect_chessboard_normalized(&img, &cfg, &params).expect("detection");
+        let (w, h) = (img.width() as f32, img.height() as f32);
+        for (a, b) in px.corners.iter().zip(&unit.corners) {
+            assert_eq!(a.grid, b.grid);
+            assert!((b.position.x * w - a.position.x).abs() < 1e-3);
+            assert!((b.position.y * h - a.position.y).abs() < 1e-3);
+        }
+    }
+
+    #[test]
+    fn normalized_charuco_scales_corners_and_marker_quads() {
+        let spec = charuco::StandardBoard::B5x7Dict4x4_50.spec();
+        let img = render_gray(&charuco_test_document(&spec));
+        let params = charuco::CharucoParams::for_board(&spec);
+        let px = detect_charuco(&img, &params).expect("detection");
+        let unit = detect_charuco_normalized(&img, &params).expect("detection");
+        let (w, h) = (img.width() as f32, img.height() as f32);
+        let close = |a: Point2<f32>, b: Point2<f32>| {
+            (b.x * w - a.x).abs() < 1e-3 && (b.y * h - a.y).abs() < 1e-3
+        };
+        assert_eq!(px.corners.len(), unit.corners.len());
+        for (a, b) in px.corners.iter().zip(&unit.corners) {
+            assert_eq!(a.id, b.id);
+            assert!(close(a.position, b.position));
+        }
+        assert!(!px.markers.is_empty());
+        for (a, b) in px.markers.iter().zip(&unit.markers) {
+            assert_eq!(a.corners_rect, b.corners_rect);
+            let (qa, qb) = (a.corners_img.expect("quad"), b.corners_img.expect("quad"));
+            assert!(qa.iter().zip(&qb).all(|(&pa, &pb)| close(pa, pb)));
+        }
+    }
+
+    #[test]
+    fn padded_buffer_detects_same_board_as_contiguous_copy() {
+        let spec = charuco::StandardBoard::B5x7Dict4x4_50.spec();
+        let doc = charuco_test_document(&spec);
+        let img = render_gray(&doc);
+
+        // Pad every row with junk bytes, as a camera driver might.
+        let (w, h) = img.dimensions();
+        let stride = w as usize + 13;
+        let mut padded = vec![0x5a_u8; stride * h as usize];
+        for (row, src) in img.as_raw().chunks_exact(w as usize).enumerate() {
+            padded[row * stride..row * stride + w as usize].copy_from_slice(src);
+        }
+        let unpadded = gray_image_from_strided_slice(w, h, stride, &padded).expect("strided");
+        assert_eq!(unpadded.as_raw(), img.as_raw());
+
+        let params = charuco::CharucoParams::for_board(&spec);
+        let expected = detect_charuco(&img, &params).expect("contiguous detection");
+        let got = detect_charuco(&unpadded, &params).expect("padded detection");
+        assert_eq!(got.corners.len(), expected.corners.len());
+        for (a, b) in got.corners.iter().zip(&expected.corners) {
+            assert_eq!((a.id, a.position), (b.id, b.position));
+        }
+
+        assert!(matches!(
+            gray_image_from_strided_slice(w, h, w as usize - 1, &padded),
+            Err(DetectError::InvalidGrayStride { .. })
+        ));
+        assert!(matches!(
+            gray_image_from_strided_slice(w, h, stride, &padded[..stride]),
+            Err(DetectError::InvalidGrayBuffer { .. })
+        ));
+    }
+
+    #[test]
+    fn detected_grid_labels_match_the_rendered_convention() {
+        use crate::core::{BoardConvention, BoardOrigin};
+
+        let spec = charuco::StandardBoard::B5x7Dict4x4_50.spec();
+        let doc = charuco_test_document(&spec);
+        let img = render_gray(&doc);
+        let origin_mm = doc.resolve_layout().expect("layout").board_origin_mm;
+        let px_per_mm = f64::from(doc.render.png_dpi) / 25.4;
+
+        for origin in [
+            BoardOrigin::TopLeft,
+            BoardOrigin::TopRight,
+            BoardOrigin::BottomRight,
+            BoardOrigin::BottomLeft,
+        ] {
+            let convention = BoardConvention::new(origin);
+            let points = doc.target.resolved_points_in(convention).expect("points");
+            let mut params = charuco::CharucoParams::for_board(&spec);
+            params.convention = convention;
+            let result = detect_charuco(&img, &params).expect("detection");
+            assert_eq!(result.corners.len(), 4 * 6, "{origin:?}");
+            for corner in &result.corners {
+                let point = points
+                    .iter()
+                    .find(|p| p.id == Some(corner.id))
+                    .expect("rendered id");
+                assert_eq!(point.grid, Some(corner.grid), "{origin:?} id {}", corner.id);
+                let x = (origin_mm[0] + point.position_mm[0]) * px_per_mm;
+                let y = (origin_mm[1] + point.position_mm[1]) * px_per_mm;
+                let d = (f64::from(corner.position.x) - x).hypot(f64::from(corner.position.y) - y);
+                assert!(d < 1.5, "{origin:?} id {}: {d:.2} px off", corner.id);
+            }
+        }
+    }
 }
@@ -0,0 +1,61 @@
+//! `detect_charuco_async` runs the same pipeline as `detect_charuco` on the
+//! Tokio blocking pool. Requires the `tokio` feature.
+
+use calib_targets::aruco::builtins;
+use calib_targets::charuco::{CharucoBoardSpec, CharucoParams};
+use calib_targets::detect;
+use calib_targets::generate::charuco_document;
+use calib_targets::printable::{render_target_bundle, PageSize};
+
+const ROWS: u32 = 5;
+const COLS: u32 = 7;
+const SQUARE_MM: f64 = 20.0;
+const MARKER_SIZE_REL: f64 = 0.75;
+
+/// Render a small ChArUco board to a grayscale image.
+fn rendered_board() -> image::GrayImage {
+    let mut doc = charuco_document(
+        ROWS,
+        COLS,
+        SQUARE_MM,
+        MARKER_SIZE_REL,
+        builtins::DICT_4X4_50,
+    );
+    doc.page.size = PageSize::Custom {
+        width_mm: 170.0,
+        height_mm: 130.0,
+    };
+    doc.page.margin_mm = 5.0;
+    doc.render.png_dpi = 150;
+    let bundle = render_target_bundle(&doc).expect("render");
+    image::load_from_memory(&bundle.png_bytes)
+        .expect("decode png")
+        .to_luma8()
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn async_detection_matches_sync() {
+    let img = rendered_board();
+    let board = CharucoBoardSpec::new(
+        ROWS,
+        COLS,
+        SQUARE_MM as f32,
+        MARKER_SIZE_REL as f32,
+        builtins::DICT_4X4_50,
+    );
+    let params = CharucoParams::for_board(&board);
+    let sync = detect::detect_charuco(&img, &params).expect("sync detection");
+
+    let result = detect::detect_charuco_async(img, params)
+        .await
+        .expect("async detection");
+
+    let inner = ((ROWS - 1) * (COLS - 1)) as usize;
+    assert_eq!(result.corners.len(), inner);
+    assert_eq!(result.corners.len(), sync.corners.len());
+    assert_eq!(result.markers.len(), sync.markers.len());
+    for (a, b) in result.corners.iter().zip(&sync.corners) {
+        assert_eq!(a.id, b.id);
+        assert_eq!(a.position, b.position);
+    }
+}
@@ -14,6 +14,9 @@
 //!   `[0, π)` and an equal-width circular-histogram bin index.
 //! * [`smooth_circular_5`] — a 1-pass `[1, 4, 6, 4, 1] / 16` circular
 //!   convolution.
+//! * [`smooth_circular_gaussian`] — circular convolution with a sampled
+//!   Gaussian of configurable width (σ in bins); `σ = 1` approximates
+//!   the 5-tap kernel.
 //! * [`pick_two_peaks`] — plateau-aware local-maxima detection on a
 //!   smoothed circular histogram. Handles the edge case where a
 //!   physical direction's mass lands on both sides of a bin boundary
@@ -101,6 +104,39 @@ pub fn smooth_circular_5(hist: &[f32]) -> Vec<f32> {
     out
 }
 
+/// Smooth a circular histogram with a sampled, normalised Gaussian of
+/// standard deviation `sigma_bins` (in bins), truncated at `3σ`.
+///
+/// `sigma_bins = 1.0` is close to [`smooth_circular_5`] (the binomial
+/// `[1, 4, 6, 4, 1] / 16` kernel has unit variance). A non-positive or
+/// non-finite `sigma_bins` disables smoothing and returns a copy of the
+/// input. Empty input returns empty output.
+pub fn smooth_circular_gaussian(hist: &[f32], sigma_bins: f32) -> Vec<f32> {
+    let n = hist.len();
+    if n == 0 || !sigma_bins.is_finite() || sigma_bins <= 0.0 {
+        return hist.to_vec();
+    }
+    let radius = ((3.0 * sigma_bins).ceil() as usize).clamp(1, n);
+    let kernel: Vec<f32> = (0..=2 * radius)
+        .map(|k| {
+            let d = k as f32 - radius as f32;
+            (-0.5 * (d / sigma_bins).powi(2)).exp()
+        })
+        .collect();
+    let k_sum: f32 = kernel.iter().sum();
+    let mut out = vec![0.0_f32; n];
+    for (i, bin) in out.iter_mut().enumerate() {
+        let mut acc = 0.0_f32;
+        for (k, &w) in kernel.iter().enumerate() {
+            let offset = k as isize - radius as isize;
+            let j = ((i as isize + offset).rem_euclid(n as isize)) as usize;
+            acc += w * hist[j];
+        }
+        *bin = acc / k_sum;
+    }
+    out
+}
+
 /// Options for [`pick_two_peaks`].
 #[non_exhaustive]
 #[derive(Clone, Copy, Debug)]
@@ -286,6 +322,36 @@ mod tests {
         assert!((sum - 16.0).abs() < 1e-4, "got {sum}");
     }
 
+    #[test]
+    fn gaussian_sigma_one_approximates_5_tap_kernel() {
+        let mut hist = vec![0.0_f32; 18];
+        hist[4] = 16.0;
+        let binomial = smooth_circular_5(&hist);
+        let gaussian = smooth_circular_gaussian(&hist, 1.0);
+        let sum: f32 = gaussian.iter().sum();
+        assert!((sum - 16.0).abs() < 1e-3, "got {sum}");
+        for (b, g) in binomial.iter().zip(&gaussian) {
+            assert!((b - g).abs() < 0.5, "binomial {b} vs gaussian {g}");
+        }
+        assert_eq!(smooth_circular_gaussian(&hist, 0.0), hist);
+    }
+
+    #[test]
+    fn gaussian_sigma_controls_peak_merging() {
+        // Two equal peaks three bins apart.
+        let mut hist = vec![0.0_f32; 90];
+        hist[10] = 100.0;
+        hist[13] = 100.0;
+        let local_maxima = |h: &[f32]| {
+            let n = h.len();
+            (0..n)
+                .filter(|&i| h[i] > h[(i + n - 1) % n] && h[i] >= h[(i + 1) % n])
+                .count()
+        };
+        assert_eq!(local_maxima(&smooth_circular_gaussian(&hist, 0.5)), 2);
+        assert_eq!(local_maxima(&smooth_circular_gaussian(&hist, 3.0)), 1);
+    }
+
     #[test]
     fn pick_two_peaks_separates_orthogonal_peaks() {
         // 18 bins of 10°, two peaks at 0° (bin 0) and 90° (bin 9).
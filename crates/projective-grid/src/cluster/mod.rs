@@ -34,7 +34,9 @@
 //!    `num_bins` bins. For every feature and every axis `k ∈ {0, 1}`,
 //!    add a vote at `wrap_pi(axes[k].angle)` with weight
 //!    `strength / (1 + axes[k].sigma)`.
-//! 2. Smooth with a `[1, 4, 6, 4, 1] / 16` circular kernel.
+//! 2. Smooth with a circular Gaussian of `smooth_sigma_bins` bins
+//!    (default `1.0`, close to a `[1, 4, 6, 4, 1] / 16` kernel; `0`
+//!    disables smoothing).
 //! 3. Find local maxima. Keep peaks with total weight ≥
 //!    `min_peak_weight_fraction × total`. Pick the two strongest
 //!    peaks separated by at least `peak_min_separation_rad`.
@@ -56,7 +58,7 @@ use serde::Serialize;
 
 pub use circular::{
     angle_to_bin, angular_dist_pi, bin_to_angle, pick_two_peaks, refine_2means_double_angle,
-    smooth_circular_5, wrap_pi, AngleVote, PeakPickOptions,
+    smooth_circular_5, smooth_circular_gaussian, wrap_pi, AngleVote, PeakPickOptions,
 };
 
 /// One undirected local lattice-axis estimate feeding the clustering
@@ -114,6 +116,10 @@ pub struct ClusterParams {
     pub base_tol_rad: f32,
     /// Multiplier on per-feature axis sigma added to [`Self::base_tol_rad`].
     pub cluster_sigma_k: f32,
+    /// Standard deviation (in bins) of the circular Gaussian the histogram
+    /// is smoothed with before peak picking. Larger values merge nearby
+    /// peaks; `0` disables smoothing. [`Self::new`] sets `1.0`.
+    pub smooth_sigma_bins: f32,
 }
 
 impl ClusterParams {
@@ -133,8 +139,15 @@ impl ClusterParams {
             max_iters_2means,
             base_tol_rad,
             cluster_sigma_k,
+            smooth_sigma_bins: 1.0,
         }
     }
+
+    /// Set the histogram smoothing width in bins (`0` disables smoothing).
+    pub fn with_smooth_sigma_bins(mut self, smooth_sigma_bins: f32) -> Self {
+        self.smooth_sigma_bins = smooth_sigma_bins;
+        self
+    }
 }
 
 /// The two recovered global grid-direction centres in `[0, π)` with
@@ -264,7 +277,7 @@ pub fn cluster_axes(
         return (None, Vec::new(), debug);
     }
 
-    let smoothed = smooth_circular_5(&hist.bins);
+    let smoothed = smooth_circular_gaussian(&hist.bins, params.smooth_sigma_bins);
     debug.smoothed = smoothed.clone();
 
     let peak_opts = PeakPickOptions::new(
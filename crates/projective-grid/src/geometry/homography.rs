@@ -136,6 +136,14 @@ impl<F: Float> Homography<F> {
     pub fn inverse(&self) -> Option<Self> {
         self.h.try_inverse().map(Self::new)
     }
+
+    /// Condition number of the matrix, `σ_max / σ_min` from its SVD.
+    ///
+    /// Shorthand for [`HomographyQuality::condition`]; see there for typical
+    /// ranges and for why it depends on the coordinate scale `H` was fit in.
+    pub fn condition_number(&self) -> F {
+        HomographyQuality::from_homography(self).condition
+    }
 }
 
 // ---- Hartley normalization ----
@@ -578,6 +586,41 @@ mod tests {
         );
     }
 
+    #[test]
+    fn condition_number_flags_near_degenerate_maps() {
+        let rect = [
+            Point2::new(0.0_f32, 0.0),
+            Point2::new(1.0, 0.0),
+            Point2::new(1.0, 1.0),
+            Point2::new(0.0, 1.0),
+        ];
+        let near_affine = [
+            Point2::new(0.1_f32, 0.0),
+            Point2::new(2.0, 0.1),
+            Point2::new(2.1, 1.9),
+            Point2::new(0.0, 2.0),
+        ];
+        let near_degenerate = [
+            Point2::new(0.0_f32, 0.0),
+            Point2::new(1.0, 0.0),
+            Point2::new(1.0, 1e-6),
+            Point2::new(0.0, 1e-6),
+        ];
+        let h_affine = homography_from_4pt(&rect, &near_affine).expect("near-affine");
+        let h_degen = homography_from_4pt(&rect, &near_degenerate).expect("near-degenerate");
+
+        assert!(
+            h_affine.condition_number() < 10.0,
+            "{}",
+            h_affine.condition_number()
+        );
+        assert!(
+            h_degen.condition_number() > 1e4,
+            "{}",
+            h_degen.condition_number()
+        );
+    }
+
     #[test]
     fn is_ill_conditioned_threshold_works() {
         let rect = [
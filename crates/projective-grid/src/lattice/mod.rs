@@ -77,6 +77,29 @@ impl Coord {
     pub const fn new(u: i32, v: i32) -> Self {
         Self { u, v }
     }
+
+    /// Row-major linear index `v * cols + u` into a dense grid `cols` wide.
+    ///
+    /// Returns `None` when the coordinate lies outside the grid's columns
+    /// (`u < 0` or `u >= cols`) or above its first row (`v < 0`).
+    pub fn to_index(self, cols: usize) -> Option<usize> {
+        let u = usize::try_from(self.u).ok().filter(|&u| u < cols)?;
+        let v = usize::try_from(self.v).ok()?;
+        v.checked_mul(cols)?.checked_add(u)
+    }
+
+    /// Inverse of [`to_index`](Self::to_index): the coordinate at row-major
+    /// index `idx` of a grid `cols` wide.
+    ///
+    /// Returns `None` for `cols == 0` or when the row does not fit an `i32`.
+    pub fn from_index(idx: usize, cols: usize) -> Option<Self> {
+        if cols == 0 {
+            return None;
+        }
+        let u = i32::try_from(idx % cols).ok()?;
+        let v = i32::try_from(idx / cols).ok()?;
+        Some(Self::new(u, v))
+    }
 }
 
 /// Optional known grid dimensions.
@@ -302,6 +325,23 @@ mod tests {
         assert!((p.y - 3.0_f32.sqrt()).abs() < 1e-6);
     }
 
+    #[test]
+    fn linear_index_round_trips_in_range() {
+        let cols = 7;
+        for v in 0..5 {
+            for u in 0..cols as i32 {
+                let g = Coord::new(u, v);
+                let idx = g.to_index(cols).expect("in range");
+                assert_eq!(idx, v as usize * cols + u as usize);
+                assert_eq!(Coord::from_index(idx, cols), Some(g));
+            }
+        }
+        assert_eq!(Coord::new(7, 0).to_index(cols), None);
+        assert_eq!(Coord::new(-1, 2).to_index(cols), None);
+        assert_eq!(Coord::new(0, -1).to_index(cols), None);
+        assert_eq!(Coord::from_index(3, 0), None);
+    }
+
     #[test]
     fn kind_dispatch_matches_trait_impls() {
         let c = Coord::new(3, -1);
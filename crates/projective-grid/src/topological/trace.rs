@@ -5,6 +5,9 @@
 //! the detector path. It records the stable facts downstream diagnostics need:
 //! input corner usability, final labelled components, and summary counts.
 
+use std::collections::{BTreeMap, HashMap};
+
+use nalgebra::{Point2, Vector2};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
@@ -59,6 +62,24 @@ pub struct TopologicalComponentTrace {
     pub index: usize,
     /// The component's labels, sorted by `(v, u, source_index)`.
     pub labels: Vec<TopologicalLabelTrace>,
+    /// Projected position of each constant-`u` grid line, one per distinct
+    /// `u` in ascending order: the mean of `p · n_u` over the line's corners,
+    /// where `n_u` is the unit vector at angle `line_axes_rad[0]`. Empty when
+    /// the component has no `v`-adjacent label pairs to orient the lines.
+    #[serde(default)]
+    pub u_line_centers: Vec<f32>,
+    /// Projected position of each constant-`v` grid line, one per distinct
+    /// `v` in ascending order, along `line_axes_rad[1]`. Empty when the
+    /// component has no `u`-adjacent label pairs.
+    #[serde(default)]
+    pub v_line_centers: Vec<f32>,
+    /// Projection axes `[n_u, n_v]` in radians. `n_u` is normal to the
+    /// constant-`u` lines and points toward increasing `u` (likewise `n_v`),
+    /// so line `k` in image space is `{ p : p · (cos a, sin a) = center }`.
+    /// A component spanning a single `u` (or `v`) has nothing to orient the
+    /// axis toward, so its sign is arbitrary there.
+    #[serde(default)]
+    pub line_axes_rad: [f32; 2],
 }
 
 /// Summary counters for the topological trace.
@@ -153,6 +174,10 @@ pub fn build_grid_topological_trace(
         return Err(TopologicalTraceError::NoComponents);
     }
 
+    let positions: HashMap<usize, Point2<f32>> = features
+        .iter()
+        .map(|f| (f.point.source_index, f.point.position))
+        .collect();
     let components: Vec<TopologicalComponentTrace> = report
         .solutions
         .iter()
@@ -169,7 +194,14 @@ pub fn build_grid_topological_trace(
                 })
                 .collect();
             labels.sort_by_key(|label| (label.v, label.u, label.source_index));
-            TopologicalComponentTrace { index, labels }
+            let lines = line_centers(&labels, &positions);
+            TopologicalComponentTrace {
+                index,
+                labels,
+                u_line_centers: lines.u_centers,
+                v_line_centers: lines.v_centers,
+                line_axes_rad: lines.axes_rad,
+            }
         })
         .collect();
     let labels = components
@@ -189,3 +221,117 @@ pub fn build_grid_topological_trace(
         diagnostics,
     })
 }
+
+struct LineCenters {
+    u_centers: Vec<f32>,
+    v_centers: Vec<f32>,
+    axes_rad: [f32; 2],
+}
+
+/// Per-line projected centres of one labelled component.
+///
+/// The grid directions `e_u` / `e_v` are the mean image-space steps between
+/// `u`- / `v`-adjacent labels. Constant-`u` lines run along `e_v`, so they
+/// are projected onto the normal of `e_v` (oriented toward `+u`), and
+/// symmetrically for constant-`v` lines. When no `u`-adjacent pair exists
+/// `e_u` is zero, so the normal is oriented along the spread of positions
+/// over `u` instead; with a single `u` there is no `+u` and the sign stays
+/// arbitrary.
+fn line_centers(
+    labels: &[TopologicalLabelTrace],
+    positions: &HashMap<usize, Point2<f32>>,
+) -> LineCenters {
+    // Iterate `labels` (sorted) rather than the map so the float sums are
+    // deterministic.
+    let placed: Vec<((i32, i32), Point2<f32>)> = labels
+        .iter()
+        .filter_map(|l| positions.get(&l.source_index).map(|&p| ((l.u, l.v), p)))
+        .collect();
+    let at: HashMap<(i32, i32), Point2<f32>> = placed.iter().copied().collect();
+    let mut e_u = Vector2::zeros();
+    let mut e_v = Vector2::zeros();
+    for &((u, v), p) in &placed {
+        if let Some(&q) = at.get(&(u + 1, v)) {
+            e_u += q - p;
+        }
+        if let Some(&q) = at.get(&(u, v + 1)) {
+            e_v += q - p;
+        }
+    }
+
+    // Direction in which positions move as `key` grows: the covariance of
+    // `p` with `key`, nonzero once two distinct keys are placed.
+    let spread = |key: fn((i32, i32)) -> i32| -> Vector2<f32> {
+        let n = placed.len().max(1) as f32;
+        let mean_k = placed.iter().map(|&(c, _)| key(c) as f32).sum::<f32>() / n;
+        let mean_p = placed.iter().map(|&(_, p)| p.coords).sum::<Vector2<f32>>() / n;
+        placed
+            .iter()
+            .map(|&(c, p)| (key(c) as f32 - mean_k) * (p.coords - mean_p))
+            .sum()
+    };
+    let toward = |e: Vector2<f32>, key: fn((i32, i32)) -> i32| {
+        if e.norm_squared() > f32::EPSILON {
+            e
+        } else {
+            spread(key)
+        }
+    };
+
+    // Normal to the lines running along `along`, oriented toward `toward`.
+    let axis = |along: Vector2<f32>, toward: Vector2<f32>| -> Option<Vector2<f32>> {
+        let n = Vector2::new(-along.y, along.x).try_normalize(f32::EPSILON)?;
+        Some(if n.dot(&toward) < 0.0 { -n } else { n })
+    };
+    let project = |axis: Option<Vector2<f32>>, key: fn((i32, i32)) -> i32| -> Vec<f32> {
+        let Some(axis) = axis else {
+            return Vec::new();
+        };
+        let mut sums: BTreeMap<i32, (f32, usize)> = BTreeMap::new();
+        for &(coord, p) in &placed {
+            let entry = sums.entry(key(coord)).or_insert((0.0, 0));
+            entry.0 += p.coords.dot(&axis);
+            entry.1 += 1;
+        }
+        sums.values()
+            .map(|&(sum, count)| sum / count as f32)
+            .collect()
+    };
+
+    let n_u = axis(e_v, toward(e_u, |c| c.0));
+    let n_v = axis(e_u, toward(e_v, |c| c.1));
+    let angle = |n: Option<Vector2<f32>>| n.map_or(0.0, |n| n.y.atan2(n.x));
+    LineCenters {
+        u_centers: project(n_u, |c| c.0),
+        v_centers: project(n_v, |c| c.1),
+        axes_rad: [angle(n_u), angle(n_v)],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn u_axis_points_toward_increasing_u_without_u_neighbours() {
+        // Columns `u = 0` and `u = 2` only, 10 px apart along -x, so no
+        // `u`-adjacent pair exists and `e_u` is zero.
+        let mut labels = Vec::new();
+        let mut positions = HashMap::new();
+        for (col, u) in [0, 2].into_iter().enumerate() {
+            for v in 0..3 {
+                let source_index = col * 3 + v as usize;
+                labels.push(TopologicalLabelTrace::new(u, v, source_index));
+                positions.insert(source_index, Point2::new(-10.0 * u as f32, 10.0 * v as f32));
+            }
+        }
+        labels.sort_by_key(|l| (l.v, l.u, l.source_index));
+
+        let lines = line_centers(&labels, &positions);
+        assert!(lines.v_centers.is_empty());
+        let n_u = Vector2::new(lines.axes_rad[0].cos(), lines.axes_rad[0].sin());
+        assert!((n_u - Vector2::new(-1.0, 0.0)).norm() < 1e-5, "{n_u:?}");
+        assert_eq!(lines.u_centers.len(), 2);
+        assert!(lines.u_centers[1] > lines.u_centers[0]);
+    }
+}